@@ -10,4 +10,17 @@ fn main() {
     if is_msmpi {
         println!("cargo:rustc-cfg=msmpi");
     }
+
+    // Features introduced by MPI-4 are only available if the library implements that version of
+    // the standard. `mpi-sys` reports the version it found in `mpi.h`.
+    println!("cargo:rustc-check-cfg=cfg(mpi4)");
+
+    let mpi_version = std::env::var("DEP_MPI_VERSION")
+        .ok()
+        .and_then(|version| version.parse::<u32>().ok())
+        .unwrap_or(0);
+
+    if mpi_version >= 4 {
+        println!("cargo:rustc-cfg=mpi4");
+    }
 }
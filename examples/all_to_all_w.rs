@@ -0,0 +1,52 @@
+#![deny(warnings)]
+
+use std::mem;
+
+use mpi::{collective::BlockLayout, traits::*, Count, Rank};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let element = mem::size_of::<Rank>() as Count;
+    let datatypes = vec![Rank::equivalent_datatype(); size as usize];
+
+    // Process `r` sends `j + 1` copies of `r` to process `j`.
+    let send_counts: Vec<Count> = (1..=size).collect();
+    let send_displs: Vec<Count> = (0..size).map(|j| element * j * (j + 1) / 2).collect();
+    let sendbuf: Vec<Rank> = vec![rank; (size * (size + 1) / 2) as usize];
+
+    // Process `r` receives `r + 1` copies of `i` from process `i`.
+    let recv_counts: Vec<Count> = vec![rank + 1; size as usize];
+    let recv_displs: Vec<Count> = (0..size).map(|i| element * i * (rank + 1)).collect();
+    let expected: Vec<Rank> = (0..size)
+        .flat_map(|i| std::iter::repeat(i).take(rank as usize + 1))
+        .collect();
+
+    let mut recvbuf = vec![-1; expected.len()];
+    unsafe {
+        world.all_to_all_w_into(
+            &sendbuf[..],
+            BlockLayout::new(&send_counts, &send_displs, &datatypes),
+            &mut recvbuf[..],
+            BlockLayout::new(&recv_counts, &recv_displs, &datatypes),
+        );
+    }
+    assert_eq!(expected, recvbuf);
+
+    let mut recvbuf = vec![-1; expected.len()];
+    mpi::request::scope(|scope| unsafe {
+        world
+            .immediate_all_to_all_w_into(
+                scope,
+                &sendbuf[..],
+                BlockLayout::new(&send_counts, &send_displs, &datatypes),
+                &mut recvbuf[..],
+                BlockLayout::new(&recv_counts, &recv_displs, &datatypes),
+            )
+            .wait();
+    });
+    assert_eq!(expected, recvbuf);
+}
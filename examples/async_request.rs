@@ -0,0 +1,137 @@
+#![deny(warnings)]
+
+use std::{
+    future::{self, Future, IntoFuture},
+    pin::{pin, Pin},
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+};
+
+use mpi::{request, traits::*, Rank};
+
+/// Wakes the thread that runs `block_on`.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor that runs `future` to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next = world.process_at_rank((rank + 1) % size);
+    let previous = world.process_at_rank((rank + size - 1) % size);
+
+    // Requests registered with a scope can be awaited within it.
+    let mut received: Rank = -1;
+    request::scope(|scope| {
+        block_on(async {
+            let send = next.immediate_send(scope, &rank);
+            let receive = previous.immediate_receive_into(scope, &mut received);
+            let status = receive.await;
+            assert_eq!(previous.rank(), status.source_rank());
+            send.await;
+        })
+    });
+    assert_eq!(previous.rank(), received);
+
+    // Values received into a `ReceiveFuture` can be awaited as well.
+    let (value, status) = request::scope(|scope| {
+        block_on(async {
+            let send = next.immediate_send(scope, &rank);
+            let value = previous.immediate_receive::<Rank>().await;
+            send.await;
+            value
+        })
+    });
+    assert_eq!(previous.rank(), value);
+    assert_eq!(previous.rank(), status.source_rank());
+
+    // Futures can be polled concurrently, all pending requests are tested together. Every message
+    // has a tag of its own, so that each future is checked to complete with its own status, also
+    // when several requests complete at once.
+    let values: Vec<Rank> = (0..size).map(|i| rank * size + i).collect();
+    let mut results = vec![-1; values.len()];
+    request::scope(|scope| {
+        block_on(async {
+            let sends: Vec<_> = (0..)
+                .zip(&values)
+                .map(|(tag, value)| {
+                    next.immediate_send_with_tag(scope, value, tag)
+                        .into_future()
+                })
+                .collect();
+            let mut receives: Vec<_> = (0..)
+                .zip(results.iter_mut())
+                .map(|(tag, result)| {
+                    let receive = previous.immediate_receive_into_with_tag(scope, result, tag);
+                    (tag, receive.into_future())
+                })
+                .collect();
+
+            // Completed futures are dropped, the others are polled again when the task wakes.
+            future::poll_fn(|cx| {
+                receives.retain_mut(|(tag, receive)| match Pin::new(receive).poll(cx) {
+                    Poll::Ready(status) => {
+                        assert_eq!(*tag, status.tag());
+                        assert_eq!(previous.rank(), status.source_rank());
+                        false
+                    }
+                    Poll::Pending => true,
+                });
+                if receives.is_empty() {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+
+            for send in sends {
+                send.await;
+            }
+        })
+    });
+    let expected: Vec<Rank> = (0..size).map(|i| previous.rank() * size + i).collect();
+    assert_eq!(expected, results);
+
+    // Dropping a pending future cancels its operation, whether it has been polled or not. No
+    // message is ever sent with this tag.
+    let unmatched_tag = size;
+    let mut unmatched: [Rank; 2] = [-1; 2];
+    request::scope(|scope| {
+        let (first, second) = unmatched.split_at_mut(1);
+        let mut polled = previous
+            .immediate_receive_into_with_tag(scope, &mut first[0], unmatched_tag)
+            .into_future();
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut polled).poll(&mut cx).is_pending());
+        drop(polled);
+
+        let unpolled = previous
+            .immediate_receive_into_with_tag(scope, &mut second[0], unmatched_tag)
+            .into_future();
+        drop(unpolled);
+    });
+    assert_eq!([-1; 2], unmatched);
+}
@@ -0,0 +1,65 @@
+#![deny(warnings)]
+
+use mpi::{
+    datatype::{Distribution, Order, UserDatatype, View},
+    point_to_point as p2p,
+    topology::Rank,
+    traits::*,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // A (4 * size) x 3 array whose rows are dealt out to the processes in blocks of two.
+    let rows = 4 * size;
+    let global = (0..rows * 3).collect::<Vec<Rank>>();
+
+    let local = UserDatatype::darray(
+        size,
+        rank,
+        &[rows, 3],
+        &[Distribution::CyclicOf(2), Distribution::NotDistributed],
+        &[size, 1],
+        Order::C,
+        &Rank::equivalent_datatype(),
+    );
+    let mut received = vec![-1; 12];
+    {
+        let view = unsafe { View::with_count_and_datatype(&global[..], 1, &local) };
+        let this_process = world.this_process();
+        p2p::send_receive_into(&view, &this_process, &mut received[..], &this_process);
+    }
+
+    let expected = [
+        2 * rank,
+        2 * rank + 1,
+        2 * (rank + size),
+        2 * (rank + size) + 1,
+    ]
+    .iter()
+    .flat_map(|row| (0..3).map(move |column| row * 3 + column))
+    .collect::<Vec<_>>();
+    assert_eq!(expected, received);
+
+    // With a block distribution every process holds four consecutive rows.
+    let local = UserDatatype::darray(
+        size,
+        rank,
+        &[rows, 3],
+        &[Distribution::Block, Distribution::NotDistributed],
+        &[size, 1],
+        Order::C,
+        &Rank::equivalent_datatype(),
+    );
+    {
+        let view = unsafe { View::with_count_and_datatype(&global[..], 1, &local) };
+        let this_process = world.this_process();
+        p2p::send_receive_into(&view, &this_process, &mut received[..], &this_process);
+    }
+
+    let expected = (rank * 12..(rank + 1) * 12).collect::<Vec<_>>();
+    assert_eq!(expected, received);
+}
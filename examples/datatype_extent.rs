@@ -0,0 +1,49 @@
+#![deny(warnings)]
+
+use std::mem::size_of;
+
+use mpi::{
+    datatype::{MutView, UserDatatype, View},
+    point_to_point as p2p,
+    topology::Rank,
+    traits::*,
+    Address, Count,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let element = Rank::equivalent_datatype();
+    let element_size = size_of::<Rank>() as Address;
+    assert_eq!(size_of::<Rank>() as Count, element.size());
+    assert_eq!((0, element_size), element.extent());
+    assert_eq!((0, element_size), element.true_extent());
+
+    // One column of a 3 x 4 matrix stored in row-major order.
+    let column = UserDatatype::vector(3, 1, 4, &element);
+    assert_eq!(3 * size_of::<Rank>() as Count, column.size());
+    assert_eq!((0, 9 * element_size), column.extent());
+
+    // Shrinking the extent to a single element makes consecutive columns start one element apart.
+    let column = UserDatatype::resized(0, element_size, &column);
+    assert_eq!(3 * size_of::<Rank>() as Count, column.size());
+    assert_eq!((0, element_size), column.extent());
+    assert_eq!((0, 9 * element_size), column.true_extent());
+
+    let matrix = (0..12).map(|i| 100 * rank + i).collect::<Vec<_>>();
+    let mut transposed = vec![-1; 12];
+    {
+        let columns = unsafe { View::with_count_and_datatype(&matrix[..], 4, &column) };
+        let mut rows =
+            unsafe { MutView::with_count_and_datatype(&mut transposed[..], 12, &element) };
+        let this_process = world.this_process();
+        p2p::send_receive_into(&columns, &this_process, &mut rows, &this_process);
+    }
+
+    let expected = (0..12)
+        .map(|i| 100 * rank + (i % 3) * 4 + i / 3)
+        .collect::<Vec<_>>();
+    assert_eq!(expected, transposed);
+}
@@ -0,0 +1,90 @@
+#![deny(warnings)]
+
+use mpi::{
+    datatype::{DecodedDatatype, Distribution, Order, UserDatatype},
+    traits::*,
+};
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let int = i32::equivalent_datatype();
+    assert_eq!(DecodedDatatype::Named(int), int.decode());
+    assert!(!int.get_name().is_empty());
+
+    let vector = UserDatatype::vector(2, 2, 3, &int);
+    assert_eq!(
+        DecodedDatatype::Vector {
+            count: 2,
+            blocklength: 2,
+            stride: 3,
+            oldtype: Box::new(DecodedDatatype::Named(int)),
+        },
+        vector.decode()
+    );
+    assert_eq!(
+        format!("vector(2, 2, 3, {})", int.get_name()),
+        vector.decode().to_string()
+    );
+
+    // Derived datatypes are decoded down to the predefined datatypes.
+    let column = UserDatatype::resized(0, 4, &vector);
+    let structured = UserDatatype::structured(
+        &[1, 3],
+        &[0, 8],
+        &[f64::equivalent_datatype(), column.as_ref()],
+    );
+    match structured.decode() {
+        DecodedDatatype::Structured {
+            blocklengths,
+            displacements,
+            types,
+        } => {
+            assert_eq!(vec![1, 3], blocklengths);
+            assert_eq!(vec![0, 8], displacements);
+            assert_eq!(DecodedDatatype::Named(f64::equivalent_datatype()), types[0]);
+            assert_eq!(
+                DecodedDatatype::Resized {
+                    lb: 0,
+                    extent: 4,
+                    oldtype: Box::new(vector.decode()),
+                },
+                types[1]
+            );
+        }
+        other => panic!("unexpected datatype {}", other),
+    }
+
+    let subarray = UserDatatype::subarray(&[4, 5], &[2, 3], &[1, 1], Order::Fortran, &int);
+    assert_eq!(
+        DecodedDatatype::Subarray {
+            sizes: vec![4, 5],
+            subsizes: vec![2, 3],
+            starts: vec![1, 1],
+            order: Order::Fortran,
+            oldtype: Box::new(DecodedDatatype::Named(int)),
+        },
+        subarray.decode()
+    );
+
+    let distribs = [Distribution::CyclicOf(2), Distribution::NotDistributed];
+    let darray = UserDatatype::darray(2, 1, &[8, 3], &distribs, &[2, 1], Order::C, &int);
+    assert_eq!(
+        DecodedDatatype::Darray {
+            size: 2,
+            rank: 1,
+            gsizes: vec![8, 3],
+            distribs: distribs.to_vec(),
+            psizes: vec![2, 1],
+            order: Order::C,
+            oldtype: Box::new(DecodedDatatype::Named(int)),
+        },
+        darray.decode()
+    );
+
+    let duplicate = darray.dup();
+    assert_eq!(
+        DecodedDatatype::Duplicate(Box::new(darray.decode())),
+        duplicate.decode()
+    );
+}
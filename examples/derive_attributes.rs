@@ -0,0 +1,71 @@
+#![deny(warnings)]
+
+use mpi::{point_to_point as p2p, traits::*, Rank};
+
+/// An identifier that does not implement `Equivalence` itself
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+struct Id(u32);
+
+/// Flags that are transferred as opaque bytes, which is sound because every byte pattern is a
+/// valid value and there is no padding
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Flags {
+    mask: u8,
+    level: u8,
+}
+
+#[derive(Equivalence, Clone, Copy, Debug, PartialEq)]
+struct Particle {
+    position: [f64; 3],
+    #[mpi(unsafe_datatype = u32::equivalent_datatype())]
+    id: Id,
+    #[mpi(unsafe_bytes)]
+    flags: Flags,
+    // Cached data is not transferred, which leaves a hole at the end of the datatype.
+    #[mpi(unsafe_skip)]
+    norm: f64,
+}
+
+impl Particle {
+    fn new(rank: Rank, i: u32) -> Self {
+        let position = [f64::from(rank), f64::from(i), 1.0];
+        Particle {
+            position,
+            id: Id(rank.unsigned_abs() * 10 + i),
+            flags: Flags {
+                mask: 1 << i,
+                level: 3,
+            },
+            norm: position.iter().map(|x| x * x).sum::<f64>().sqrt(),
+        }
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next = world.process_at_rank((rank + 1) % size);
+    let previous = world.process_at_rank((rank + size - 1) % size);
+
+    let particles = [Particle::new(rank, 0), Particle::new(rank, 1)];
+    let mut received = [Particle {
+        position: [0.0; 3],
+        id: Id(0),
+        flags: Flags { mask: 0, level: 0 },
+        norm: -1.0,
+    }; 2];
+    p2p::send_receive_into(&particles, &next, &mut received, &previous);
+
+    // The skipped field keeps its value on the receiving side.
+    for (i, particle) in (0..).zip(&received) {
+        let expected = Particle {
+            norm: -1.0,
+            ..Particle::new(previous.rank(), i)
+        };
+        assert_eq!(&expected, particle);
+    }
+}
@@ -0,0 +1,63 @@
+#![deny(warnings)]
+
+use mpi::{point_to_point as p2p, traits::*};
+
+#[derive(Equivalence, Clone, Copy, Debug, Default, PartialEq)]
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+#[derive(Equivalence, Clone, Copy, Debug, PartialEq)]
+struct Samples<T, const N: usize>([T; N], u8);
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next = world.process_at_rank((rank + 1) % size);
+    let previous = world.process_at_rank((rank + size - 1) % size);
+
+    // Every instantiation of a generic struct has a datatype of its own.
+    let ints = Pair { a: rank, b: -rank };
+    let (received, _) = p2p::send_receive::<Pair<i32>, _, _, _>(&ints, &next, &previous);
+    assert_eq!(
+        Pair {
+            a: previous.rank(),
+            b: -previous.rank()
+        },
+        received
+    );
+
+    let floats = Pair {
+        a: f64::from(rank) / 2.0,
+        b: 0.5,
+    };
+    let (received, _) = p2p::send_receive::<Pair<f64>, _, _, _>(&floats, &next, &previous);
+    assert_eq!(f64::from(previous.rank()) / 2.0, received.a);
+    assert_eq!(0.5, received.b);
+
+    // Generic structs can be nested and use const parameters.
+    let nested = Pair {
+        a: Pair { a: 1u8, b: 2 },
+        b: Pair { a: 3, b: 4 },
+    };
+    let (received, _) = p2p::send_receive::<Pair<Pair<u8>>, _, _, _>(&nested, &next, &previous);
+    assert_eq!(nested, received);
+
+    let samples = Samples([rank; 3], 7);
+    let (received, _) = p2p::send_receive::<Samples<i32, 3>, _, _, _>(&samples, &next, &previous);
+    assert_eq!(Samples([previous.rank(); 3], 7), received);
+
+    // The datatypes are cached, so they are only built once per type.
+    assert_eq!(
+        Pair::<f64>::equivalent_datatype(),
+        Pair::<f64>::equivalent_datatype()
+    );
+    assert_ne!(
+        Pair::<f64>::equivalent_datatype(),
+        Pair::<i32>::equivalent_datatype()
+    );
+}
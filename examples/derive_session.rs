@@ -0,0 +1,48 @@
+#![deny(warnings)]
+
+#[cfg(not(mpi4))]
+fn main() {
+    // Sessions were introduced in MPI-4.
+}
+
+#[cfg(mpi4)]
+fn main() {
+    use mpi::{session::Session, topology::SimpleCommunicator, traits::*};
+
+    #[derive(Equivalence, Clone, Copy, Debug, Default, PartialEq)]
+    struct Sample {
+        rank: i32,
+        value: f64,
+    }
+
+    fn gather_samples(session: &Session) {
+        let group = session.group_from_pset("mpi://WORLD").unwrap();
+        let comm = SimpleCommunicator::from_group(&group, "org.rsmpi.examples.derive_session");
+        let rank = comm.rank();
+
+        let sample = Sample {
+            rank,
+            value: f64::from(rank) / 2.0,
+        };
+        let mut samples = vec![Sample::default(); comm.size() as usize];
+        comm.all_gather_into(&sample, &mut samples[..]);
+        for (i, sample) in (0..).zip(&samples) {
+            assert_eq!(
+                Sample {
+                    rank: i,
+                    value: f64::from(i) / 2.0
+                },
+                *sample
+            );
+        }
+    }
+
+    // A library that only uses sessions can communicate derived types without the world model.
+    let session = Session::init();
+    gather_samples(&session);
+    drop(session);
+
+    // The datatype remains valid for sessions that are initialized later on.
+    let session = Session::init();
+    gather_samples(&session);
+}
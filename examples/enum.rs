@@ -0,0 +1,51 @@
+#![deny(warnings)]
+
+use mpi::{datatype::Checked, point_to_point as p2p, traits::*, Rank};
+
+#[derive(Equivalence, Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+enum State {
+    Idle,
+    Running = 4,
+    Done,
+}
+
+#[derive(Equivalence, Clone, Copy, Debug, PartialEq)]
+struct Report {
+    rank: Rank,
+    state: Checked<State>,
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next = world.process_at_rank((rank + 1) % size);
+    let previous = world.process_at_rank((rank + size - 1) % size);
+
+    // Enums are communicated through their integer representation.
+    assert_eq!(4, State::Running.to_repr());
+    assert_eq!(Some(State::Done), State::from_repr(5));
+    assert_eq!(None, State::from_repr(1));
+
+    let state = Checked::new(State::Running);
+    let (received, _) = p2p::send_receive::<Checked<State>, _, _, _>(&state, &next, &previous);
+    assert_eq!(Some(State::Running), received.get());
+
+    // Checked enums can be fields of structs that derive `Equivalence`.
+    let report = Report {
+        rank,
+        state: State::Done.into(),
+    };
+    let (received, _) = p2p::send_receive::<Report, _, _, _>(&report, &next, &previous);
+    assert_eq!(previous.rank(), received.rank);
+    assert_eq!(Some(State::Done), received.state.get());
+
+    // An invalid discriminant is received as an integer and never turns into a `State`.
+    let (received, _) = p2p::send_receive::<Checked<State>, _, _, _>(&1u8, &next, &previous);
+    assert_eq!(1, received.repr());
+    assert_eq!(None, received.get());
+    assert_ne!(Checked::new(State::Idle), received);
+}
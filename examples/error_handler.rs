@@ -0,0 +1,120 @@
+#![deny(warnings)]
+
+#[cfg(feature = "user-operations")]
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc,
+};
+
+#[cfg(feature = "user-operations")]
+use mpi::{
+    environment::{self, ErrorHandler},
+    ffi,
+    io::OpenOptions,
+    traits::*,
+    window::Window,
+    Error, MpiError,
+};
+
+#[cfg(feature = "user-operations")]
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    // A library registers its own error class, with an error code and a description.
+    let class = environment::add_error_class();
+    let code = environment::add_error_code(class);
+    environment::add_error_string(code, "the library ran out of patience").unwrap();
+
+    let last_error = Arc::new(AtomicI32::new(0));
+    let handler = {
+        let last_error = Arc::clone(&last_error);
+        ErrorHandler::new(move |comm, code| {
+            assert_eq!(rank, comm.rank());
+            last_error.store(code, Ordering::SeqCst);
+        })
+    };
+
+    let comm = world.duplicate();
+    // Only `try_` variants of operations fail on `comm` and its duplicates.
+    unsafe { comm.set_error_handler(&handler) };
+
+    comm.call_error_handler(code);
+    assert_eq!(code, last_error.load(Ordering::SeqCst));
+    match MpiError::from_code(code) {
+        MpiError::Mpi {
+            class: error_class,
+            message,
+            ..
+        } => {
+            assert_eq!(class, error_class);
+            assert_eq!("the library ran out of patience", message);
+        }
+        _ => unreachable!(),
+    }
+
+    // Errors raised by MPI are passed to the handler, after which the operation returns them.
+    assert!(comm.this_process().try_send_with_tag(&rank, -42).is_err());
+    match MpiError::from_code(last_error.load(Ordering::SeqCst)) {
+        MpiError::Mpi { class, .. } => assert_eq!(ffi::MPI_ERR_TAG as Error, class),
+        _ => unreachable!(),
+    }
+
+    // Duplicates inherit the error handler.
+    last_error.store(0, Ordering::SeqCst);
+    let duplicate = comm.duplicate();
+    duplicate.call_error_handler(code);
+    assert_eq!(code, last_error.load(Ordering::SeqCst));
+
+    // Error handlers can be retrieved and attached to other communicators.
+    let inherited = duplicate.get_error_handler();
+    last_error.store(0, Ordering::SeqCst);
+    unsafe { duplicate.set_error_handler(&ErrorHandler::errors_return()) };
+    assert!(duplicate
+        .this_process()
+        .try_send_with_tag(&rank, -42)
+        .is_err());
+    assert_eq!(0, last_error.load(Ordering::SeqCst));
+    unsafe { world.set_error_handler(&inherited) };
+    world.call_error_handler(code);
+    assert_eq!(code, last_error.load(Ordering::SeqCst));
+    unsafe { world.set_error_handler(&ErrorHandler::errors_are_fatal()) };
+
+    // Windows and files have error handlers of their own.
+    let mut local = [0u8; 4];
+    let window = Window::create(&world, &mut local[..]);
+    let window_error = Arc::new(AtomicI32::new(0));
+    let window_handler = {
+        let window_error = Arc::clone(&window_error);
+        ErrorHandler::new_for_windows(move |code| window_error.store(code, Ordering::SeqCst))
+    };
+    // Only `call_error_handler()` raises errors on the window.
+    unsafe { window.set_error_handler(&window_handler) };
+    window.call_error_handler(code);
+    assert_eq!(code, window_error.load(Ordering::SeqCst));
+    drop(window);
+
+    let path = std::env::temp_dir().join("rsmpi_error_handler_example.dat");
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .delete_on_close(true)
+        .open(&world, &path)
+        .unwrap();
+    let file_error = Arc::new(AtomicI32::new(0));
+    let file_handler = {
+        let file_error = Arc::clone(&file_error);
+        ErrorHandler::new_for_files(move |file, code| {
+            assert!(file.size() >= 0);
+            file_error.store(code, Ordering::SeqCst);
+        })
+    };
+    // Only `call_error_handler()` raises errors on the file.
+    unsafe { file.set_error_handler(&file_handler) };
+    file.call_error_handler(code);
+    assert_eq!(code, file_error.load(Ordering::SeqCst));
+}
+
+#[cfg(not(feature = "user-operations"))]
+fn main() {}
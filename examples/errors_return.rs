@@ -0,0 +1,57 @@
+#![deny(warnings)]
+
+use mpi::{collective::SystemOperation, ffi, traits::*, Error, MpiError, Rank};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    // Only `try_` variants of operations fail from here on.
+    unsafe { world.set_errors_return() };
+
+    // Invalid arguments are reported as errors instead of aborting the program.
+    match world.try_process_at_rank(size) {
+        Err(MpiError::Mpi { class, .. }) => assert_eq!(ffi::MPI_ERR_RANK as Error, class),
+        _ => panic!("expected an invalid rank error"),
+    }
+    match world.this_process().try_send_with_tag(&rank, -42) {
+        Err(MpiError::Mpi { class, message, .. }) => {
+            assert_eq!(ffi::MPI_ERR_TAG as Error, class);
+            assert!(!message.is_empty());
+        }
+        _ => panic!("expected an invalid tag error"),
+    }
+
+    // The communicator is still usable afterwards.
+    let mut sum: Rank = 0;
+    world
+        .try_all_reduce_into(&rank, &mut sum, SystemOperation::sum())
+        .unwrap();
+    assert_eq!(size * (size - 1) / 2, sum);
+
+    let mut value = if rank == 0 { 42 } else { 0 };
+    world
+        .try_process_at_rank(0)
+        .unwrap()
+        .try_broadcast_into(&mut value)
+        .unwrap();
+    assert_eq!(42, value);
+
+    if size > 1 {
+        if rank == 0 {
+            world.process_at_rank(1).try_send(&value).unwrap();
+        } else if rank == 1 {
+            let mut received: Rank = 0;
+            let status = world
+                .process_at_rank(0)
+                .try_receive_into(&mut received)
+                .unwrap();
+            assert_eq!(0, status.source_rank());
+            assert_eq!(42, received);
+        }
+    }
+
+    world.try_barrier().unwrap();
+}
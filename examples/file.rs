@@ -0,0 +1,59 @@
+#![deny(warnings)]
+
+use std::mem::size_of;
+
+use mpi::{
+    datatype::UserDatatype,
+    io::{Offset, OpenOptions, Whence},
+    traits::*,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let path = std::env::temp_dir().join("rsmpi_file_example.dat");
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .delete_on_close(true)
+        .open(&world, &path)
+        .unwrap();
+    file.set_size(0);
+
+    // Every process writes its own block of the file.
+    let n = 4;
+    let bytes = size_of::<i32>() as Offset;
+    let block: Vec<i32> = (0..n).map(|i| rank * n + i).collect();
+    file.write_at_all(Offset::from(rank * n) * bytes, &block[..]);
+
+    // Make the writes of all processes visible to all processes.
+    file.sync();
+    world.barrier();
+    file.sync();
+
+    let expected: Vec<i32> = (0..size * n).collect();
+    let mut all = vec![0; (size * n) as usize];
+    file.read_at_all(0, &mut all[..]);
+    assert_eq!(all, expected);
+
+    // The shared file pointer hands out the blocks in rank order.
+    file.seek_shared(0, Whence::Start);
+    let mut own = vec![0; n as usize];
+    file.read_ordered(&mut own[..]);
+    assert_eq!(own, block);
+
+    // A view that only shows the block of the calling process.
+    let filetype = UserDatatype::contiguous(n, &i32::equivalent_datatype());
+    file.set_view(
+        Offset::from(rank * n) * bytes,
+        &i32::equivalent_datatype(),
+        &filetype,
+    );
+    own.iter_mut().for_each(|x| *x = 0);
+    file.read_all(&mut own[..]);
+    assert_eq!(own, block);
+}
@@ -0,0 +1,70 @@
+#![deny(warnings)]
+
+use mpi::{
+    topology::{DistributedGraphNeighbors, IntoTopology},
+    traits::*,
+    Count, Rank,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let left = (rank + size - 1) % size;
+    let right = (rank + 1) % size;
+
+    // A ring, where every node is connected to the nodes to its left and right.
+    let index: Vec<Count> = (1..=size).map(|i| 2 * i).collect();
+    let edges: Vec<Rank> = (0..size)
+        .flat_map(|r| [(r + size - 1) % size, (r + 1) % size])
+        .collect();
+    let graph = world
+        .create_graph_communicator(&index, &edges, false)
+        .expect("every process is a node of the graph");
+
+    assert_eq!((size, 2 * size), graph.num_nodes_and_edges());
+    let layout = graph.get_layout();
+    assert_eq!(index, layout.index);
+    assert_eq!(edges, layout.edges);
+    assert_eq!(2, graph.num_neighbors(rank));
+    assert_eq!(vec![left, right], graph.neighbors(rank));
+
+    // A directed ring, where every process specifies the edge to the process on its right.
+    let dist_graph =
+        world.create_dist_graph_communicator(&[rank], &[1], &[right], Some(&[rank][..]), false);
+    assert_eq!((1, 1, true), dist_graph.num_neighbors());
+    let DistributedGraphNeighbors {
+        sources,
+        source_weights,
+        destinations,
+        destination_weights,
+    } = dist_graph.neighbors();
+    assert_eq!(vec![left], sources);
+    assert_eq!(Some(vec![left]), source_weights);
+    assert_eq!(vec![right], destinations);
+    assert_eq!(Some(vec![rank]), destination_weights);
+
+    // The same directed ring, but every process specifies exactly its own edges.
+    let adjacent =
+        world.create_dist_graph_communicator_adjacent(&[left], None, &[right], None, false);
+    assert_eq!((1, 1, false), adjacent.num_neighbors());
+    let neighbors = adjacent.neighbors();
+    assert_eq!(vec![left], neighbors.sources);
+    assert_eq!(None, neighbors.source_weights);
+    assert_eq!(vec![right], neighbors.destinations);
+    assert_eq!(None, neighbors.destination_weights);
+
+    // Duplicates keep the topology of the original communicator.
+    match graph.duplicate().into_topology() {
+        IntoTopology::Graph(duplicate) => assert_eq!(vec![left, right], duplicate.neighbors(rank)),
+        _ => panic!("expected a graph communicator"),
+    }
+    match adjacent.duplicate().into_topology() {
+        IntoTopology::DistributedGraph(duplicate) => {
+            assert_eq!((1, 1, false), duplicate.num_neighbors())
+        }
+        _ => panic!("expected a distributed graph communicator"),
+    }
+}
@@ -0,0 +1,86 @@
+#![deny(warnings)]
+
+use std::mem;
+
+use mpi::{
+    collective::BlockLayout,
+    datatype::{Partition, PartitionMut},
+    traits::*,
+    Address, Count, Rank,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let left = (rank + size - 1) % size;
+    let right = (rank + 1) % size;
+
+    let ring = world
+        .create_cartesian_communicator(&[size], &[true], false)
+        .unwrap();
+
+    let mut gathered = [-1; 2];
+    mpi::request::scope(|scope| {
+        ring.immediate_neighbor_all_gather_into(scope, &rank, &mut gathered[..])
+            .wait();
+    });
+    assert_eq!([left, right], gathered);
+
+    let outgoing = [10 * rank, 10 * rank + 1];
+    let mut incoming = [-1; 2];
+    mpi::request::scope(|scope| {
+        ring.immediate_neighbor_all_to_all_into(scope, &outgoing[..], &mut incoming[..])
+            .wait();
+    });
+    assert_eq!([10 * left + 1, 10 * right], incoming);
+
+    let counts: [Count; 2] = [1, 1];
+    let displs: [Count; 2] = [0, 1];
+    let mut incoming = [-1; 2];
+    {
+        let mut partition = PartitionMut::new(&mut incoming[..], &counts[..], &displs[..]);
+        mpi::request::scope(|scope| {
+            ring.immediate_neighbor_all_gather_varcount_into(scope, &rank, &mut partition)
+                .wait();
+        });
+    }
+    assert_eq!([left, right], incoming);
+
+    // Send one element to the left and two elements to the right.
+    let outgoing = [rank, rank, rank];
+    let send_counts: [Count; 2] = [1, 2];
+    let send_displs: [Count; 2] = [0, 1];
+    let recv_counts: [Count; 2] = [2, 1];
+    let recv_displs: [Count; 2] = [0, 2];
+    let mut incoming = [-1; 3];
+    {
+        let partition = Partition::new(&outgoing[..], &send_counts[..], &send_displs[..]);
+        let mut partition_mut =
+            PartitionMut::new(&mut incoming[..], &recv_counts[..], &recv_displs[..]);
+        mpi::request::scope(|scope| {
+            ring.immediate_neighbor_all_to_all_varcount_into(scope, &partition, &mut partition_mut)
+                .wait();
+        });
+    }
+    assert_eq!([left, left, right], incoming);
+
+    let datatypes = [Rank::equivalent_datatype(), Rank::equivalent_datatype()];
+    let element = mem::size_of::<Rank>() as Address;
+    let send_byte_displs = [0, element];
+    let recv_byte_displs = [0, 2 * element];
+    let mut incoming = [-1; 3];
+    mpi::request::scope(|scope| unsafe {
+        ring.immediate_neighbor_all_to_all_w_into(
+            scope,
+            &outgoing[..],
+            BlockLayout::new(&send_counts, &send_byte_displs, &datatypes),
+            &mut incoming[..],
+            BlockLayout::new(&recv_counts, &recv_byte_displs, &datatypes),
+        )
+        .wait();
+    });
+    assert_eq!([left, left, right], incoming);
+}
@@ -7,9 +7,11 @@ use std::os::raw::{c_int, c_void};
 use mpi::collective::UserOperation;
 use mpi::{
     collective::{SystemOperation, UnsafeUserOperation},
+    datatype::PartitionMut,
     ffi::MPI_Datatype,
     topology::Rank,
     traits::*,
+    Count,
 };
 
 #[cfg(feature = "user-operations")]
@@ -110,6 +112,31 @@ fn main() {
     });
     assert_eq!(b, rank.wrapping_pow(size as u32));
 
+    // Every process receives one element of the result, except for the last one, which receives
+    // the remaining two.
+    let counts: Vec<Count> = (0..size)
+        .map(|r| if r == size - 1 { 2 } else { 1 })
+        .collect();
+    let displs: Vec<Count> = (0..size).collect();
+    let e = vec![rank + 1; size as usize + 1];
+    let mut f = vec![0; size as usize + 1];
+    {
+        let mut partition = PartitionMut::new(&mut f[..], &counts[..], &displs[..]);
+        mpi::request::scope(|scope| {
+            world
+                .immediate_reduce_scatter_into(
+                    scope,
+                    &e[..],
+                    &mut partition,
+                    SystemOperation::sum(),
+                )
+                .wait();
+        });
+    }
+    let start = displs[rank as usize] as usize;
+    let end = start + counts[rank as usize] as usize;
+    assert!(f[start..end].iter().all(|&x| x == size * (size + 1) / 2));
+
     test_user_operations(universe.world());
 
     let mut d = 0;
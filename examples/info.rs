@@ -0,0 +1,41 @@
+#![deny(warnings)]
+
+use mpi::{info::Info, traits::*};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let mut info = Info::builder()
+        .set("mpi_assert_no_any_tag", "true")
+        .set("rsmpi_example", "info")
+        .build()
+        .unwrap();
+    assert_eq!(2, info.len());
+    assert_eq!(Some("info".to_string()), info.get("rsmpi_example"));
+    assert_eq!(None, info.get("rsmpi_missing"));
+
+    let mut keys: Vec<String> = info.keys().collect();
+    keys.sort();
+    assert_eq!(vec!["mpi_assert_no_any_tag", "rsmpi_example"], keys);
+
+    info.set("rsmpi_example", "replaced").unwrap();
+    assert_eq!(Some("replaced".to_string()), info.get("rsmpi_example"));
+    assert!(info.set("", "empty key").is_err());
+    assert!(info.set("nul\0key", "value").is_err());
+
+    let copy = info.clone();
+    assert!(info.delete("rsmpi_example"));
+    assert!(!info.delete("rsmpi_example"));
+    assert_eq!(1, info.len());
+    assert_eq!(2, copy.len());
+
+    let comm = world.duplicate_with_info(&copy);
+    assert_eq!(world.size(), comm.size());
+
+    // MPI only reports the hints it actually uses, so unknown keys may be missing.
+    let used = comm.get_info();
+    assert!(used.keys().all(|key| used.get(&key).is_some()));
+
+    comm.set_info(&info);
+}
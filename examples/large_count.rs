@@ -0,0 +1,58 @@
+#![deny(warnings)]
+
+use mpi::{
+    collective::SystemOperation, datatype::PartitionMut, point_to_point as p2p, traits::*,
+    LargeCount, Rank,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next = world.process_at_rank((rank + 1) % size);
+    let previous = world.process_at_rank((rank + size - 1) % size);
+
+    // Buffers of any length are sent, the count only has to fit into a `LargeCount`.
+    let msg: Vec<Rank> = (0..1000).map(|i| rank * 1000 + i).collect();
+    assert_eq!(1000, msg.large_count());
+
+    let mut buf = vec![-1; msg.len()];
+    let status = p2p::send_receive_into(&msg[..], &next, &mut buf[..], &previous);
+    assert_eq!(
+        buf.large_count(),
+        status.large_count(Rank::equivalent_datatype())
+    );
+    assert_eq!(
+        LargeCount::from(status.count(Rank::equivalent_datatype())),
+        status.large_count(Rank::equivalent_datatype())
+    );
+
+    let expected: Vec<Rank> = (0..1000).map(|i| previous.rank() * 1000 + i).collect();
+    assert_eq!(expected, buf);
+
+    // The blocking collective operations accept large counts too, and partitions may be given as
+    // `LargeCount`.
+    let mut all = vec![-1; msg.len() * size as usize];
+    world.all_gather_into(&msg[..], &mut all[..]);
+    assert_eq!((0..size * 1000).collect::<Vec<Rank>>(), all);
+
+    let counts: Vec<LargeCount> = vec![msg.large_count(); size as usize];
+    let displs: Vec<LargeCount> = (0..size)
+        .map(|r| LargeCount::from(r) * msg.large_count())
+        .collect();
+    let mut gathered = vec![-1; all.len()];
+    {
+        let mut partition = PartitionMut::new(&mut gathered[..], &counts[..], &displs[..]);
+        world.all_gather_varcount_into(&msg[..], &mut partition);
+    }
+    assert_eq!(all, gathered);
+
+    let mut sum = vec![0; msg.len()];
+    world.all_reduce_into(&msg[..], &mut sum[..], SystemOperation::sum());
+    let expected: Vec<Rank> = (0..1000)
+        .map(|i| 1000 * size * (size - 1) / 2 + size * i)
+        .collect();
+    assert_eq!(expected, sum);
+}
@@ -0,0 +1,96 @@
+#![deny(warnings)]
+
+use std::mem;
+
+use mpi::{
+    collective::BlockLayout,
+    datatype::{Partition, PartitionMut},
+    traits::*,
+    Address, Count, Rank,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let left = (rank + size - 1) % size;
+    let right = (rank + 1) % size;
+
+    // In a periodic one-dimensional Cartesian communicator the neighbors are the processes to the
+    // left and to the right.
+    let ring = world
+        .create_cartesian_communicator(&[size], &[true], false)
+        .unwrap();
+    assert_eq!((2, 2), ring.neighborhood_size());
+
+    let mut gathered = [-1; 2];
+    ring.neighbor_all_gather_into(&rank, &mut gathered[..]);
+    assert_eq!([left, right], gathered);
+
+    let outgoing = [10 * rank, 10 * rank + 1];
+    let mut incoming = [-1; 2];
+    ring.neighbor_all_to_all_into(&outgoing[..], &mut incoming[..]);
+    assert_eq!([10 * left + 1, 10 * right], incoming);
+
+    // Process 0 sends two elements, all other processes send one.
+    let count_of = |r: Rank| -> Count {
+        if r == 0 {
+            2
+        } else {
+            1
+        }
+    };
+    let outgoing: Vec<Rank> = vec![rank; count_of(rank) as usize];
+    let counts: Vec<Count> = vec![count_of(left), count_of(right)];
+    let displs: Vec<Count> = vec![0, counts[0]];
+    let mut incoming = vec![-1; (counts[0] + counts[1]) as usize];
+    {
+        let mut partition = PartitionMut::new(&mut incoming[..], &counts[..], &displs[..]);
+        ring.neighbor_all_gather_varcount_into(&outgoing[..], &mut partition);
+    }
+    let (from_left, from_right) = incoming.split_at(counts[0] as usize);
+    assert!(from_left.iter().all(|&r| r == left));
+    assert!(from_right.iter().all(|&r| r == right));
+
+    // Send one element to the left and two elements to the right.
+    let outgoing = [rank, rank, rank];
+    let send_counts: [Count; 2] = [1, 2];
+    let send_displs: [Count; 2] = [0, 1];
+    let recv_counts: [Count; 2] = [2, 1];
+    let recv_displs: [Count; 2] = [0, 2];
+    let mut incoming = [-1; 3];
+    {
+        let partition = Partition::new(&outgoing[..], &send_counts[..], &send_displs[..]);
+        let mut partition_mut =
+            PartitionMut::new(&mut incoming[..], &recv_counts[..], &recv_displs[..]);
+        ring.neighbor_all_to_all_varcount_into(&partition, &mut partition_mut);
+    }
+    assert_eq!([left, left, right], incoming);
+
+    // The same exchange, but with per-peer datatypes and byte displacements.
+    let datatypes = [Rank::equivalent_datatype(), Rank::equivalent_datatype()];
+    let element = mem::size_of::<Rank>() as Address;
+    let send_byte_displs = [0, element];
+    let recv_byte_displs = [0, 2 * element];
+    let mut incoming = [-1; 3];
+    unsafe {
+        ring.neighbor_all_to_all_w_into(
+            &outgoing[..],
+            BlockLayout::new(&send_counts, &send_byte_displs, &datatypes),
+            &mut incoming[..],
+            BlockLayout::new(&recv_counts, &recv_byte_displs, &datatypes),
+        );
+    }
+    assert_eq!([left, left, right], incoming);
+
+    // In a directed ring every process receives from the left and sends to the right.
+    let directed =
+        world.create_dist_graph_communicator_adjacent(&[left], None, &[right], None, false);
+    assert_eq!((1, 1), directed.neighborhood_size());
+
+    let mut from_left: Rank = -1;
+    directed.neighbor_all_to_all_into(&rank, &mut from_left);
+    assert_eq!(left, from_left);
+}
@@ -0,0 +1,41 @@
+#![deny(warnings)]
+
+use mpi::{datatype, traits::*};
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    // The external32 representation is big-endian on every platform.
+    let ints = [0x0102_0304i32, -2];
+    let packed = datatype::pack_external(&ints[..]);
+    let expected = ints
+        .iter()
+        .flat_map(|i| i.to_be_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(expected, packed);
+    assert_eq!(
+        8,
+        datatype::pack_external_size(2, &i32::equivalent_datatype())
+    );
+
+    let mut new_ints = [0, 0];
+    let position = unsafe { datatype::unpack_external_into(&packed, &mut new_ints[..], 0) };
+    assert_eq!(8, position);
+    assert_eq!(ints, new_ints);
+
+    // Several buffers can be packed into a single record.
+    let value = 1.5f64;
+    let mut record = vec![0; 16];
+    let position = datatype::pack_external_into(&value, &mut record[..], 0);
+    let position = datatype::pack_external_into(&ints[..], &mut record[..], position);
+    assert_eq!(16, position);
+    assert_eq!(value.to_be_bytes(), record[..8]);
+
+    let mut new_value = 0.0f64;
+    unsafe {
+        let position = datatype::unpack_external_into(&record, &mut new_value, 0);
+        datatype::unpack_external_into(&record, &mut new_ints[..], position);
+    }
+    assert_eq!(value, new_value);
+    assert_eq!(ints, new_ints);
+}
@@ -0,0 +1,84 @@
+#![deny(warnings)]
+
+#[cfg(not(mpi4))]
+fn main() {
+    // Partitioned communication was introduced in MPI-4.
+}
+
+#[cfg(mpi4)]
+fn main() {
+    use std::thread;
+
+    use mpi::{request::RequestPartition, traits::*, Rank, Threading};
+
+    const PARTITIONS: usize = 4;
+    const PARTITION_LEN: usize = 8;
+
+    fn value(rank: Rank, step: Rank, partition: usize) -> Rank {
+        rank * 100 + step * 10 + Rank::try_from(partition).unwrap()
+    }
+
+    fn fill(mut partition: RequestPartition<Rank>, rank: Rank, step: Rank) {
+        let value = value(rank, step, partition.index());
+        partition.iter_mut().for_each(|x| *x = value);
+        partition.ready();
+    }
+
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next = world.process_at_rank((rank + 1) % size);
+    let previous = world.process_at_rank((rank + size - 1) % size);
+
+    let mut send_buffer = vec![0; PARTITIONS * PARTITION_LEN];
+    let mut receive_buffer = vec![-1; PARTITIONS * PARTITION_LEN];
+    mpi::request::scope(|scope| {
+        let mut send = next.partitioned_send_init(scope, &mut send_buffer[..], PARTITIONS);
+        let mut receive =
+            previous.partitioned_receive_init(scope, &mut receive_buffer[..], PARTITIONS);
+
+        for step in 0..3 {
+            receive.start();
+            send.start();
+
+            // Every partition is filled and marked ready by a thread of its own.
+            if threading == Threading::Multiple {
+                thread::scope(|threads| {
+                    for partition in send.partitions_mut() {
+                        threads.spawn(move || fill(partition, rank, step));
+                    }
+                });
+            } else {
+                for partition in send.partitions_mut() {
+                    fill(partition, rank, step);
+                }
+            }
+            // Partitions that have been marked ready are not handed out again until the next start.
+            assert_eq!(0, send.partitions_mut().count());
+
+            // Received partitions can be used as soon as they have arrived.
+            {
+                let mut pending: Vec<_> = receive.partitions_mut().collect();
+                while !pending.is_empty() {
+                    pending.retain_mut(|partition| {
+                        if !partition.arrived() {
+                            return true;
+                        }
+                        let expected = value(previous.rank(), step, partition.index());
+                        assert!(partition.iter().all(|&x| x == expected));
+                        false
+                    });
+                }
+            }
+
+            send.wait();
+            receive.wait();
+            assert_eq!(send.data()[0], value(rank, step, 0));
+        }
+    });
+
+    let last = value(previous.rank(), 2, PARTITIONS - 1);
+    assert_eq!(receive_buffer[PARTITIONS * PARTITION_LEN - 1], last);
+}
@@ -0,0 +1,52 @@
+#![deny(warnings)]
+
+use mpi::{request::PersistentRequestCollection, traits::*};
+
+const STEPS: i32 = 5;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Pass values around a ring, reusing the same requests in every step.
+    let mut send_buffer = [0i32; 2];
+    let mut receive_buffer = [0i32; 2];
+    mpi::request::scope(|scope| {
+        let mut send = next_process.send_init(scope, &mut send_buffer);
+        let mut receive = previous_process.receive_init(scope, &mut receive_buffer);
+
+        for step in 0..STEPS {
+            *send.data_mut() = [rank, step];
+            receive.start();
+            send.start();
+            send.wait();
+            let status = receive.wait();
+            assert_eq!(status.source_rank(), previous_rank);
+            assert_eq!(*receive.data(), [previous_rank, step]);
+        }
+    });
+
+    // The same exchange, started and completed as a batch.
+    let mut outgoing = [rank];
+    let mut incoming = [-1];
+    mpi::request::scope(|scope| {
+        let mut requests = PersistentRequestCollection::new();
+        let receive = requests.add(previous_process.receive_init(scope, &mut incoming[..]));
+        let send = requests.add(next_process.send_init(scope, &mut outgoing[..]));
+
+        let mut statuses = Vec::new();
+        for step in 0..STEPS {
+            requests.data_mut(send)[0] = rank * STEPS + step;
+            requests.start_all();
+            requests.wait_all(&mut statuses);
+            assert_eq!(statuses.len(), 2);
+            assert_eq!(requests.data(receive)[0], previous_rank * STEPS + step);
+        }
+    });
+}
@@ -0,0 +1,72 @@
+#![deny(warnings)]
+
+#[cfg(not(mpi4))]
+fn main() {
+    // Persistent collective operations were introduced in MPI-4.
+}
+
+#[cfg(mpi4)]
+fn main() {
+    use mpi::{collective::SystemOperation, traits::*, Rank};
+
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+    let root_process = world.process_at_rank(0);
+
+    let mut barrier = world.barrier_init();
+
+    // Set up the reduction once and run it in every step of the loop.
+    let mut local: Rank = 0;
+    let mut total: Rank = 0;
+    mpi::request::scope(|scope| {
+        let mut sum = world.all_reduce_init(scope, &mut local, &mut total, SystemOperation::sum());
+        for step in 0..3 {
+            *sum.send_data_mut() = rank + step;
+            sum.start();
+            sum.wait();
+            assert_eq!(*sum.data(), size * (size - 1) / 2 + size * step);
+
+            barrier.start();
+            barrier.wait();
+        }
+    });
+
+    let mut x = [0; 4];
+    mpi::request::scope(|scope| {
+        let mut broadcast = root_process.broadcast_init(scope, &mut x[..]);
+        for step in 0..3 {
+            if rank == 0 {
+                broadcast.data_mut().fill(step);
+            }
+            broadcast.start();
+            broadcast.wait();
+            assert!(broadcast.data().iter().all(|&v| v == step));
+        }
+    });
+
+    let mut i = rank;
+    if rank == 0 {
+        let mut a = vec![0; size as usize];
+        mpi::request::scope(|scope| {
+            let mut gather = root_process.gather_init_root(scope, &mut i, &mut a[..]);
+            for step in 0..3 {
+                *gather.send_data_mut() = step;
+                gather.start();
+                gather.wait();
+                let expected: Vec<Rank> = (0..size).map(|r| r + step).collect();
+                assert_eq!(gather.data(), &expected[..]);
+            }
+        });
+    } else {
+        mpi::request::scope(|scope| {
+            let mut gather = root_process.gather_init(scope, &mut i);
+            for step in 0..3 {
+                *gather.data_mut() = rank + step;
+                gather.start();
+                gather.wait();
+            }
+        });
+    }
+}
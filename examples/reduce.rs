@@ -8,9 +8,11 @@ use std::os::raw::{c_int, c_void};
 use mpi::collective::UserOperation;
 use mpi::{
     collective::{self, SystemOperation, UnsafeUserOperation},
+    datatype::PartitionMut,
     ffi::MPI_Datatype,
     topology::Rank,
     traits::*,
+    Count,
 };
 
 #[cfg(feature = "user-operations")]
@@ -112,6 +114,28 @@ fn main() {
     world.reduce_scatter_block_into(&f[..], &mut g, SystemOperation::product());
     assert_eq!(g, rank.wrapping_pow(size as u32));
 
+    // Process `r` receives `r + 1` elements of the result.
+    let counts: Vec<Count> = (1..=size).collect();
+    let displs: Vec<Count> = counts
+        .iter()
+        .scan(0, |acc, &x| {
+            let tmp = *acc;
+            *acc += x;
+            Some(tmp)
+        })
+        .collect();
+    let total = counts.iter().sum::<Count>() as usize;
+    let h = vec![rank; total];
+    let mut k = vec![-1; total];
+    {
+        let mut partition = PartitionMut::new(&mut k[..], &counts[..], &displs[..]);
+        world.reduce_scatter_into(&h[..], &mut partition, SystemOperation::sum());
+    }
+    let start = displs[rank as usize] as usize;
+    let end = start + counts[rank as usize] as usize;
+    assert!(k[start..end].iter().all(|&x| x == size * (size - 1) / 2));
+    assert!(k[..start].iter().chain(&k[end..]).all(|&x| x == -1));
+
     test_user_operations(universe.world());
 
     let mut i = 0;
@@ -0,0 +1,64 @@
+#![deny(warnings)]
+
+use std::collections::HashMap;
+
+use mpi::{traits::*, MpiError, Rank};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    // Values of any size are sent, the receiver probes the message to size its buffer.
+    if size > 1 {
+        if rank == 0 {
+            let mut ranks: HashMap<String, Vec<Rank>> = HashMap::new();
+            ranks.insert("even".to_string(), (0..size).step_by(2).collect());
+            ranks.insert("odd".to_string(), (1..size).step_by(2).collect());
+            world.process_at_rank(1).send_serialized(&ranks).unwrap();
+        } else if rank == 1 {
+            let (ranks, status) = world
+                .process_at_rank(0)
+                .receive_deserialized::<HashMap<String, Vec<Rank>>>()
+                .unwrap();
+            assert_eq!(0, status.source_rank());
+            let odd: Vec<Rank> = (1..size).step_by(2).collect();
+            assert_eq!(2, ranks.len());
+            assert_eq!(Some(&odd), ranks.get("odd"));
+        }
+    }
+
+    // Nested enums are broadcast from the root to all other processes.
+    let root = world.process_at_rank(0);
+    let mut value: Option<Result<String, Rank>> = None;
+    if rank == 0 {
+        value = Some(Ok("broadcast".to_string()));
+    }
+    root.broadcast_serialized(&mut value).unwrap();
+    assert_eq!(Some(Ok("broadcast".to_string())), value);
+
+    // The gathered values may have a different size on every process.
+    let name = "x".repeat(rank as usize);
+    let gathered = root.gather_serialized(&name).unwrap();
+    if rank == 0 {
+        let expected: Vec<String> = (0..size as usize).map(|r| "x".repeat(r)).collect();
+        assert_eq!(Some(expected), gathered);
+    } else {
+        assert_eq!(None, gathered);
+    }
+
+    // Decode failures are reported as errors.
+    if size > 1 {
+        if rank == 0 {
+            let bytes = [0xffu8; 4];
+            world
+                .process_at_rank(1)
+                .send_serialized(&bytes[..])
+                .unwrap();
+        } else if rank == 1 {
+            let result = world.process_at_rank(0).receive_deserialized::<String>();
+            assert!(matches!(result, Err(MpiError::Serde(_))));
+        }
+    }
+}
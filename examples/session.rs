@@ -0,0 +1,47 @@
+#![deny(warnings)]
+
+#[cfg(not(mpi4))]
+fn main() {
+    // Sessions were introduced in MPI-4.
+}
+
+#[cfg(mpi4)]
+fn main() {
+    use mpi::{
+        collective::SystemOperation, session::Session, topology::SimpleCommunicator, traits::*,
+        Rank, Threading,
+    };
+
+    // A session does not initialize the world model.
+    // MPI may provide a different level of multithreading support than requested.
+    let (session, _threading) = Session::init_with_threading(Threading::Funneled);
+
+    let psets = session.psets();
+    assert!(psets.iter().any(|pset| pset == "mpi://WORLD"));
+    assert!(psets.iter().any(|pset| pset == "mpi://SELF"));
+    let size: Rank = session
+        .pset_info("mpi://WORLD")
+        .unwrap()
+        .get("mpi_size")
+        .and_then(|size| size.parse().ok())
+        .expect("process sets report their size");
+
+    {
+        let group = session.group_from_pset("mpi://WORLD").unwrap();
+        assert_eq!(size, group.size());
+
+        let comm = SimpleCommunicator::from_group(&group, "org.rsmpi.examples.session");
+        assert_eq!(size, comm.size());
+        assert_eq!(group.rank(), Some(comm.rank()));
+
+        let mut sum: Rank = 0;
+        comm.all_reduce_into(&comm.rank(), &mut sum, SystemOperation::sum());
+        assert_eq!(size * (size - 1) / 2, sum);
+    }
+
+    // The world model can still be initialized independently of the session.
+    let universe = mpi::initialize().unwrap();
+    assert_eq!(size, universe.world().size());
+
+    drop(session);
+}
@@ -0,0 +1,52 @@
+#![deny(warnings)]
+
+use mpi::{traits::*, window::SharedWindow, Rank};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let node = world.split_shared(world.rank());
+    let size = node.size();
+    let rank = node.rank();
+
+    // A single copy of a lookup table per node, filled in by the first process of the node.
+    let len = if rank == 0 { 1000 } else { 0 };
+    let mut table = SharedWindow::<u64>::allocate(&node, len);
+    assert_eq!(len, table.len());
+    if rank == 0 {
+        for (i, x) in table.local_mut().iter_mut().enumerate() {
+            *x = (i * i) as u64;
+        }
+    }
+    {
+        let epoch = table.synchronize();
+        let squares = epoch.segment(0);
+        assert_eq!(1000, squares.len());
+        assert!(squares
+            .iter()
+            .enumerate()
+            .all(|(i, &x)| x == (i * i) as u64));
+        if size > 1 {
+            assert!(epoch.segment(1).is_empty());
+        }
+    }
+
+    // Every process reads the segments of all processes of the node.
+    let mut ranks = SharedWindow::<Rank>::allocate(&node, 1);
+    ranks.local_mut()[0] = rank;
+    {
+        let epoch = ranks.synchronize();
+        for other in 0..size {
+            assert_eq!(&[other], epoch.segment(other));
+        }
+    }
+
+    // Every process writes into the segment of its right neighbor, which no other process
+    // accesses in the same epoch.
+    {
+        let mut epoch = ranks.synchronize();
+        let right = unsafe { epoch.segment_mut((rank + 1) % size) };
+        right[0] = -rank;
+    }
+    assert_eq!(&[-((rank - 1 + size) % size)], ranks.local());
+}
@@ -0,0 +1,35 @@
+#![deny(warnings)]
+
+use mpi::{topology::SplitType, traits::*};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // Processes on the same node, ranked in reverse order of their world rank.
+    let node = world
+        .split_by_type(SplitType::Shared, world.size() - world.rank())
+        .expect("every process shares memory with itself");
+    assert!(node.size() <= world.size());
+    let mut ranks = vec![0; node.size() as usize];
+    node.all_gather_into(&world.rank(), &mut ranks[..]);
+    assert!(ranks.windows(2).all(|pair| pair[0] > pair[1]));
+
+    #[cfg(mpi4)]
+    {
+        // MPI-4 defines "mpi_shared_memory" as the same split as `SplitType::Shared`.
+        let guided = world
+            .split_by_type(
+                SplitType::HardwareGuided("mpi_shared_memory".to_string()),
+                world.rank(),
+            )
+            .expect("every process shares memory with itself");
+        assert_eq!(node.size(), guided.size());
+
+        // The next smaller hardware level, e.g. the sockets of a node, if there is one.
+        if let Some(level) = node.split_by_type(SplitType::HardwareUnguided, node.rank()) {
+            assert!(level.size() < node.size());
+            assert!(level.get_info().get("mpi_hw_resource_type").is_some());
+        }
+    }
+}
@@ -1,7 +1,11 @@
 #![deny(warnings)]
 use std::fmt::Debug;
 
-use mpi::traits::*;
+use mpi::{
+    datatype::{address_of, DecodedDatatype},
+    traits::*,
+    Address,
+};
 
 fn assert_equivalence<A, B>(comm: &impl Communicator, a: &A, b: &B)
 where
@@ -66,6 +70,26 @@ fn main() {
         },
     );
 
+    // The derived datatype describes each field at its offset in the struct.
+    let data = MyDataC::default();
+    let offset = |field: Address| field - address_of(&data);
+    assert_eq!(
+        DecodedDatatype::Structured {
+            blocklengths: vec![1, 1, 1],
+            displacements: vec![
+                offset(address_of(&data.b)),
+                offset(address_of(&data.f)),
+                offset(address_of(&data.i)),
+            ],
+            types: vec![
+                bool::equivalent_datatype().decode(),
+                f64::equivalent_datatype().decode(),
+                u16::equivalent_datatype().decode(),
+            ],
+        },
+        MyDataC::equivalent_datatype().decode()
+    );
+
     #[derive(Equivalence, Default, PartialEq, Debug)]
     struct MyDataOrdered {
         bf: (bool, f64),
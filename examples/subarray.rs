@@ -0,0 +1,71 @@
+#![deny(warnings)]
+
+use mpi::{
+    datatype::{Order, UserDatatype, View},
+    point_to_point as p2p,
+    topology::Rank,
+    traits::*,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // A 4 x 5 array with one layer of ghost cells around a 2 x 3 interior.
+    let cell = |rank: Rank, row: Rank, column: Rank| {
+        if row == 0 || row == 3 || column == 0 || column == 4 {
+            -1
+        } else {
+            100 * rank + 10 * row + column
+        }
+    };
+    let local = (0..20)
+        .map(|i| cell(rank, i / 5, i % 5))
+        .collect::<Vec<_>>();
+
+    let interior = UserDatatype::subarray(
+        &[4, 5],
+        &[2, 3],
+        &[1, 1],
+        Order::C,
+        &Rank::equivalent_datatype(),
+    );
+    let mut received = vec![0; 6];
+    {
+        let view = unsafe { View::with_count_and_datatype(&local[..], 1, &interior) };
+        p2p::send_receive_into(&view, &next_process, &mut received[..], &previous_process);
+    }
+
+    let expected = (0..6)
+        .map(|i| cell(previous_rank, 1 + i / 3, 1 + i % 3))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, received);
+
+    // In Fortran order the first index varies fastest, so the same subarray of the transposed
+    // array selects the same elements in a different sequence.
+    let transposed = (0..20)
+        .map(|i| cell(rank, i % 4, i / 4))
+        .collect::<Vec<_>>();
+    let interior = UserDatatype::subarray(
+        &[4, 5],
+        &[2, 3],
+        &[1, 1],
+        Order::Fortran,
+        &Rank::equivalent_datatype(),
+    );
+    {
+        let view = unsafe { View::with_count_and_datatype(&transposed[..], 1, &interior) };
+        p2p::send_receive_into(&view, &next_process, &mut received[..], &previous_process);
+    }
+
+    let expected = (0..6)
+        .map(|i| cell(previous_rank, 1 + i % 2, 1 + i / 2))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, received);
+}
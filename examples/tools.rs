@@ -0,0 +1,77 @@
+#![deny(warnings)]
+
+#[cfg(msmpi)]
+fn main() {
+    // MS-MPI does not implement the tool information interface.
+}
+
+#[cfg(not(msmpi))]
+fn main() {
+    use mpi::{
+        tools::{Binding, Tools, Value, ValueType},
+        traits::*,
+    };
+
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // The tool information interface is initialized independently of the world model.
+    let tools = Tools::init().unwrap();
+
+    let cvars = tools.cvars().unwrap();
+    assert_eq!(tools.num_cvars(), cvars.len());
+    for cvar in cvars.iter().take(10) {
+        assert_eq!(Some(cvar), tools.find_cvar(&cvar.name).unwrap().as_ref());
+    }
+    assert_eq!(None, tools.find_cvar("rsmpi_no_such_variable").unwrap());
+
+    for cvar in &cvars {
+        let handle = match cvar.binding {
+            Binding::NoObject => tools.cvar_handle(cvar).unwrap(),
+            Binding::Communicator => tools.cvar_handle_for_communicator(cvar, &world).unwrap(),
+            Binding::Other(_) => continue,
+        };
+        // Variables of types that have no `Value` cannot be accessed.
+        if let ValueType::Other(_) = cvar.value_type {
+            assert!(handle.read().is_err());
+            continue;
+        }
+        let values = handle.read().unwrap();
+        if cvar.value_type == ValueType::Char {
+            assert_eq!(1, values.len());
+        } else {
+            assert_eq!(handle.count(), values.len());
+        }
+
+        // Values of the wrong type are rejected before they reach MPI.
+        let wrong = match cvar.value_type {
+            ValueType::Double => vec![Value::Int(0); handle.count()],
+            _ => vec![Value::Double(0.0); handle.count()],
+        };
+        assert!(handle.write(&wrong).is_err());
+    }
+
+    let session = tools.pvar_session().unwrap();
+    for pvar in tools.pvars().unwrap() {
+        let handle = match pvar.binding {
+            Binding::NoObject => session.handle(&pvar).unwrap(),
+            Binding::Communicator => session.handle_for_communicator(&pvar, &world).unwrap(),
+            Binding::Other(_) => continue,
+        };
+        if let ValueType::Other(_) = pvar.value_type {
+            assert!(handle.read().is_err());
+            continue;
+        }
+        if !pvar.continuous {
+            handle.start().unwrap();
+        }
+        world.barrier();
+        if !pvar.continuous {
+            handle.stop().unwrap();
+        }
+        let values = handle.read().unwrap();
+        if pvar.value_type != ValueType::Char {
+            assert_eq!(handle.count(), values.len());
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![deny(warnings)]
+
+use mpi::{
+    collective::SystemOperation,
+    traits::*,
+    window::{LockType, Window},
+    Rank,
+};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    // Every process writes its rank into the window memory of every other process.
+    let mut exposed: Vec<Rank> = vec![-1; size as usize];
+    let mut window = Window::create(&world, &mut exposed[..]);
+    let x = rank;
+    mpi::request::scope(|scope| {
+        let epoch = window.fence(scope);
+        for target in 0..size {
+            epoch.put(&x, target, rank as mpi::Address);
+        }
+    });
+    let expected: Vec<Rank> = (0..size).collect();
+    assert_eq!(window.local(), &expected[..]);
+
+    // Every process reads the slot of its right neighbor from that neighbor.
+    let right = (rank + 1) % size;
+    let mut y: Rank = -1;
+    mpi::request::scope(|scope| {
+        let epoch = window.fence(scope);
+        epoch.get(&mut y, right, right as mpi::Address);
+    });
+    assert_eq!(y, right);
+    drop(window);
+    assert_eq!(exposed, expected);
+
+    // Every process adds one to the counter on the root process under a shared lock.
+    let mut counter = Window::<Rank>::allocate(&world, 1);
+    let one: Rank = 1;
+    mpi::request::scope(|scope| {
+        let epoch = counter.lock_all(scope);
+        epoch.accumulate(&one, 0, 0, SystemOperation::sum());
+    });
+    world.barrier();
+
+    let mut total: Rank = 0;
+    mpi::request::scope(|scope| {
+        let epoch = counter.lock(LockType::Shared, 0, scope);
+        epoch.get(&mut total, 0, 0);
+    });
+    assert_eq!(total, size);
+}
@@ -0,0 +1,26 @@
+#![deny(warnings)]
+
+use mpi::{traits::*, window::Window, Rank};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let left = (rank + size - 1) % size;
+    let right = (rank + 1) % size;
+    let mut neighbors = vec![left, right];
+    neighbors.dedup();
+    let neighbors = world.group().include(&neighbors[..]);
+
+    // Every process is both origin and target: it writes its rank into the first slot of its
+    // right neighbor and into the second slot of its left neighbor.
+    let mut window = Window::<Rank>::allocate(&world, 2);
+    mpi::request::scope(|scope| {
+        let epoch = window.post_start(&neighbors, &neighbors, scope);
+        epoch.put(&rank, right, 0);
+        epoch.put(&rank, left, 1);
+    });
+    assert_eq!(window.local(), &[left, right][..]);
+}
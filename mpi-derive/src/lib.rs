@@ -22,6 +22,56 @@ use syn::{DeriveInput, Error, Expr, Fields, Type};
 /// }
 /// ```
 ///
+/// Generic structs are supported as well. Their type parameters are bounded by `Equivalence` and
+/// `'static`, and the datatype of each instantiation is built on first use:
+/// ```ignore
+/// use mpi_derive::Equivalence;
+///
+/// #[derive(Equivalence)]
+/// struct Pair<T> {
+///     a: T,
+///     b: T,
+/// }
+/// ```
+///
+/// Fields can be described differently with the field attribute `mpi`:
+/// - `#[mpi(unsafe_skip)]` leaves the field out of the datatype, e.g. for cached data. The field
+///   keeps its value when receiving into an existing value, but is left uninitialized by functions
+///   that return a new value such as `receive()`.
+/// - `#[mpi(unsafe_datatype = ...)]` uses the datatype given by an expression, which evaluates to
+///   a `DatatypeRef` or a reference to a `UserDatatype`, for a field whose type does not implement
+///   `Equivalence`. Building the datatype panics if its true extent does not lie within the field.
+/// - `#[mpi(unsafe_bytes)]` transfers the field as opaque bytes. The field type must be `Copy`.
+///
+/// # Safety
+///
+/// The attributes prefixed with `unsafe_` are an unchecked promise that the datatype of the struct
+/// describes its fields correctly, like a hand-written `unsafe impl Equivalence`:
+/// - A struct with a field marked `unsafe_skip` must only be received into existing values, e.g.
+///   with `receive_into()`, unless the field type is valid when uninitialized, like
+///   `MaybeUninit<T>`. Reading any other value of a struct that was returned by `receive()` or a
+///   similar function is undefined behaviour.
+/// - A field marked `unsafe_bytes` must not contain padding, references or pointers, and every
+///   value that is sent must be a valid value of the field type on the receiving process.
+/// - The datatype given by `unsafe_datatype` must describe the field in the same way as an
+///   implementation of `Equivalence` for its type would: it may only cover initialized bytes of the
+///   field, and every value that it receives must be a valid value of the field type.
+/// ```ignore
+/// use mpi::traits::Equivalence;
+/// use mpi_derive::Equivalence;
+///
+/// #[derive(Equivalence)]
+/// struct Particle {
+///     position: [f64; 3],
+///     #[mpi(unsafe_datatype = u32::equivalent_datatype())]
+///     id: Id,
+///     #[mpi(unsafe_bytes)]
+///     flags: Flags,
+///     #[mpi(unsafe_skip)]
+///     norm: f64,
+/// }
+/// ```
+///
 /// If you use `mpi` via a re-export, you can modify the crate path using the `mpi` attribute:
 /// ```ignore
 /// use mpi_derive::Equivalence;
@@ -34,11 +84,26 @@ use syn::{DeriveInput, Error, Expr, Fields, Type};
 ///     material_properties: [f64; 20],
 /// }
 /// ```
+///
+/// Enums without fields that have an explicit integer `repr` implement `EquivalentEnum` instead
+/// and are communicated as `Checked<E>`, so that receiving an invalid discriminant cannot produce
+/// an invalid value:
+/// ```ignore
+/// use mpi_derive::Equivalence;
+///
+/// #[derive(Equivalence)]
+/// #[repr(u8)]
+/// enum State {
+///     Idle,
+///     Running = 4,
+///     Done,
+/// }
+/// ```
 #[proc_macro_derive(Equivalence, attributes(mpi))]
 pub fn create_user_datatype(input: TokenStream1) -> TokenStream1 {
     let ast: syn::DeriveInput = syn::parse(input).expect("Couldn't parse struct");
     let result = match ast.data {
-        syn::Data::Enum(_) => panic!("#[derive(Equivalence)] is not compatible with enums"),
+        syn::Data::Enum(ref e) => equivalence_for_enum(&ast, e),
         syn::Data::Union(_) => panic!("#[derive(Equivalence)] is not compatible with unions"),
         syn::Data::Struct(ref s) => equivalence_for_struct(&ast, &s.fields),
     };
@@ -95,40 +160,225 @@ fn equivalence_for_type(mpi_crate_path: &TokenStream2, ty: &syn::Type) -> TokenS
     }
 }
 
-fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStream2 {
-    let ident = &ast.ident;
+/// How a field is described in the datatype of its struct, set by the field attribute `mpi`
+enum FieldDatatype {
+    /// The datatype that is equivalent to the type of the field
+    Equivalent,
+    /// `#[mpi(unsafe_skip)]`: the field is left out of the datatype
+    Skip,
+    /// `#[mpi(unsafe_datatype = ...)]`: the datatype given by an expression
+    Explicit(Expr),
+    /// `#[mpi(unsafe_bytes)]`: the field is transferred as opaque bytes
+    Bytes,
+}
 
-    let field_blocklengths = fields.iter().map(|_| 1);
+fn field_datatype(field: &syn::Field) -> syn::Result<FieldDatatype> {
+    const FIELD_ATTR: &str = "mpi";
 
-    let field_names = fields
+    let mut datatype = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(FIELD_ATTR))
+    {
+        attr.parse_nested_meta(|meta| {
+            let field_datatype = if meta.path.is_ident("unsafe_skip") {
+                FieldDatatype::Skip
+            } else if meta.path.is_ident("unsafe_bytes") {
+                FieldDatatype::Bytes
+            } else if meta.path.is_ident("unsafe_datatype") {
+                FieldDatatype::Explicit(meta.value()?.parse()?)
+            } else if ["skip", "bytes", "datatype"]
+                .iter()
+                .any(|name| meta.path.is_ident(name))
+            {
+                return Err(Error::new_spanned(
+                    &meta.path,
+                    format!(
+                        "`{0}` cannot be checked by the compiler. Use `unsafe_{0}` and uphold \
+                         its safety contract",
+                        meta.path.to_token_stream()
+                    ),
+                ));
+            } else {
+                return Err(Error::new_spanned(
+                    &meta.path,
+                    format!(
+                        "unexpected attribute `{}`. Expected `unsafe_skip`, `unsafe_datatype` or \
+                         `unsafe_bytes`",
+                        meta.path.to_token_stream()
+                    ),
+                ));
+            };
+
+            if datatype.is_some() {
+                return Err(Error::new_spanned(
+                    &meta.path,
+                    "Only one of `unsafe_skip`, `unsafe_datatype` and `unsafe_bytes` is allowed per \
+                     field",
+                ));
+            }
+            datatype = Some(field_datatype);
+            Ok(())
+        })?;
+    }
+
+    Ok(datatype.unwrap_or(FieldDatatype::Equivalent))
+}
+
+/// The expression that builds the datatype of the struct `ident` with `fields`, which is named
+/// `parent` in the generated code
+fn struct_datatype(
+    mpi_crate_path: &TokenStream2,
+    ident: &syn::Ident,
+    parent: &TokenStream2,
+    fields: &Fields,
+    field_datatypes: &[FieldDatatype],
+) -> TokenStream2 {
+    let included: Vec<_> = fields
         .iter()
         .enumerate()
-        .map(|(i, field)| -> Box<dyn quote::ToTokens> {
+        .zip(field_datatypes)
+        .filter(|(_, datatype)| !matches!(datatype, FieldDatatype::Skip))
+        .collect();
+
+    let field_blocklengths: Vec<_> = included.iter().map(|_| 1).collect();
+
+    let field_names: Vec<_> = included
+        .iter()
+        .map(|((i, field), _)| -> Box<dyn quote::ToTokens> {
             if let Some(ident) = field.ident.as_ref() {
                 // named struct fields
                 Box::new(ident)
             } else {
                 // tuple struct fields
-                Box::new(syn::Index::from(i))
+                Box::new(syn::Index::from(*i))
+            }
+        })
+        .collect();
+
+    let field_datatypes: Vec<_> = included
+        .iter()
+        .zip(&field_names)
+        .map(|(((_, field), datatype), name)| match datatype {
+            FieldDatatype::Explicit(expr) => {
+                let ty = &field.ty;
+                let field_str = format!("{}::{}", ident, name.to_token_stream());
+                quote! { #mpi_crate_path::datatype::internal::check_field_datatype(
+                    #mpi_crate_path::datatype::UncommittedDatatypeRef::from(#expr),
+                    ::std::mem::size_of::<#ty>(),
+                    #field_str)
+                }
             }
-        });
+            FieldDatatype::Bytes => {
+                let ty = &field.ty;
+                quote! { &#mpi_crate_path::datatype::UncommittedUserDatatype::contiguous(
+                    ::std::mem::size_of::<#ty>().try_into().expect("rsmpi derive: Field size is too large for MPI_Datatype i32"),
+                    &<u8 as #mpi_crate_path::datatype::Equivalence>::equivalent_datatype())
+                }
+            }
+            FieldDatatype::Equivalent => equivalence_for_type(mpi_crate_path, &field.ty),
+            FieldDatatype::Skip => unreachable!("skipped fields are not included"),
+        })
+        .collect();
+
+    let structured = |datatype: TokenStream2| {
+        quote! {
+            #datatype::structured::<
+                #mpi_crate_path::datatype::UncommittedDatatypeRef,
+            >(
+                &[#(#field_blocklengths as #mpi_crate_path::Count),*],
+                &[#(#mpi_crate_path::internal::memoffset::offset_of!(#parent, #field_names) as #mpi_crate_path::Address),*],
+                &[#(#mpi_crate_path::datatype::UncommittedDatatypeRef::from(#field_datatypes)),*],
+            )
+        }
+    };
+
+    // Skipped fields leave holes that may be at the end of the struct, so the extent of the
+    // datatype is set to the size of the struct for arrays to be laid out correctly.
+    if included.len() < fields.len() {
+        let structured = structured(quote!(#mpi_crate_path::datatype::UncommittedUserDatatype));
+        quote! {
+            #mpi_crate_path::datatype::UserDatatype::resized(
+                0,
+                ::std::mem::size_of::<#parent>().try_into().expect("rsmpi derive: Struct size is too large for MPI_Aint"),
+                &#structured,
+            )
+        }
+    } else {
+        structured(quote!(#mpi_crate_path::datatype::UserDatatype))
+    }
+}
+
+fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStream2 {
+    let ident = &ast.ident;
+
+    // parse field attributes. If that fails, convert the parse error into a compile error.
+    let field_datatypes = match fields
+        .iter()
+        .map(field_datatype)
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(field_datatypes) => field_datatypes,
+        Err(e) => return e.into_compile_error(),
+    };
+
+    // Fields that are transferred as bytes must at least be `Copy`, so that they cannot own
+    // resources that would be duplicated by the transfer.
+    let bytes_types: Vec<_> = fields
+        .iter()
+        .zip(&field_datatypes)
+        .filter(|(_, datatype)| matches!(datatype, FieldDatatype::Bytes))
+        .map(|(field, _)| &field.ty)
+        .collect();
 
     // parse crate path. If that fails, convert the parse error into a compile error.
     let crate_path_res = mpi_crate_path(ast);
 
     match crate_path_res {
         Ok(mpi_crate_path) => {
-            let field_datatypes = fields
-                .iter()
-                .map(|field| equivalence_for_type(&mpi_crate_path, &field.ty));
+            // A `static` inside a generic function is shared by all of its instantiations, so the
+            // datatypes of generic structs are cached per type at runtime instead.
+            if !ast.generics.params.is_empty() {
+                let datatype = struct_datatype(
+                    &mpi_crate_path,
+                    ident,
+                    &quote!(Self),
+                    fields,
+                    &field_datatypes,
+                );
+                let datatype = quote! {
+                    #mpi_crate_path::datatype::internal::check_derive_equivalence_universe_state(
+                        ::std::any::type_name::<Self>(),
+                    );
 
+                    #datatype
+                };
+                return equivalence_for_generic_struct(
+                    ast,
+                    &mpi_crate_path,
+                    &bytes_types,
+                    datatype,
+                );
+            }
+
+            let datatype = struct_datatype(
+                &mpi_crate_path,
+                ident,
+                &quote!(#ident),
+                fields,
+                &field_datatypes,
+            );
             let ident_str = ident.to_string();
 
             // TODO and NOTE: Technically this code can race with MPI init and finalize, as can any other
             // code in rsmpi that interacts with the MPI library without taking a handle to `Universe`.
             // This requires larger attention, and so currently this is not addressed.
             quote! {
-                unsafe impl #mpi_crate_path::datatype::Equivalence for #ident {
+                unsafe impl #mpi_crate_path::datatype::Equivalence for #ident
+                where
+                    #(#bytes_types: ::std::marker::Copy,)*
+                {
                     type Out = #mpi_crate_path::datatype::DatatypeRef<'static>;
                     fn equivalent_datatype() -> Self::Out {
                         use #mpi_crate_path::internal::once_cell::sync::Lazy;
@@ -137,13 +387,7 @@ fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStrea
                         static DATATYPE: Lazy<#mpi_crate_path::datatype::UserDatatype> = Lazy::new(|| {
                             #mpi_crate_path::datatype::internal::check_derive_equivalence_universe_state(#ident_str);
 
-                            #mpi_crate_path::datatype::UserDatatype::structured::<
-                                #mpi_crate_path::datatype::UncommittedDatatypeRef,
-                            >(
-                                &[#(#field_blocklengths as #mpi_crate_path::Count),*],
-                                &[#(#mpi_crate_path::internal::memoffset::offset_of!(#ident, #field_names) as #mpi_crate_path::Address),*],
-                                &[#(#mpi_crate_path::datatype::UncommittedDatatypeRef::from(#field_datatypes)),*],
-                            )
+                            #datatype
                         });
 
                         DATATYPE.as_ref()
@@ -155,6 +399,135 @@ fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStrea
     }
 }
 
+fn equivalence_for_generic_struct(
+    ast: &syn::DeriveInput,
+    mpi_crate_path: &TokenStream2,
+    bytes_types: &[&Type],
+    datatype: TokenStream2,
+) -> TokenStream2 {
+    let ident = &ast.ident;
+
+    // Every type parameter has to be `Equivalence` itself and `'static` for its `TypeId`.
+    let mut generics = ast.generics.clone();
+    for param in generics.params.iter_mut() {
+        match param {
+            syn::GenericParam::Type(ref mut type_param) => {
+                type_param
+                    .bounds
+                    .push(syn::parse_quote!(#mpi_crate_path::datatype::Equivalence));
+                type_param.bounds.push(syn::parse_quote!('static));
+            }
+            syn::GenericParam::Lifetime(ref lifetime_param) => {
+                return Error::new_spanned(
+                    lifetime_param,
+                    "#[derive(Equivalence)] is not compatible with lifetime parameters",
+                )
+                .into_compile_error();
+            }
+            syn::GenericParam::Const(_) => {}
+        }
+    }
+    let where_clause = generics.make_where_clause();
+    for ty in bytes_types {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ty: ::std::marker::Copy));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        unsafe impl #impl_generics #mpi_crate_path::datatype::Equivalence for #ident #ty_generics #where_clause {
+            type Out = #mpi_crate_path::datatype::DatatypeRef<'static>;
+            fn equivalent_datatype() -> Self::Out {
+                use ::std::convert::TryInto;
+
+                #mpi_crate_path::datatype::internal::generic_equivalent_datatype::<Self>(|| {
+                    #datatype
+                })
+            }
+        }
+    }
+}
+
+fn equivalence_for_enum(ast: &syn::DeriveInput, data: &syn::DataEnum) -> TokenStream2 {
+    let ident = &ast.ident;
+
+    let result = mpi_crate_path(ast).and_then(|mpi_crate_path| {
+        let repr = enum_repr(ast)?;
+
+        if let Some(variant) = data
+            .variants
+            .iter()
+            .find(|variant| !matches!(variant.fields, Fields::Unit))
+        {
+            return Err(Error::new_spanned(
+                variant,
+                "#[derive(Equivalence)] is only compatible with enums without fields",
+            ));
+        }
+
+        let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+
+        // The discriminants are compared to the received integer instead of transmuting it, so
+        // that an invalid discriminant never becomes a value of the enum.
+        Ok(quote! {
+            impl #mpi_crate_path::datatype::EquivalentEnum for #ident {
+                type Repr = #repr;
+
+                fn to_repr(&self) -> #repr {
+                    match *self {
+                        #(#ident::#variants => #ident::#variants as #repr,)*
+                    }
+                }
+
+                fn from_repr(repr: #repr) -> ::std::option::Option<Self> {
+                    #(
+                        if repr == #ident::#variants as #repr {
+                            return ::std::option::Option::Some(#ident::#variants);
+                        }
+                    )*
+                    ::std::option::Option::None
+                }
+            }
+        })
+    });
+
+    result.unwrap_or_else(|e| e.into_compile_error())
+}
+
+/// The integer type given by the `repr` attribute of an enum
+fn enum_repr(input: &DeriveInput) -> syn::Result<syn::Ident> {
+    const REPR_ATTR: &str = "repr";
+    const INTEGER_TYPES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+    ];
+
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(REPR_ATTR))
+    {
+        attr.parse_nested_meta(|meta| {
+            if INTEGER_TYPES.iter().any(|ty| meta.path.is_ident(ty)) {
+                repr = meta.path.get_ident().cloned();
+            } else if meta.input.peek(syn::token::Paren) {
+                // skip arguments of other representations, e.g. `align(8)`
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    repr.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "#[derive(Equivalence)] requires an integer `repr` on enums, e.g. `#[repr(u8)]`",
+        )
+    })
+}
+
 fn mpi_crate_path(input: &DeriveInput) -> syn::Result<TokenStream2> {
     const MPI_CRATE_PATH_ATTR: &str = "mpi";
     const META_PATH: &str = "crate";
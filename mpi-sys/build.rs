@@ -68,6 +68,19 @@ fn main() {
         .generate()
         .unwrap();
 
+    // Let dependent crates know which version of the standard the library implements. With
+    // `links = "mpi"` this is visible to their build scripts as `DEP_MPI_VERSION`.
+    let generated = bindings.to_string();
+    let version = generated
+        .split("pub const MPI_VERSION")
+        .nth(1)
+        .and_then(|rest| rest.split(';').next())
+        .and_then(|decl| decl.rsplit('=').next())
+        .map(str::trim);
+    if let Some(version) = version {
+        println!("cargo:version={}", version);
+    }
+
     // Write the bindings to disk.
     let out_dir = env::var("OUT_DIR").expect("cargo did not set OUT_DIR");
     let out_file = Path::new(&out_dir).join("functions_and_types.rs");
@@ -5,6 +5,13 @@ const MPI_Datatype RSMPI_C_BOOL = MPI_C_BOOL;
 const MPI_Datatype RSMPI_FLOAT = MPI_FLOAT;
 const MPI_Datatype RSMPI_DOUBLE = MPI_DOUBLE;
 
+const MPI_Datatype RSMPI_CHAR = MPI_CHAR;
+const MPI_Datatype RSMPI_INT = MPI_INT;
+const MPI_Datatype RSMPI_UNSIGNED = MPI_UNSIGNED;
+const MPI_Datatype RSMPI_UNSIGNED_LONG = MPI_UNSIGNED_LONG;
+const MPI_Datatype RSMPI_UNSIGNED_LONG_LONG = MPI_UNSIGNED_LONG_LONG;
+const MPI_Datatype RSMPI_COUNT = MPI_COUNT;
+
 const MPI_Datatype RSMPI_INT8_T = MPI_INT8_T;
 const MPI_Datatype RSMPI_INT16_T = MPI_INT16_T;
 const MPI_Datatype RSMPI_INT32_T = MPI_INT32_T;
@@ -20,11 +27,36 @@ const MPI_Datatype RSMPI_DOUBLE_COMPLEX = MPI_C_DOUBLE_COMPLEX;
 
 const MPI_Datatype RSMPI_DATATYPE_NULL = MPI_DATATYPE_NULL;
 
+const int RSMPI_ORDER_C = MPI_ORDER_C;
+const int RSMPI_ORDER_FORTRAN = MPI_ORDER_FORTRAN;
+const int RSMPI_DISTRIBUTE_BLOCK = MPI_DISTRIBUTE_BLOCK;
+const int RSMPI_DISTRIBUTE_CYCLIC = MPI_DISTRIBUTE_CYCLIC;
+const int RSMPI_DISTRIBUTE_NONE = MPI_DISTRIBUTE_NONE;
+const int RSMPI_DISTRIBUTE_DFLT_DARG = MPI_DISTRIBUTE_DFLT_DARG;
+
+const int RSMPI_COMBINER_NAMED = MPI_COMBINER_NAMED;
+const int RSMPI_COMBINER_DUP = MPI_COMBINER_DUP;
+const int RSMPI_COMBINER_CONTIGUOUS = MPI_COMBINER_CONTIGUOUS;
+const int RSMPI_COMBINER_VECTOR = MPI_COMBINER_VECTOR;
+const int RSMPI_COMBINER_HVECTOR = MPI_COMBINER_HVECTOR;
+const int RSMPI_COMBINER_INDEXED = MPI_COMBINER_INDEXED;
+const int RSMPI_COMBINER_HINDEXED = MPI_COMBINER_HINDEXED;
+const int RSMPI_COMBINER_INDEXED_BLOCK = MPI_COMBINER_INDEXED_BLOCK;
+const int RSMPI_COMBINER_HINDEXED_BLOCK = MPI_COMBINER_HINDEXED_BLOCK;
+const int RSMPI_COMBINER_STRUCT = MPI_COMBINER_STRUCT;
+const int RSMPI_COMBINER_SUBARRAY = MPI_COMBINER_SUBARRAY;
+const int RSMPI_COMBINER_DARRAY = MPI_COMBINER_DARRAY;
+const int RSMPI_COMBINER_RESIZED = MPI_COMBINER_RESIZED;
+
 const MPI_Comm RSMPI_COMM_WORLD = MPI_COMM_WORLD;
 const MPI_Comm RSMPI_COMM_NULL = MPI_COMM_NULL;
 const MPI_Comm RSMPI_COMM_SELF = MPI_COMM_SELF;
 
 const int RSMPI_COMM_TYPE_SHARED = MPI_COMM_TYPE_SHARED;
+#if MPI_VERSION >= 4
+const int RSMPI_COMM_TYPE_HW_GUIDED = MPI_COMM_TYPE_HW_GUIDED;
+const int RSMPI_COMM_TYPE_HW_UNGUIDED = MPI_COMM_TYPE_HW_UNGUIDED;
+#endif
 
 const MPI_Group RSMPI_GROUP_EMPTY = MPI_GROUP_EMPTY;
 const MPI_Group RSMPI_GROUP_NULL = MPI_GROUP_NULL;
@@ -58,6 +90,9 @@ const int RSMPI_DIST_GRAPH = MPI_DIST_GRAPH;
 
 const int RSMPI_MAX_LIBRARY_VERSION_STRING = MPI_MAX_LIBRARY_VERSION_STRING;
 const int RSMPI_MAX_PROCESSOR_NAME = MPI_MAX_PROCESSOR_NAME;
+const int RSMPI_MAX_ERROR_STRING = MPI_MAX_ERROR_STRING;
+const int RSMPI_MAX_INFO_KEY = MPI_MAX_INFO_KEY;
+const int RSMPI_MAX_INFO_VAL = MPI_MAX_INFO_VAL;
 
 const MPI_Op RSMPI_MAX = MPI_MAX;
 const MPI_Op RSMPI_MIN = MPI_MIN;
@@ -75,13 +110,52 @@ const MPI_Errhandler RSMPI_ERRORS_RETURN = MPI_ERRORS_RETURN;
 
 const MPI_File RSMPI_FILE_NULL = MPI_FILE_NULL;
 
+const int RSMPI_MODE_RDONLY = MPI_MODE_RDONLY;
+const int RSMPI_MODE_RDWR = MPI_MODE_RDWR;
+const int RSMPI_MODE_WRONLY = MPI_MODE_WRONLY;
+const int RSMPI_MODE_CREATE = MPI_MODE_CREATE;
+const int RSMPI_MODE_EXCL = MPI_MODE_EXCL;
+const int RSMPI_MODE_DELETE_ON_CLOSE = MPI_MODE_DELETE_ON_CLOSE;
+const int RSMPI_MODE_UNIQUE_OPEN = MPI_MODE_UNIQUE_OPEN;
+const int RSMPI_MODE_SEQUENTIAL = MPI_MODE_SEQUENTIAL;
+const int RSMPI_MODE_APPEND = MPI_MODE_APPEND;
+
+const int RSMPI_SEEK_SET = MPI_SEEK_SET;
+const int RSMPI_SEEK_CUR = MPI_SEEK_CUR;
+const int RSMPI_SEEK_END = MPI_SEEK_END;
+
 const MPI_Info RSMPI_INFO_NULL = MPI_INFO_NULL;
 
+#if MPI_VERSION >= 4
+const MPI_Session RSMPI_SESSION_NULL = MPI_SESSION_NULL;
+#endif
+
 const MPI_Win RSMPI_WIN_NULL = MPI_WIN_NULL;
 
+// MS-MPI does not implement the tool information interface.
+#ifndef MSMPI_VER
+const MPI_T_enum RSMPI_T_ENUM_NULL = MPI_T_ENUM_NULL;
+const MPI_T_cvar_handle RSMPI_T_CVAR_HANDLE_NULL = MPI_T_CVAR_HANDLE_NULL;
+const MPI_T_pvar_session RSMPI_T_PVAR_SESSION_NULL = MPI_T_PVAR_SESSION_NULL;
+const MPI_T_pvar_handle RSMPI_T_PVAR_HANDLE_NULL = MPI_T_PVAR_HANDLE_NULL;
+const int RSMPI_T_BIND_NO_OBJECT = MPI_T_BIND_NO_OBJECT;
+const int RSMPI_T_BIND_MPI_COMM = MPI_T_BIND_MPI_COMM;
+const int RSMPI_T_SCOPE_CONSTANT = MPI_T_SCOPE_CONSTANT;
+const int RSMPI_T_SCOPE_READONLY = MPI_T_SCOPE_READONLY;
+#endif
+
+const int RSMPI_LOCK_EXCLUSIVE = MPI_LOCK_EXCLUSIVE;
+const int RSMPI_LOCK_SHARED = MPI_LOCK_SHARED;
+const int RSMPI_MODE_NOCHECK = MPI_MODE_NOCHECK;
+
 int* RSMPI_UNWEIGHTED() {
     return MPI_UNWEIGHTED;
 }
+
+int* RSMPI_WEIGHTS_EMPTY() {
+    return MPI_WEIGHTS_EMPTY;
+}
+
 double RSMPI_Wtime() {
   return MPI_Wtime();
 }
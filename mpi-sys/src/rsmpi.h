@@ -11,6 +11,13 @@ extern const MPI_Datatype RSMPI_C_BOOL;
 extern const MPI_Datatype RSMPI_FLOAT;
 extern const MPI_Datatype RSMPI_DOUBLE;
 
+extern const MPI_Datatype RSMPI_CHAR;
+extern const MPI_Datatype RSMPI_INT;
+extern const MPI_Datatype RSMPI_UNSIGNED;
+extern const MPI_Datatype RSMPI_UNSIGNED_LONG;
+extern const MPI_Datatype RSMPI_UNSIGNED_LONG_LONG;
+extern const MPI_Datatype RSMPI_COUNT;
+
 extern const MPI_Datatype RSMPI_INT8_T;
 extern const MPI_Datatype RSMPI_INT16_T;
 extern const MPI_Datatype RSMPI_INT32_T;
@@ -26,11 +33,36 @@ extern const MPI_Datatype RSMPI_DOUBLE_COMPLEX;
 
 extern const MPI_Datatype RSMPI_DATATYPE_NULL;
 
+extern const int RSMPI_ORDER_C;
+extern const int RSMPI_ORDER_FORTRAN;
+extern const int RSMPI_DISTRIBUTE_BLOCK;
+extern const int RSMPI_DISTRIBUTE_CYCLIC;
+extern const int RSMPI_DISTRIBUTE_NONE;
+extern const int RSMPI_DISTRIBUTE_DFLT_DARG;
+
+extern const int RSMPI_COMBINER_NAMED;
+extern const int RSMPI_COMBINER_DUP;
+extern const int RSMPI_COMBINER_CONTIGUOUS;
+extern const int RSMPI_COMBINER_VECTOR;
+extern const int RSMPI_COMBINER_HVECTOR;
+extern const int RSMPI_COMBINER_INDEXED;
+extern const int RSMPI_COMBINER_HINDEXED;
+extern const int RSMPI_COMBINER_INDEXED_BLOCK;
+extern const int RSMPI_COMBINER_HINDEXED_BLOCK;
+extern const int RSMPI_COMBINER_STRUCT;
+extern const int RSMPI_COMBINER_SUBARRAY;
+extern const int RSMPI_COMBINER_DARRAY;
+extern const int RSMPI_COMBINER_RESIZED;
+
 extern const MPI_Comm RSMPI_COMM_WORLD;
 extern const MPI_Comm RSMPI_COMM_NULL;
 extern const MPI_Comm RSMPI_COMM_SELF;
 
 extern const int RSMPI_COMM_TYPE_SHARED;
+#if MPI_VERSION >= 4
+extern const int RSMPI_COMM_TYPE_HW_GUIDED;
+extern const int RSMPI_COMM_TYPE_HW_UNGUIDED;
+#endif
 
 extern const MPI_Group RSMPI_GROUP_EMPTY;
 extern const MPI_Group RSMPI_GROUP_NULL;
@@ -64,6 +96,9 @@ extern const int RSMPI_DIST_GRAPH;
 
 extern const int RSMPI_MAX_LIBRARY_VERSION_STRING;
 extern const int RSMPI_MAX_PROCESSOR_NAME;
+extern const int RSMPI_MAX_ERROR_STRING;
+extern const int RSMPI_MAX_INFO_KEY;
+extern const int RSMPI_MAX_INFO_VAL;
 
 extern const MPI_Op RSMPI_MAX;
 extern const MPI_Op RSMPI_MIN;
@@ -81,11 +116,46 @@ extern const MPI_Errhandler RSMPI_ERRORS_RETURN;
 
 extern const MPI_File RSMPI_FILE_NULL;
 
+extern const int RSMPI_MODE_RDONLY;
+extern const int RSMPI_MODE_RDWR;
+extern const int RSMPI_MODE_WRONLY;
+extern const int RSMPI_MODE_CREATE;
+extern const int RSMPI_MODE_EXCL;
+extern const int RSMPI_MODE_DELETE_ON_CLOSE;
+extern const int RSMPI_MODE_UNIQUE_OPEN;
+extern const int RSMPI_MODE_SEQUENTIAL;
+extern const int RSMPI_MODE_APPEND;
+
+extern const int RSMPI_SEEK_SET;
+extern const int RSMPI_SEEK_CUR;
+extern const int RSMPI_SEEK_END;
+
 extern const MPI_Info RSMPI_INFO_NULL;
 
+#if MPI_VERSION >= 4
+extern const MPI_Session RSMPI_SESSION_NULL;
+#endif
+
 extern const MPI_Win RSMPI_WIN_NULL;
 
+// MS-MPI does not implement the tool information interface.
+#ifndef MSMPI_VER
+extern const MPI_T_enum RSMPI_T_ENUM_NULL;
+extern const MPI_T_cvar_handle RSMPI_T_CVAR_HANDLE_NULL;
+extern const MPI_T_pvar_session RSMPI_T_PVAR_SESSION_NULL;
+extern const MPI_T_pvar_handle RSMPI_T_PVAR_HANDLE_NULL;
+extern const int RSMPI_T_BIND_NO_OBJECT;
+extern const int RSMPI_T_BIND_MPI_COMM;
+extern const int RSMPI_T_SCOPE_CONSTANT;
+extern const int RSMPI_T_SCOPE_READONLY;
+#endif
+
+extern const int RSMPI_LOCK_EXCLUSIVE;
+extern const int RSMPI_LOCK_SHARED;
+extern const int RSMPI_MODE_NOCHECK;
+
 int* RSMPI_UNWEIGHTED();
+int* RSMPI_WEIGHTS_EMPTY();
 
 double RSMPI_Wtime();
 double RSMPI_Wtick();
@@ -2,18 +2,31 @@
 //!
 //! Developing...
 //!
+//! The persistent collective operations (`*_init()`) are part of MPI-4 and are only available if
+//! the MPI library implements that version of the standard.
+//!
+//! The blocking operations accept buffers and partitions beyond the range of `Count`. With MPI-4,
+//! they call the large-count `_c` variants of the MPI functions. Older libraries receive such
+//! buffers as a derived datatype and reductions in chunks that fit the range of `Count`, while
+//! partitions beyond that range and reductions that cannot be split, like
+//! `reduce_scatter_block_into()`, panic. The non-blocking (`immediate_*()`) and persistent
+//! (`*_init()`) operations do not support large counts yet and panic for buffers beyond the range
+//! of `Count`.
+//!
 //! # Unfinished features
 //!
-//! - **5.8**: All-to-all, `MPI_Alltoallw()`
-//! - **5.10**: Reduce-scatter, `MPI_Reduce_scatter()`
-//! - **5.12**: Nonblocking collective operations,
-//! `MPI_Ialltoallw()`, `MPI_Ireduce_scatter()`
+//! - **6.13**: Persistent collective operations, `MPI_Alltoallw_init()`,
+//! `MPI_Reduce_scatter_init()`
+//! - **6.2.1**: Large-count variants of the non-blocking and persistent collective operations,
+//! e.g. `MPI_Ibcast_c()`, `MPI_Allgatherv_init_c()`, and of `MPI_Alltoallw_c()` and
+//! `MPI_Neighbor_alltoallw_c()`
 
 #[cfg(feature = "user-operations")]
 use std::mem;
 use std::{
     ffi::{CString, NulError},
     fmt,
+    ops::Div,
     os::raw::{c_char, c_int, c_void},
     process::Command,
     ptr,
@@ -22,22 +35,60 @@ use std::{
 use conv::ConvUtil;
 #[cfg(feature = "user-operations")]
 use libffi::middle::{Cif, Closure, Type};
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
 
+#[cfg(feature = "serde")]
+use crate::datatype::PartitionMut;
 #[cfg(feature = "user-operations")]
-use crate::datatype::{DatatypeRef, DynBuffer, DynBufferMut};
+use crate::datatype::{DynBuffer, DynBufferMut};
+#[cfg(mpi4)]
+use crate::request::{PersistentCollectiveRequest, PersistentRequest};
 use crate::{
-    datatype::traits::*,
+    check_error,
+    datatype::{traits::*, CountedBuffer, DatatypeRef, RawCount, RawPartition},
     ffi,
-    ffi::MPI_Op,
+    ffi::{MPI_Datatype, MPI_Info, MPI_Op},
+    info::Info,
     raw::traits::*,
     request::{Request, Scope, StaticScope},
-    topology::{traits::*, InterCommunicator, Process, Rank},
-    with_uninitialized, MpiError,
+    topology::{
+        traits::*, CartesianCommunicator, DistributedGraphCommunicator, GraphCommunicator,
+        InterCommunicator, Process, Rank,
+    },
+    with_uninitialized, with_uninitialized2, Address, Count, LargeCount, MpiError,
 };
 
+/// The functions used with a `CountedBuffer` or a `RawPartition`: the large-count variants with
+/// MPI-4, the classic ones otherwise
+mod large {
+    #[cfg(not(mpi4))]
+    pub(super) use crate::ffi::{
+        MPI_Allgather, MPI_Allgatherv, MPI_Allreduce, MPI_Alltoall, MPI_Alltoallv, MPI_Bcast,
+        MPI_Exscan, MPI_Gather, MPI_Gatherv, MPI_Neighbor_allgather, MPI_Neighbor_allgatherv,
+        MPI_Neighbor_alltoall, MPI_Neighbor_alltoallv, MPI_Reduce, MPI_Reduce_local,
+        MPI_Reduce_scatter, MPI_Reduce_scatter_block, MPI_Scan, MPI_Scatter, MPI_Scatterv,
+    };
+    #[cfg(mpi4)]
+    pub(super) use crate::ffi::{
+        MPI_Allgather_c as MPI_Allgather, MPI_Allgatherv_c as MPI_Allgatherv,
+        MPI_Allreduce_c as MPI_Allreduce, MPI_Alltoall_c as MPI_Alltoall,
+        MPI_Alltoallv_c as MPI_Alltoallv, MPI_Bcast_c as MPI_Bcast, MPI_Exscan_c as MPI_Exscan,
+        MPI_Gather_c as MPI_Gather, MPI_Gatherv_c as MPI_Gatherv,
+        MPI_Neighbor_allgather_c as MPI_Neighbor_allgather,
+        MPI_Neighbor_allgatherv_c as MPI_Neighbor_allgatherv,
+        MPI_Neighbor_alltoall_c as MPI_Neighbor_alltoall,
+        MPI_Neighbor_alltoallv_c as MPI_Neighbor_alltoallv, MPI_Reduce_c as MPI_Reduce,
+        MPI_Reduce_local_c as MPI_Reduce_local,
+        MPI_Reduce_scatter_block_c as MPI_Reduce_scatter_block,
+        MPI_Reduce_scatter_c as MPI_Reduce_scatter, MPI_Scan_c as MPI_Scan,
+        MPI_Scatter_c as MPI_Scatter, MPI_Scatterv_c as MPI_Scatterv,
+    };
+}
+
 /// Collective communication traits
 pub mod traits {
-    pub use super::{CommunicatorCollectives, Operation, Root};
+    pub use super::{CommunicatorCollectives, NeighborhoodCollectives, Operation, Root};
 }
 
 /// Collective communication patterns defined on `Communicator`s
@@ -62,6 +113,22 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Like [`barrier`](#method.barrier), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Examples
+    ///
+    /// See `examples/errors_return.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.3, 8.3
+    fn try_barrier(&self) -> Result<(), MpiError> {
+        check_error(unsafe { ffi::MPI_Barrier(self.as_raw()) })
+    }
+
     /// Gather contents of buffers on all participating processes.
     ///
     /// After the call completes, the contents of the send `Buffer`s on all processes will be
@@ -81,19 +148,53 @@ pub trait CommunicatorCollectives: Communicator {
         S: Buffer,
         R: BufferMut,
     {
+        let send = CountedBuffer::new(sendbuf);
+        let recv = counted_block(&*recvbuf, self.target_size());
         unsafe {
-            ffi::MPI_Allgather(
+            large::MPI_Allgather(
                 sendbuf.pointer(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
+                send.count,
+                send.datatype(),
                 recvbuf.pointer_mut(),
-                recvbuf.count() / self.target_size(),
-                recvbuf.as_datatype().as_raw(),
+                recv.count,
+                recv.datatype(),
                 self.as_raw(),
             );
         }
     }
 
+    /// Like [`all_gather_into`](#method.all_gather_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.7, 8.3
+    fn try_all_gather_into<S: ?Sized, R: ?Sized>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        let send = CountedBuffer::new(sendbuf);
+        let recv = counted_block(&*recvbuf, self.target_size());
+        check_error(unsafe {
+            large::MPI_Allgather(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.as_raw(),
+            )
+        })
+    }
+
     /// Gather contents of buffers on all participating processes.
     ///
     /// After the call completes, the contents of the send `Buffer`s on all processes will be
@@ -114,14 +215,16 @@ pub trait CommunicatorCollectives: Communicator {
         S: Buffer,
         R: PartitionedBufferMut,
     {
+        let send = CountedBuffer::new(sendbuf);
+        let recvpart = RawPartition::new(&*recvbuf);
         unsafe {
-            ffi::MPI_Allgatherv(
+            large::MPI_Allgatherv(
                 sendbuf.pointer(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
+                send.count,
+                send.datatype(),
                 recvbuf.pointer_mut(),
-                recvbuf.counts().as_ptr(),
-                recvbuf.displs().as_ptr(),
+                recvpart.counts.as_ptr(),
+                recvpart.displs.as_ptr(),
                 recvbuf.as_datatype().as_raw(),
                 self.as_raw(),
             );
@@ -145,19 +248,54 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
     {
         let c_size = self.target_size();
+        let send = counted_block(sendbuf, c_size);
+        let recv = counted_block(&*recvbuf, c_size);
         unsafe {
-            ffi::MPI_Alltoall(
+            large::MPI_Alltoall(
                 sendbuf.pointer(),
-                sendbuf.count() / c_size,
-                sendbuf.as_datatype().as_raw(),
+                send.count,
+                send.datatype(),
                 recvbuf.pointer_mut(),
-                recvbuf.count() / c_size,
-                recvbuf.as_datatype().as_raw(),
+                recv.count,
+                recv.datatype(),
                 self.as_raw(),
             );
         }
     }
 
+    /// Like [`all_to_all_into`](#method.all_to_all_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.8, 8.3
+    fn try_all_to_all_into<S: ?Sized, R: ?Sized>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        let c_size = self.target_size();
+        let send = counted_block(sendbuf, c_size);
+        let recv = counted_block(&*recvbuf, c_size);
+        check_error(unsafe {
+            large::MPI_Alltoall(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.as_raw(),
+            )
+        })
+    }
+
     /// Distribute the send `Buffer`s from all processes to the receive `Buffer`s on all processes.
     ///
     /// The count of elements to send and receive to and from each process can vary and is specified
@@ -171,21 +309,77 @@ pub trait CommunicatorCollectives: Communicator {
         S: PartitionedBuffer,
         R: PartitionedBufferMut,
     {
+        let sendpart = RawPartition::new(sendbuf);
+        let recvpart = RawPartition::new(&*recvbuf);
         unsafe {
-            ffi::MPI_Alltoallv(
+            large::MPI_Alltoallv(
                 sendbuf.pointer(),
-                sendbuf.counts().as_ptr(),
-                sendbuf.displs().as_ptr(),
+                sendpart.counts.as_ptr(),
+                sendpart.displs.as_ptr(),
                 sendbuf.as_datatype().as_raw(),
                 recvbuf.pointer_mut(),
-                recvbuf.counts().as_ptr(),
-                recvbuf.displs().as_ptr(),
+                recvpart.counts.as_ptr(),
+                recvpart.displs.as_ptr(),
                 recvbuf.as_datatype().as_raw(),
                 self.as_raw(),
             );
         }
     }
 
+    /// Distribute the send `Buffer`s from all processes to the receive `Buffer`s on all processes,
+    /// where the block exchanged with every process has its own datatype.
+    ///
+    /// Block `i` of `sendlayout` is sent to process `i` and block `j` of `recvlayout` is received
+    /// from process `j`. Unlike the neighborhood variant, the displacements are given as `Count`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layouts do not have one block for each process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_to_all_w.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.8
+    ///
+    /// # Safety
+    /// Every block described by `sendlayout` and `recvlayout` must lie within `sendbuf` and
+    /// `recvbuf` respectively.
+    unsafe fn all_to_all_w_into<S: ?Sized, R: ?Sized>(
+        &self,
+        sendbuf: &S,
+        sendlayout: BlockLayout<'_, Count>,
+        recvbuf: &mut R,
+        recvlayout: BlockLayout<'_, Count>,
+    ) where
+        S: Pointer,
+        R: PointerMut,
+    {
+        assert_eq!(
+            sendlayout.counts().count(),
+            self.target_size(),
+            "sendlayout must have one block for each process"
+        );
+        assert_eq!(
+            recvlayout.counts().count(),
+            self.target_size(),
+            "recvlayout must have one block for each process"
+        );
+        ffi::MPI_Alltoallw(
+            sendbuf.pointer(),
+            sendlayout.counts().as_ptr(),
+            sendlayout.displs().as_ptr(),
+            sendlayout.datatypes_ptr(),
+            recvbuf.pointer_mut(),
+            recvlayout.counts().as_ptr(),
+            recvlayout.displs().as_ptr(),
+            recvlayout.datatypes_ptr(),
+            self.as_raw(),
+        );
+    }
+
     /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
     /// stores the result in `recvbuf` on all processes.
     ///
@@ -202,18 +396,59 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
+        let datatype = sendbuf.as_datatype();
         unsafe {
-            ffi::MPI_Allreduce(
-                sendbuf.pointer(),
-                recvbuf.pointer_mut(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                op.as_raw(),
-                self.as_raw(),
-            );
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Allreduce(
+                    byte_offset(sendbuf.pointer(), offset),
+                    byte_offset_mut(recvbuf.pointer_mut(), offset),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.as_raw(),
+                )
+            });
         }
     }
 
+    /// Like [`all_reduce_into`](#method.all_reduce_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Examples
+    ///
+    /// See `examples/errors_return.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6, 8.3
+    fn try_all_reduce_into<S: ?Sized, R: ?Sized, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        op: O,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+        O: Operation,
+    {
+        let datatype = sendbuf.as_datatype();
+        check_error(unsafe {
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Allreduce(
+                    byte_offset(sendbuf.pointer(), offset),
+                    byte_offset_mut(recvbuf.pointer_mut(), offset),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.as_raw(),
+                )
+            })
+        })
+    }
+
     /// Performs an element-wise global reduction under the operation `op` of the input data in
     /// `sendbuf` and scatters the result into equal sized blocks in the receive buffers on all
     /// processes.
@@ -235,12 +470,58 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
-        assert_eq!(recvbuf.count() * self.target_size(), sendbuf.count());
+        assert_eq!(
+            recvbuf.large_count() * LargeCount::from(self.target_size()),
+            sendbuf.large_count()
+        );
         unsafe {
-            ffi::MPI_Reduce_scatter_block(
+            large::MPI_Reduce_scatter_block(
                 sendbuf.pointer(),
                 recvbuf.pointer_mut(),
-                recvbuf.count(),
+                reduction_count(recvbuf.large_count()),
+                sendbuf.as_datatype().as_raw(),
+                op.as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Performs an element-wise global reduction under the operation `op` of the input data in
+    /// `sendbuf` and scatters the result into blocks of varying size in the receive buffers on all
+    /// processes.
+    ///
+    /// The counts of the partitions of `recvbuf` give the number of elements of the result that
+    /// each process receives, so `sendbuf` must contain their sum. Only the partition of `recvbuf`
+    /// that belongs to the calling process is written.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.10.2
+    fn reduce_scatter_into<S: ?Sized, R: ?Sized, O>(&self, sendbuf: &S, recvbuf: &mut R, op: O)
+    where
+        S: Buffer,
+        R: PartitionedBufferMut,
+        O: Operation,
+    {
+        assert_eq!(recvbuf.counts().count(), self.target_size());
+        assert_eq!(
+            recvbuf
+                .counts()
+                .iter()
+                .map(|&c| Into::<LargeCount>::into(c))
+                .sum::<LargeCount>(),
+            sendbuf.large_count()
+        );
+        let recvpart = RawPartition::new(&*recvbuf);
+        unsafe {
+            large::MPI_Reduce_scatter(
+                sendbuf.pointer(),
+                own_partition_mut(recvbuf, self.rank()),
+                recvpart.counts.as_ptr(),
                 sendbuf.as_datatype().as_raw(),
                 op.as_raw(),
                 self.as_raw(),
@@ -264,15 +545,18 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
+        let datatype = sendbuf.as_datatype();
         unsafe {
-            ffi::MPI_Scan(
-                sendbuf.pointer(),
-                recvbuf.pointer_mut(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                op.as_raw(),
-                self.as_raw(),
-            );
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Scan(
+                    byte_offset(sendbuf.pointer(), offset),
+                    byte_offset_mut(recvbuf.pointer_mut(), offset),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.as_raw(),
+                )
+            });
         }
     }
 
@@ -292,15 +576,18 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
+        let datatype = sendbuf.as_datatype();
         unsafe {
-            ffi::MPI_Exscan(
-                sendbuf.pointer(),
-                recvbuf.pointer_mut(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                op.as_raw(),
-                self.as_raw(),
-            );
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Exscan(
+                    byte_offset(sendbuf.pointer(), offset),
+                    byte_offset_mut(recvbuf.pointer_mut(), offset),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.as_raw(),
+                )
+            });
         }
     }
 
@@ -387,7 +674,7 @@ pub trait CommunicatorCollectives: Communicator {
     ) -> Request<'a, R, Sc>
     where
         S: 'a + Buffer,
-        R: 'a + PartitionedBufferMut,
+        R: 'a + PartitionedBufferMut<Count = Count>,
         Sc: Scope<'a>,
     {
         unsafe {
@@ -466,8 +753,8 @@ pub trait CommunicatorCollectives: Communicator {
         recvbuf: &'a mut R,
     ) -> Request<'a, R, Sc>
     where
-        S: 'a + PartitionedBuffer,
-        R: 'a + PartitionedBufferMut,
+        S: 'a + PartitionedBuffer<Count = Count>,
+        R: 'a + PartitionedBufferMut<Count = Count>,
         Sc: Scope<'a>,
     {
         unsafe {
@@ -493,6 +780,71 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiate non-blocking all-to-all communication, where the block exchanged with every
+    /// process has its own datatype.
+    ///
+    /// The layouts are borrowed for the lifetime of the request, because MPI may access them
+    /// until the operation completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layouts do not have one block for each process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_to_all_w.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.6
+    ///
+    /// # Safety
+    /// Every block described by `sendlayout` and `recvlayout` must lie within `sendbuf` and
+    /// `recvbuf` respectively.
+    unsafe fn immediate_all_to_all_w_into<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        sendlayout: BlockLayout<'a, Count>,
+        recvbuf: &'a mut R,
+        recvlayout: BlockLayout<'a, Count>,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Pointer,
+        R: 'a + PointerMut,
+        Sc: Scope<'a>,
+    {
+        assert_eq!(
+            sendlayout.counts().count(),
+            self.target_size(),
+            "sendlayout must have one block for each process"
+        );
+        assert_eq!(
+            recvlayout.counts().count(),
+            self.target_size(),
+            "recvlayout must have one block for each process"
+        );
+        Request::from_raw(
+            with_uninitialized(|request| {
+                ffi::MPI_Ialltoallw(
+                    sendbuf.pointer(),
+                    sendlayout.counts().as_ptr(),
+                    sendlayout.displs().as_ptr(),
+                    sendlayout.datatypes_ptr(),
+                    recvbuf.pointer_mut(),
+                    recvlayout.counts().as_ptr(),
+                    recvlayout.displs().as_ptr(),
+                    recvlayout.datatypes_ptr(),
+                    self.as_raw(),
+                    request,
+                )
+            })
+            .1,
+            recvbuf,
+            scope,
+        )
+    }
+
     /// Initiates a non-blocking global reduction under the operation `op` of the input data in
     /// `sendbuf` and stores the result in `recvbuf` on all processes.
     ///
@@ -581,6 +933,54 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiates a non-blocking element-wise global reduction under the operation `op` of the
+    /// input data in `sendbuf` and scatters the result into blocks of varying size in the receive
+    /// buffers on all processes.
+    ///
+    /// Only the partition of `recvbuf` that belongs to the calling process is written.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.9
+    fn immediate_reduce_scatter_into<'a, S: ?Sized, R: ?Sized, O, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + PartitionedBufferMut<Count = Count>,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
+    {
+        assert_eq!(recvbuf.counts().count(), self.target_size());
+        assert_eq!(recvbuf.counts().iter().sum::<Count>(), sendbuf.count());
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ireduce_scatter(
+                        sendbuf.pointer(),
+                        own_partition_mut(recvbuf, self.rank()),
+                        recvbuf.counts().as_ptr(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
     /// Initiates a non-blocking global inclusive prefix reduction of the data in `sendbuf` into
     /// `recvbuf` under operation `op`.
     ///
@@ -666,429 +1066,2366 @@ pub trait CommunicatorCollectives: Communicator {
             )
         }
     }
-}
-
-impl<C: Communicator + ?Sized> CommunicatorCollectives for C {}
-
-/// Something that can take the role of 'root' in a collective operation.
-///
-/// Many collective operations define a 'root' process that takes a special role in the
-/// communication. These collective operations are implemented as default methods of this trait.
-pub trait Root: AsCommunicator {
-    /// Rank of the root process
-    fn root_rank(&self) -> Rank;
 
-    /// Broadcast of the contents of a buffer
+    /// Create a persistent barrier synchronization among all processes in a `Communicator`
     ///
-    /// After the call completes, the `Buffer` on all processes in the `Communicator` of the `Root`
-    /// `&self` will contain what it contains on the `Root`.
+    /// Each time the returned request is started and completed, the processes synchronize as in
+    /// [`barrier()`](#method.barrier).
     ///
     /// # Examples
     ///
-    /// See `examples/broadcast.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.4
-    fn broadcast_into<Buf: ?Sized>(&self, buffer: &mut Buf)
-    where
-        Buf: BufferMut,
-    {
+    /// 6.13.1
+    #[cfg(mpi4)]
+    fn barrier_init(&self) -> PersistentRequest<'static, ()> {
         unsafe {
-            ffi::MPI_Bcast(
-                buffer.pointer_mut(),
-                buffer.count(),
-                buffer.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Barrier_init(self.as_raw(), ffi::RSMPI_INFO_NULL, request)
+                })
+                .1,
+                // `()` is zero-sized, so a dangling pointer is a valid `&'static mut ()` that does
+                // not need an allocation.
+                ptr::NonNull::dangling().as_mut(),
+                StaticScope,
+            )
         }
     }
 
-    /// Gather contents of buffers on `Root`.
-    ///
-    /// After the call completes, the contents of the `Buffer`s on all ranks will be
-    /// concatenated into the `Buffer` on `Root`.
-    ///
-    /// All send `Buffer`s must have the same count of elements.
-    ///
-    /// This function must be called on all non-root processes.
+    /// Create a persistent gather of the contents of all `sendbuf`s into all `recvbuf`s on all
+    /// processes in the communicator.
     ///
     /// # Examples
     ///
-    /// See `examples/gather.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.5
-    fn gather_into<S: ?Sized>(&self, sendbuf: &S)
+    /// 6.13.5
+    #[cfg(mpi4)]
+    fn all_gather_init<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
     where
-        S: Buffer,
-    {
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        let recvcount = recvbuf.count() / self.target_size();
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Allgather_init(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvcount,
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent gather of the contents of all `sendbuf`s into all `recvbuf`s on all
+    /// processes in the communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.5
+    #[cfg(mpi4)]
+    fn all_gather_varcount_init<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + PartitionedBufferMut<Count = Count>,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Allgatherv_init(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.counts().as_ptr(),
+                        recvbuf.displs().as_ptr(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent all-to-all communication.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.6
+    #[cfg(mpi4)]
+    fn all_to_all_init<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        let c_size = self.target_size();
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Alltoall_init(
+                        sendbuf.pointer(),
+                        sendbuf.count() / c_size,
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.count() / c_size,
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent all-to-all communication with varying counts.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.6
+    #[cfg(mpi4)]
+    fn all_to_all_varcount_init<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + PartitionedBuffer<Count = Count>,
+        R: 'a + PartitionedBufferMut<Count = Count>,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Alltoallv_init(
+                        sendbuf.pointer(),
+                        sendbuf.counts().as_ptr(),
+                        sendbuf.displs().as_ptr(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.counts().as_ptr(),
+                        recvbuf.displs().as_ptr(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent global reduction under the operation `op` of the input data in
+    /// `sendbuf` that stores the result in `recvbuf` on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.8
+    #[cfg(mpi4)]
+    fn all_reduce_init<'a, S: ?Sized, R: ?Sized, O, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Allreduce_init(
+                        sendbuf.pointer(),
+                        recvbuf.pointer_mut(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent element-wise global reduction under the operation `op` of the input
+    /// data in `sendbuf` that scatters the result into equal sized blocks in the receive buffers
+    /// on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.9
+    #[cfg(mpi4)]
+    fn reduce_scatter_block_init<'a, S: ?Sized, R: ?Sized, O, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
+    {
+        assert_eq!(recvbuf.count() * self.target_size(), sendbuf.count());
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Reduce_scatter_block_init(
+                        sendbuf.pointer(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent global inclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.11
+    #[cfg(mpi4)]
+    fn scan_init<'a, S: ?Sized, R: ?Sized, O, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Scan_init(
+                        sendbuf.pointer(),
+                        recvbuf.pointer_mut(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent global exclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.12
+    #[cfg(mpi4)]
+    fn exclusive_scan_init<'a, S: ?Sized, R: ?Sized, O, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            PersistentCollectiveRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Exscan_init(
+                        sendbuf.pointer(),
+                        recvbuf.pointer_mut(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+}
+
+impl<C: Communicator + ?Sized> CommunicatorCollectives for C {}
+
+/// Layout of a buffer in the `w` variants of the all-to-all operations, e.g.
+/// [`neighbor_all_to_all_w_into()`](trait.NeighborhoodCollectives.html#method.neighbor_all_to_all_w_into)
+///
+/// Block `i` is the part of the buffer that is exchanged with the `i`th peer. It consists of
+/// `counts[i]` elements of `datatypes[i]` starting `displs[i]` bytes from the start of the
+/// buffer. The type `D` of the displacements follows the MPI function that the layout is passed
+/// to.
+#[derive(Copy, Clone, Debug)]
+pub struct BlockLayout<'a, D = Address> {
+    counts: &'a [Count],
+    displs: &'a [D],
+    datatypes: &'a [DatatypeRef<'a>],
+}
+
+impl<'a, D> BlockLayout<'a, D> {
+    /// Describe a buffer from the counts, byte displacements and datatypes of its blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counts`, `displs` and `datatypes` are not of equal length.
+    pub fn new(counts: &'a [Count], displs: &'a [D], datatypes: &'a [DatatypeRef<'a>]) -> Self {
+        assert_eq!(
+            counts.len(),
+            displs.len(),
+            "counts, displs and datatypes must be parallel, equal-sized arrays"
+        );
+        assert_eq!(
+            counts.len(),
+            datatypes.len(),
+            "counts, displs and datatypes must be parallel, equal-sized arrays"
+        );
+        BlockLayout {
+            counts,
+            displs,
+            datatypes,
+        }
+    }
+
+    /// The number of blocks
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Whether the layout contains no blocks
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of elements in each block
+    pub fn counts(&self) -> &'a [Count] {
+        self.counts
+    }
+
+    /// The displacement in bytes of each block from the start of the buffer
+    pub fn displs(&self) -> &'a [D] {
+        self.displs
+    }
+
+    /// The datatype of the elements of each block
+    pub fn datatypes(&self) -> &'a [DatatypeRef<'a>] {
+        self.datatypes
+    }
+
+    fn datatypes_ptr(&self) -> *const MPI_Datatype {
+        // `DatatypeRef` is a transparent wrapper around `MPI_Datatype`
+        self.datatypes.as_ptr().cast()
+    }
+}
+
+/// A pointer to the partition of `buf` that belongs to `rank`
+///
+/// # Safety
+/// `rank` must be a valid index into the partitions of `buf`.
+unsafe fn own_partition_mut<R: ?Sized>(buf: &mut R, rank: Rank) -> *mut c_void
+where
+    R: PartitionedBufferMut,
+{
+    let rank: usize = rank.value_as().expect("rank is negative");
+    let (_, _, extent) = with_uninitialized2(|lb, extent| {
+        ffi::MPI_Type_get_extent(buf.as_datatype().as_raw(), lb, extent)
+    });
+    let offset = Into::<LargeCount>::into(buf.displs()[rank])
+        .value_as::<Address>()
+        .expect("displacement does not fit into an address")
+        * extent;
+    buf.pointer_mut()
+        .cast::<u8>()
+        .offset(
+            offset
+                .value_as()
+                .expect("displacement does not fit into an offset"),
+        )
+        .cast()
+}
+
+/// Divide the `count` of a buffer equally among `degree` neighbors.
+fn per_neighbor<C>(count: C, degree: Count) -> C
+where
+    C: From<Count> + Div<Output = C>,
+{
+    if degree > 0 {
+        count / C::from(degree)
+    } else {
+        C::from(0)
+    }
+}
+
+/// Lowers the block of `buf` that is exchanged with each of `blocks` processes to a count and a
+/// datatype.
+fn counted_block<B>(buf: &B, blocks: Count) -> CountedBuffer<B::Out>
+where
+    B: ?Sized + Collection + AsDatatype,
+{
+    CountedBuffer::with_count(per_neighbor(buf.large_count(), blocks), buf.as_datatype())
+}
+
+/// Reduces `count` elements of `datatype` by calling `reduce` with the offset in bytes and the
+/// count of each chunk.
+///
+/// With MPI-4, the large-count functions reduce all elements at once.
+#[cfg(mpi4)]
+unsafe fn reduce_chunked<F>(count: LargeCount, _datatype: MPI_Datatype, mut reduce: F) -> c_int
+where
+    F: FnMut(Address, RawCount) -> c_int,
+{
+    reduce(0, count)
+}
+
+/// Reduces `count` elements of `datatype` by calling `reduce` with the offset in bytes and the
+/// count of each chunk.
+///
+/// Older libraries are called once for every chunk that fits the range of `Count`, since the
+/// predefined operations cannot be applied to the derived datatypes of a `CountedBuffer`. The
+/// first error ends the reduction.
+#[cfg(not(mpi4))]
+unsafe fn reduce_chunked<F>(count: LargeCount, datatype: MPI_Datatype, mut reduce: F) -> c_int
+where
+    F: FnMut(Address, RawCount) -> c_int,
+{
+    const CHUNK: Count = 1 << 30;
+    let (_, _, extent) =
+        with_uninitialized2(|lb, extent| ffi::MPI_Type_get_extent(datatype, lb, extent));
+    let mut done: LargeCount = 0;
+    loop {
+        let chunk = (count - done).min(LargeCount::from(CHUNK));
+        let offset = done
+            .value_as::<Address>()
+            .ok()
+            .and_then(|done| done.checked_mul(extent))
+            .expect("Size of buffer cannot be expressed as an MPI Address.");
+        let code = reduce(
+            offset,
+            chunk
+                .value_as()
+                .expect("rsmpi internal error: chunk exceeds the range of Count"),
+        );
+        done += chunk;
+        if code != ffi::MPI_SUCCESS as c_int || done >= count {
+            return code;
+        }
+    }
+}
+
+/// The count of a reduction that cannot be split into chunks
+#[cfg(mpi4)]
+fn reduction_count(count: LargeCount) -> RawCount {
+    count
+}
+
+/// The count of a reduction that cannot be split into chunks
+///
+/// # Panics
+///
+/// Panics if `count` exceeds the range of `Count`, which requires MPI-4.
+#[cfg(not(mpi4))]
+fn reduction_count(count: LargeCount) -> RawCount {
+    count
+        .value_as()
+        .expect("Reductions of more than `Count` elements per process require an MPI-4 library.")
+}
+
+/// `ptr` advanced by `offset` bytes, unless it is null
+fn byte_offset(ptr: *const c_void, offset: Address) -> *const c_void {
+    if ptr.is_null() {
+        ptr
+    } else {
+        ptr.cast::<u8>().wrapping_offset(offset).cast()
+    }
+}
+
+/// `ptr` advanced by `offset` bytes, unless it is null
+fn byte_offset_mut(ptr: *mut c_void, offset: Address) -> *mut c_void {
+    if ptr.is_null() {
+        ptr
+    } else {
+        ptr.cast::<u8>().wrapping_offset(offset).cast()
+    }
+}
+
+/// The error of processes that receive the announcement that a value could not be serialized on
+/// `process`
+#[cfg(feature = "serde")]
+fn serialization_failed(process: &str) -> MpiError {
+    MpiError::Serde(format!("Failed to serialize a value on {}", process).into())
+}
+
+/// Collective communication patterns on the neighborhood of a process in a communicator with a
+/// process topology
+///
+/// The sources of the neighborhood are the processes that send data to the calling process and
+/// the destinations are the processes that receive data from it. In a `CartesianCommunicator` both
+/// are the two neighbors in each dimension, ordered by dimension and with the neighbor in the
+/// negative direction first, where missing neighbors at non-periodic boundaries count as
+/// `MPI_PROC_NULL`. In a `GraphCommunicator` both are the neighbors of the calling process, and in a
+/// `DistributedGraphCommunicator` they are the sources and destinations of its edges.
+///
+/// # Standard section(s)
+///
+/// 7.6
+pub trait NeighborhoodCollectives: Communicator {
+    /// The number of sources and destinations in the neighborhood of the calling process.
+    fn neighborhood_size(&self) -> (Count, Count);
+
+    /// Gather the contents of `sendbuf` from all sources into `recvbuf`, while sending the
+    /// contents of `sendbuf` to all destinations.
+    ///
+    /// `recvbuf` is split into equal sized blocks, one for each source.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.6.1
+    fn neighbor_all_gather_into<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        let (indegree, _) = self.neighborhood_size();
+        let send = CountedBuffer::new(sendbuf);
+        let recv = counted_block(&*recvbuf, indegree);
+        unsafe {
+            large::MPI_Neighbor_allgather(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Gather the contents of `sendbuf` from all sources into the partitions of `recvbuf`, while
+    /// sending the contents of `sendbuf` to all destinations.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.6.1
+    fn neighbor_all_gather_varcount_into<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: PartitionedBufferMut,
+    {
+        let send = CountedBuffer::new(sendbuf);
+        let recvpart = RawPartition::new(&*recvbuf);
+        unsafe {
+            large::MPI_Neighbor_allgatherv(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recvpart.counts.as_ptr(),
+                recvpart.displs.as_ptr(),
+                recvbuf.as_datatype().as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Send a distinct block of `sendbuf` to each destination and receive a distinct block of
+    /// `recvbuf` from each source.
+    ///
+    /// Both buffers are split into equal sized blocks, one for each destination and source
+    /// respectively.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.6.2
+    fn neighbor_all_to_all_into<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        let (indegree, outdegree) = self.neighborhood_size();
+        let send = counted_block(sendbuf, outdegree);
+        let recv = counted_block(&*recvbuf, indegree);
+        unsafe {
+            large::MPI_Neighbor_alltoall(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Send partition `i` of `sendbuf` to destination `i` and receive partition `j` of `recvbuf`
+    /// from source `j`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.6.2
+    fn neighbor_all_to_all_varcount_into<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: PartitionedBuffer,
+        R: PartitionedBufferMut,
+    {
+        let sendpart = RawPartition::new(sendbuf);
+        let recvpart = RawPartition::new(&*recvbuf);
+        unsafe {
+            large::MPI_Neighbor_alltoallv(
+                sendbuf.pointer(),
+                sendpart.counts.as_ptr(),
+                sendpart.displs.as_ptr(),
+                sendbuf.as_datatype().as_raw(),
+                recvbuf.pointer_mut(),
+                recvpart.counts.as_ptr(),
+                recvpart.displs.as_ptr(),
+                recvbuf.as_datatype().as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Send block `i` of `sendbuf` to destination `i` and receive block `j` of `recvbuf` from
+    /// source `j`, where every block has its own datatype.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layouts do not have one block for each destination and source respectively.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.6.2
+    ///
+    /// # Safety
+    /// Every block described by `sendlayout` and `recvlayout` must lie within `sendbuf` and
+    /// `recvbuf` respectively.
+    unsafe fn neighbor_all_to_all_w_into<S: ?Sized, R: ?Sized>(
+        &self,
+        sendbuf: &S,
+        sendlayout: BlockLayout<'_, Address>,
+        recvbuf: &mut R,
+        recvlayout: BlockLayout<'_, Address>,
+    ) where
+        S: Pointer,
+        R: PointerMut,
+    {
+        let (indegree, outdegree) = self.neighborhood_size();
+        assert_eq!(
+            sendlayout.counts().count(),
+            outdegree,
+            "sendlayout must have one block for each destination"
+        );
+        assert_eq!(
+            recvlayout.counts().count(),
+            indegree,
+            "recvlayout must have one block for each source"
+        );
+        ffi::MPI_Neighbor_alltoallw(
+            sendbuf.pointer(),
+            sendlayout.counts().as_ptr(),
+            sendlayout.displs().as_ptr(),
+            sendlayout.datatypes_ptr(),
+            recvbuf.pointer_mut(),
+            recvlayout.counts().as_ptr(),
+            recvlayout.displs().as_ptr(),
+            recvlayout.datatypes_ptr(),
+            self.as_raw(),
+        );
+    }
+
+    /// Initiate non-blocking gather of the contents of `sendbuf` from all sources into
+    /// `recvbuf`, while sending the contents of `sendbuf` to all destinations.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.7.1
+    fn immediate_neighbor_all_gather_into<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        let (indegree, _) = self.neighborhood_size();
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ineighbor_allgather(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        per_neighbor(recvbuf.count(), indegree),
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking gather of the contents of `sendbuf` from all sources into the
+    /// partitions of `recvbuf`, while sending the contents of `sendbuf` to all destinations.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.7.1
+    fn immediate_neighbor_all_gather_varcount_into<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + PartitionedBufferMut<Count = Count>,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ineighbor_allgatherv(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.counts().as_ptr(),
+                        recvbuf.displs().as_ptr(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking exchange of a distinct block of `sendbuf` with each destination and
+    /// a distinct block of `recvbuf` with each source.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.7.2
+    fn immediate_neighbor_all_to_all_into<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        let (indegree, outdegree) = self.neighborhood_size();
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ineighbor_alltoall(
+                        sendbuf.pointer(),
+                        per_neighbor(sendbuf.count(), outdegree),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        per_neighbor(recvbuf.count(), indegree),
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking exchange of partition `i` of `sendbuf` with destination `i` and
+    /// partition `j` of `recvbuf` with source `j`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.7.2
+    fn immediate_neighbor_all_to_all_varcount_into<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + PartitionedBuffer<Count = Count>,
+        R: 'a + PartitionedBufferMut<Count = Count>,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ineighbor_alltoallv(
+                        sendbuf.pointer(),
+                        sendbuf.counts().as_ptr(),
+                        sendbuf.displs().as_ptr(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.counts().as_ptr(),
+                        recvbuf.displs().as_ptr(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking exchange of block `i` of `sendbuf` with destination `i` and block
+    /// `j` of `recvbuf` with source `j`, where every block has its own datatype.
+    ///
+    /// The layouts are borrowed for the lifetime of the request, because MPI may access them
+    /// until the operation completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layouts do not have one block for each destination and source respectively.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.7.2
+    ///
+    /// # Safety
+    /// Every block described by `sendlayout` and `recvlayout` must lie within `sendbuf` and
+    /// `recvbuf` respectively.
+    unsafe fn immediate_neighbor_all_to_all_w_into<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        sendlayout: BlockLayout<'a, Address>,
+        recvbuf: &'a mut R,
+        recvlayout: BlockLayout<'a, Address>,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Pointer,
+        R: 'a + PointerMut,
+        Sc: Scope<'a>,
+    {
+        let (indegree, outdegree) = self.neighborhood_size();
+        assert_eq!(
+            sendlayout.counts().count(),
+            outdegree,
+            "sendlayout must have one block for each destination"
+        );
+        assert_eq!(
+            recvlayout.counts().count(),
+            indegree,
+            "recvlayout must have one block for each source"
+        );
+        Request::from_raw(
+            with_uninitialized(|request| {
+                ffi::MPI_Ineighbor_alltoallw(
+                    sendbuf.pointer(),
+                    sendlayout.counts().as_ptr(),
+                    sendlayout.displs().as_ptr(),
+                    sendlayout.datatypes_ptr(),
+                    recvbuf.pointer_mut(),
+                    recvlayout.counts().as_ptr(),
+                    recvlayout.displs().as_ptr(),
+                    recvlayout.datatypes_ptr(),
+                    self.as_raw(),
+                    request,
+                )
+            })
+            .1,
+            recvbuf,
+            scope,
+        )
+    }
+}
+
+impl NeighborhoodCollectives for CartesianCommunicator {
+    fn neighborhood_size(&self) -> (Count, Count) {
+        let degree = 2 * self.num_dimensions();
+        (degree, degree)
+    }
+}
+
+impl NeighborhoodCollectives for GraphCommunicator {
+    fn neighborhood_size(&self) -> (Count, Count) {
+        let degree = self.num_neighbors(self.rank());
+        (degree, degree)
+    }
+}
+
+impl NeighborhoodCollectives for DistributedGraphCommunicator {
+    fn neighborhood_size(&self) -> (Count, Count) {
+        let (indegree, outdegree, _) = self.num_neighbors();
+        (indegree, outdegree)
+    }
+}
+
+/// Something that can take the role of 'root' in a collective operation.
+///
+/// Many collective operations define a 'root' process that takes a special role in the
+/// communication. These collective operations are implemented as default methods of this trait.
+pub trait Root: AsCommunicator {
+    /// Rank of the root process
+    fn root_rank(&self) -> Rank;
+
+    /// Broadcast of the contents of a buffer
+    ///
+    /// After the call completes, the `Buffer` on all processes in the `Communicator` of the `Root`
+    /// `&self` will contain what it contains on the `Root`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_into<Buf: ?Sized>(&self, buffer: &mut Buf)
+    where
+        Buf: BufferMut,
+    {
+        let counted = CountedBuffer::new(&*buffer);
+        unsafe {
+            large::MPI_Bcast(
+                buffer.pointer_mut(),
+                counted.count,
+                counted.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Like [`broadcast_into`](#method.broadcast_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Examples
+    ///
+    /// See `examples/errors_return.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4, 8.3
+    fn try_broadcast_into<Buf: ?Sized>(&self, buffer: &mut Buf) -> Result<(), MpiError>
+    where
+        Buf: BufferMut,
+    {
+        let counted = CountedBuffer::new(&*buffer);
+        check_error(unsafe {
+            large::MPI_Bcast(
+                buffer.pointer_mut(),
+                counted.count,
+                counted.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            )
+        })
+    }
+
+    /// Broadcast of a serialized value
+    ///
+    /// After the call completes, `value` on all processes in the `Communicator` of the `Root`
+    /// `&self` will be a deserialized copy of `value` on the `Root`. Unlike
+    /// [`broadcast_into()`](#method.broadcast_into), this works for any value that implements
+    /// `serde::Serialize` and `serde::Deserialize`, e.g. a `String` or a `HashMap`.
+    ///
+    /// Returns an `Err` on all processes if `value` cannot be serialized on the `Root` and on the
+    /// processes that fail to deserialize the value they received.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    #[cfg(feature = "serde")]
+    fn broadcast_serialized<T>(&self, value: &mut T) -> Result<(), MpiError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        // The root announces a failed serialization with a length of `usize::MAX`.
+        if self.as_communicator().rank() == self.root_rank() {
+            let mut bytes = match bincode::serialize(value) {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    let mut len = usize::MAX;
+                    self.broadcast_into(&mut len);
+                    return Err(error.into());
+                }
+            };
+            let mut len = bytes.len();
+            self.broadcast_into(&mut len);
+            self.broadcast_into(&mut bytes[..]);
+            Ok(())
+        } else {
+            let mut len = 0usize;
+            self.broadcast_into(&mut len);
+            if len == usize::MAX {
+                return Err(serialization_failed("the root process"));
+            }
+            let mut bytes = vec![0u8; len];
+            self.broadcast_into(&mut bytes[..]);
+            *value = bincode::deserialize(&bytes)?;
+            Ok(())
+        }
+    }
+
+    /// Gather contents of buffers on `Root`.
+    ///
+    /// After the call completes, the contents of the `Buffer`s on all ranks will be
+    /// concatenated into the `Buffer` on `Root`.
+    ///
+    /// All send `Buffer`s must have the same count of elements.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_into<S: ?Sized>(&self, sendbuf: &S)
+    where
+        S: Buffer,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let send = CountedBuffer::new(sendbuf);
+        unsafe {
+            large::MPI_Gather(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                ptr::null_mut(),
+                0,
+                u8::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Like [`gather_into`](#method.gather_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5, 8.3
+    fn try_gather_into<S: ?Sized>(&self, sendbuf: &S) -> Result<(), MpiError>
+    where
+        S: Buffer,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let send = CountedBuffer::new(sendbuf);
+        check_error(unsafe {
+            large::MPI_Gather(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                ptr::null_mut(),
+                0,
+                u8::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            )
+        })
+    }
+
+    /// Gather contents of buffers on `Root`.
+    ///
+    /// After the call completes, the contents of the `Buffer`s on all ranks will be
+    /// concatenated into the `Buffer` on `Root`.
+    ///
+    /// All send `Buffer`s must have the same count of elements.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let send = CountedBuffer::new(sendbuf);
+        let recv = counted_block(&*recvbuf, self.as_communicator().target_size());
+        unsafe {
+            large::MPI_Gather(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Like [`gather_into_root`](#method.gather_into_root), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5, 8.3
+    fn try_gather_into_root<S: ?Sized, R: ?Sized>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let send = CountedBuffer::new(sendbuf);
+        let recv = counted_block(&*recvbuf, self.as_communicator().target_size());
+        check_error(unsafe {
+            large::MPI_Gather(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            )
+        })
+    }
+
+    /// Gather contents of buffers on `Root`.
+    ///
+    /// After the call completes, the contents of the `Buffer`s on all ranks will be
+    /// concatenated into the `Buffer` on `Root`.
+    ///
+    /// The send `Buffer`s may contain different counts of elements on different processes. The
+    /// distribution of elements in the receive `Buffer` is specified via `Partitioned`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_varcount.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_varcount_into<S: ?Sized>(&self, sendbuf: &S)
+    where
+        S: Buffer,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let send = CountedBuffer::new(sendbuf);
+        unsafe {
+            large::MPI_Gatherv(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                ptr::null_mut(),
+                ptr::null(),
+                ptr::null(),
+                u8::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Gather contents of buffers on `Root`.
+    ///
+    /// After the call completes, the contents of the `Buffer`s on all ranks will be
+    /// concatenated into the `Buffer` on `Root`.
+    ///
+    /// The send `Buffer`s may contain different counts of elements on different processes. The
+    /// distribution of elements in the receive `Buffer` is specified via `Partitioned`.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_varcount.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_varcount_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: PartitionedBufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let send = CountedBuffer::new(sendbuf);
+        let recvpart = RawPartition::new(&*recvbuf);
+        unsafe {
+            large::MPI_Gatherv(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recvpart.counts.as_ptr(),
+                recvpart.displs.as_ptr(),
+                recvbuf.as_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Gather serialized values from all processes on the root process.
+    ///
+    /// Unlike [`gather_into_root()`](#method.gather_into_root), this works for any value that
+    /// implements `serde::Serialize` and `serde::Deserialize`, and the values may have different
+    /// sizes on different processes. Returns the values in order of rank on the `Root` and `None`
+    /// on all other processes.
+    ///
+    /// Returns an `Err` on processes that fail to serialize their `value`, and on the `Root` if
+    /// any value cannot be serialized or deserialized.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    #[cfg(feature = "serde")]
+    fn gather_serialized<T>(&self, value: &T) -> Result<Option<Vec<T>>, MpiError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        // A process that fails to serialize its value still takes part with an empty message and
+        // announces the failure with a length of -1.
+        let serialized = bincode::serialize(value);
+        let bytes: &[u8] = serialized.as_deref().unwrap_or(&[]);
+        let len: Count = match serialized {
+            Ok(ref bytes) => bytes
+                .len()
+                .value_as()
+                .expect("Length of serialized value cannot be expressed as an MPI Count."),
+            Err(_) => -1,
+        };
+
+        if self.as_communicator().rank() != self.root_rank() {
+            self.gather_into(&len);
+            self.gather_varcount_into(bytes);
+            return serialized.map(|_| None).map_err(MpiError::from);
+        }
+
+        let size = self
+            .as_communicator()
+            .target_size()
+            .value_as()
+            .expect("Communicator size cannot be expressed as a usize.");
+        let mut lens: Vec<Count> = vec![0; size];
+        self.gather_into_root(&len, &mut lens[..]);
+
+        let counts: Vec<Count> = lens.iter().map(|&len| len.max(0)).collect();
+        let displs: Vec<Count> = counts
+            .iter()
+            .scan(0, |acc, &count| {
+                let displ = *acc;
+                *acc += count;
+                Some(displ)
+            })
+            .collect();
+        let total: Count = counts.iter().sum();
+        let mut buf = vec![
+            0u8;
+            total
+                .value_as()
+                .expect("Length of gathered values cannot be expressed as a usize.")
+        ];
+        {
+            let mut partition = PartitionMut::new(&mut buf[..], &counts[..], &displs[..]);
+            self.gather_varcount_into_root(bytes, &mut partition);
+        }
+
+        serialized?;
+        if lens.contains(&-1) {
+            return Err(serialization_failed("a non-root process"));
+        }
+
+        let mut rest = &buf[..];
+        let mut values = Vec::with_capacity(size);
+        for &count in &counts {
+            let (head, tail) = rest.split_at(
+                count
+                    .value_as()
+                    .expect("rsmpi internal error: negative count of serialized value"),
+            );
+            values.push(bincode::deserialize(head)?);
+            rest = tail;
+        }
+        Ok(Some(values))
+    }
+
+    /// Scatter contents of a buffer on the root process to all processes.
+    ///
+    /// After the call completes each participating process will have received a part of the send
+    /// `Buffer` on the root process.
+    ///
+    /// All send `Buffer`s must have the same count of elements.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_into<R: ?Sized>(&self, recvbuf: &mut R)
+    where
+        R: BufferMut,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let recv = CountedBuffer::new(&*recvbuf);
+        unsafe {
+            large::MPI_Scatter(
+                ptr::null(),
+                0,
+                u8::equivalent_datatype().as_raw(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Like [`scatter_into`](#method.scatter_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6, 8.3
+    fn try_scatter_into<R: ?Sized>(&self, recvbuf: &mut R) -> Result<(), MpiError>
+    where
+        R: BufferMut,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let recv = CountedBuffer::new(&*recvbuf);
+        check_error(unsafe {
+            large::MPI_Scatter(
+                ptr::null(),
+                0,
+                u8::equivalent_datatype().as_raw(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            )
+        })
+    }
+
+    /// Scatter contents of a buffer on the root process to all processes.
+    ///
+    /// After the call completes each participating process will have received a part of the send
+    /// `Buffer` on the root process.
+    ///
+    /// All send `Buffer`s must have the same count of elements.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let send = counted_block(sendbuf, self.as_communicator().target_size());
+        let recv = CountedBuffer::new(&*recvbuf);
+        unsafe {
+            large::MPI_Scatter(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Like [`scatter_into_root`](#method.scatter_into_root), but returns the error reported by
+    /// MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6, 8.3
+    fn try_scatter_into_root<S: ?Sized, R: ?Sized>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let send = counted_block(sendbuf, self.as_communicator().target_size());
+        let recv = CountedBuffer::new(&*recvbuf);
+        check_error(unsafe {
+            large::MPI_Scatter(
+                sendbuf.pointer(),
+                send.count,
+                send.datatype(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            )
+        })
+    }
+
+    /// Scatter contents of a buffer on the root process to all processes.
+    ///
+    /// After the call completes each participating process will have received a part of the send
+    /// `Buffer` on the root process.
+    ///
+    /// The send `Buffer` may contain different counts of elements for different processes. The
+    /// distribution of elements in the send `Buffer` is specified via `Partitioned`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_varcount.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_varcount_into<R: ?Sized>(&self, recvbuf: &mut R)
+    where
+        R: BufferMut,
+    {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let recv = CountedBuffer::new(&*recvbuf);
         unsafe {
-            ffi::MPI_Gather(
-                sendbuf.pointer(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                ptr::null_mut(),
-                0,
+            large::MPI_Scatterv(
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
                 u8::equivalent_datatype().as_raw(),
+                recvbuf.pointer_mut(),
+                recv.count,
+                recv.datatype(),
                 self.root_rank(),
                 self.as_communicator().as_raw(),
             );
         }
     }
 
-    /// Gather contents of buffers on `Root`.
+    /// Scatter contents of a buffer on the root process to all processes.
     ///
-    /// After the call completes, the contents of the `Buffer`s on all ranks will be
-    /// concatenated into the `Buffer` on `Root`.
+    /// After the call completes each participating process will have received a part of the send
+    /// `Buffer` on the root process.
     ///
-    /// All send `Buffer`s must have the same count of elements.
+    /// The send `Buffer` may contain different counts of elements for different processes. The
+    /// distribution of elements in the send `Buffer` is specified via `Partitioned`.
     ///
     /// This function must be called on the root process.
     ///
     /// # Examples
     ///
-    /// See `examples/gather.rs`
+    /// See `examples/scatter_varcount.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.5
-    fn gather_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    /// 5.6
+    fn scatter_varcount_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
     where
-        S: Buffer,
+        S: PartitionedBuffer,
         R: BufferMut,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let sendpart = RawPartition::new(sendbuf);
+        let recv = CountedBuffer::new(&*recvbuf);
         unsafe {
-            let recvcount = recvbuf.count() / self.as_communicator().target_size();
-            ffi::MPI_Gather(
+            large::MPI_Scatterv(
                 sendbuf.pointer(),
-                sendbuf.count(),
+                sendpart.counts.as_ptr(),
+                sendpart.displs.as_ptr(),
                 sendbuf.as_datatype().as_raw(),
                 recvbuf.pointer_mut(),
-                recvcount,
-                recvbuf.as_datatype().as_raw(),
+                recv.count,
+                recv.datatype(),
                 self.root_rank(),
                 self.as_communicator().as_raw(),
             );
         }
     }
 
-    /// Gather contents of buffers on `Root`.
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// stores the result on the `Root` process.
     ///
-    /// After the call completes, the contents of the `Buffer`s on all ranks will be
-    /// concatenated into the `Buffer` on `Root`.
+    /// This function must be called on all non-root processes.
     ///
-    /// The send `Buffer`s may contain different counts of elements on different processes. The
-    /// distribution of elements in the receive `Buffer` is specified via `Partitioned`.
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    fn reduce_into<S: ?Sized, O>(&self, sendbuf: &S, op: O)
+    where
+        S: Buffer,
+        O: Operation,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let datatype = sendbuf.as_datatype();
+        unsafe {
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Reduce(
+                    byte_offset(sendbuf.pointer(), offset),
+                    ptr::null_mut(),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.root_rank(),
+                    self.as_communicator().as_raw(),
+                )
+            });
+        }
+    }
+
+    /// Like [`reduce_into`](#method.reduce_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
     ///
     /// This function must be called on all non-root processes.
     ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1, 8.3
+    fn try_reduce_into<S: ?Sized, O>(&self, sendbuf: &S, op: O) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        O: Operation,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        let datatype = sendbuf.as_datatype();
+        check_error(unsafe {
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Reduce(
+                    byte_offset(sendbuf.pointer(), offset),
+                    ptr::null_mut(),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.root_rank(),
+                    self.as_communicator().as_raw(),
+                )
+            })
+        })
+    }
+
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// stores the result on the `Root` process.
+    ///
+    /// This function must be called on the root process.
+    ///
     /// # Examples
     ///
-    /// See `examples/gather_varcount.rs`
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    fn reduce_into_root<S: ?Sized, R: ?Sized, O>(&self, sendbuf: &S, recvbuf: &mut R, op: O)
+    where
+        S: Buffer,
+        R: BufferMut,
+        O: Operation,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let datatype = sendbuf.as_datatype();
+        unsafe {
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Reduce(
+                    byte_offset(sendbuf.pointer(), offset),
+                    byte_offset_mut(recvbuf.pointer_mut(), offset),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.root_rank(),
+                    self.as_communicator().as_raw(),
+                )
+            });
+        }
+    }
+
+    /// Like [`reduce_into_root`](#method.reduce_into_root), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1, 8.3
+    fn try_reduce_into_root<S: ?Sized, R: ?Sized, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        op: O,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+        O: Operation,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let datatype = sendbuf.as_datatype();
+        check_error(unsafe {
+            reduce_chunked(sendbuf.large_count(), datatype.as_raw(), |offset, count| {
+                large::MPI_Reduce(
+                    byte_offset(sendbuf.pointer(), offset),
+                    byte_offset_mut(recvbuf.pointer_mut(), offset),
+                    count,
+                    datatype.as_raw(),
+                    op.as_raw(),
+                    self.root_rank(),
+                    self.as_communicator().as_raw(),
+                )
+            })
+        })
+    }
+
+    /// Initiate broadcast of a value from the `Root` process to all other processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_broadcast.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.2
+    fn immediate_broadcast_into<'a, Buf: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ibcast(
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_gather.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.3
+    fn immediate_gather_into<'a, S: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+    ) -> Request<'a, S, Sc>
+    where
+        S: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Igather(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        ptr::null_mut(),
+                        0,
+                        u8::equivalent_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// This function must be called on the root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_gather.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.3
+    fn immediate_gather_into_root<'a, S: ?Sized, R: ?Sized, Sc>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        unsafe {
+            let recvcount = recvbuf.count() / self.as_communicator().target_size();
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Igather(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvcount,
+                        recvbuf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_gather_varcount.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.5
-    fn gather_varcount_into<S: ?Sized>(&self, sendbuf: &S)
+    /// 5.12.3
+    fn immediate_gather_varcount_into<'a, Sc, S: ?Sized>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+    ) -> Request<'a, S, Sc>
     where
-        S: Buffer,
+        S: 'a + Buffer,
+        Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Gatherv(
-                sendbuf.pointer(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                ptr::null_mut(),
-                ptr::null(),
-                ptr::null(),
-                u8::equivalent_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Igatherv(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        ptr::null_mut(),
+                        ptr::null(),
+                        ptr::null(),
+                        u8::equivalent_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                scope,
+            )
         }
     }
 
-    /// Gather contents of buffers on `Root`.
-    ///
-    /// After the call completes, the contents of the `Buffer`s on all ranks will be
-    /// concatenated into the `Buffer` on `Root`.
-    ///
-    /// The send `Buffer`s may contain different counts of elements on different processes. The
-    /// distribution of elements in the receive `Buffer` is specified via `Partitioned`.
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
-    /// This function must be called on the root process.
+    /// This function must be called on the root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/gather_varcount.rs`
+    /// See `examples/immediate_gather_varcount.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.5
-    fn gather_varcount_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    /// 5.12.3
+    fn immediate_gather_varcount_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
     where
-        S: Buffer,
-        R: PartitionedBufferMut,
+        S: 'a + Buffer,
+        R: 'a + PartitionedBufferMut<Count = Count>,
+        Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Gatherv(
-                sendbuf.pointer(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                recvbuf.pointer_mut(),
-                recvbuf.counts().as_ptr(),
-                recvbuf.displs().as_ptr(),
-                recvbuf.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Igatherv(
+                        sendbuf.pointer(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.counts().as_ptr(),
+                        recvbuf.displs().as_ptr(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
         }
     }
 
-    /// Scatter contents of a buffer on the root process to all processes.
-    ///
-    /// After the call completes each participating process will have received a part of the send
-    /// `Buffer` on the root process.
-    ///
-    /// All send `Buffer`s must have the same count of elements.
+    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/scatter.rs`
+    /// See `examples/immediate_scatter.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.6
-    fn scatter_into<R: ?Sized>(&self, recvbuf: &mut R)
+    /// 5.12.4
+    fn immediate_scatter_into<'a, Sc, R: ?Sized>(
+        &self,
+        scope: Sc,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
     where
-        R: BufferMut,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Scatter(
-                ptr::null(),
-                0,
-                u8::equivalent_datatype().as_raw(),
-                recvbuf.pointer_mut(),
-                recvbuf.count(),
-                recvbuf.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Iscatter(
+                        ptr::null(),
+                        0,
+                        u8::equivalent_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.count(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
         }
     }
 
-    /// Scatter contents of a buffer on the root process to all processes.
-    ///
-    /// After the call completes each participating process will have received a part of the send
-    /// `Buffer` on the root process.
-    ///
-    /// All send `Buffer`s must have the same count of elements.
+    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
-    /// This function must be called on the root process.
+    /// This function must be called on the root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/scatter.rs`
+    /// See `examples/immediate_scatter.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.6
-    fn scatter_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    /// 5.12.4
+    fn immediate_scatter_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
     where
-        S: Buffer,
-        R: BufferMut,
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
-        let sendcount = sendbuf.count() / self.as_communicator().target_size();
         unsafe {
-            ffi::MPI_Scatter(
-                sendbuf.pointer(),
-                sendcount,
-                sendbuf.as_datatype().as_raw(),
-                recvbuf.pointer_mut(),
-                recvbuf.count(),
-                recvbuf.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            let sendcount = sendbuf.count() / self.as_communicator().target_size();
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Iscatter(
+                        sendbuf.pointer(),
+                        sendcount,
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.count(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
         }
     }
 
-    /// Scatter contents of a buffer on the root process to all processes.
-    ///
-    /// After the call completes each participating process will have received a part of the send
-    /// `Buffer` on the root process.
-    ///
-    /// The send `Buffer` may contain different counts of elements for different processes. The
-    /// distribution of elements in the send `Buffer` is specified via `Partitioned`.
+    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/scatter_varcount.rs`
+    /// See `examples/immediate_scatter_varcount.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.6
-    fn scatter_varcount_into<R: ?Sized>(&self, recvbuf: &mut R)
+    /// 5.12.4
+    fn immediate_scatter_varcount_into<'a, Sc, R: ?Sized>(
+        &self,
+        scope: Sc,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
     where
-        R: BufferMut,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Scatterv(
-                ptr::null(),
-                ptr::null(),
-                ptr::null(),
-                u8::equivalent_datatype().as_raw(),
-                recvbuf.pointer_mut(),
-                recvbuf.count(),
-                recvbuf.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Iscatterv(
+                        ptr::null(),
+                        ptr::null(),
+                        ptr::null(),
+                        u8::equivalent_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.count(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
         }
     }
 
-    /// Scatter contents of a buffer on the root process to all processes.
-    ///
-    /// After the call completes each participating process will have received a part of the send
-    /// `Buffer` on the root process.
-    ///
-    /// The send `Buffer` may contain different counts of elements for different processes. The
-    /// distribution of elements in the send `Buffer` is specified via `Partitioned`.
+    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
-    /// This function must be called on the root process.
+    /// This function must be called on the root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/scatter_varcount.rs`
+    /// See `examples/immediate_scatter_varcount.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.6
-    fn scatter_varcount_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    /// 5.12.4
+    fn immediate_scatter_varcount_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
     where
-        S: PartitionedBuffer,
-        R: BufferMut,
+        S: 'a + PartitionedBuffer<Count = Count>,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Scatterv(
-                sendbuf.pointer(),
-                sendbuf.counts().as_ptr(),
-                sendbuf.displs().as_ptr(),
-                sendbuf.as_datatype().as_raw(),
-                recvbuf.pointer_mut(),
-                recvbuf.count(),
-                recvbuf.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Iscatterv(
+                        sendbuf.pointer(),
+                        sendbuf.counts().as_ptr(),
+                        sendbuf.displs().as_ptr(),
+                        sendbuf.as_datatype().as_raw(),
+                        recvbuf.pointer_mut(),
+                        recvbuf.count(),
+                        recvbuf.as_datatype().as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
         }
     }
 
-    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
-    /// stores the result on the `Root` process.
+    /// Initiates a non-blacking global reduction under the operation `op` of the input data in
+    /// `sendbuf` and stores the result on the `Root` process.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/reduce.rs`
+    /// See `examples/immediate_reduce.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.9.1
-    fn reduce_into<S: ?Sized, O>(&self, sendbuf: &S, op: O)
+    /// 5.12.7
+    fn immediate_reduce_into<'a, Sc, S: ?Sized, O>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        op: O,
+    ) -> Request<'a, S, Sc>
     where
-        S: Buffer,
-        O: Operation,
+        S: 'a + Buffer,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Reduce(
-                sendbuf.pointer(),
-                ptr::null_mut(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                op.as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ireduce(
+                        sendbuf.pointer(),
+                        ptr::null_mut(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                sendbuf,
+                scope,
+            )
         }
     }
 
-    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
-    /// stores the result on the `Root` process.
-    ///
-    /// This function must be called on the root process.
+    /// Initiates a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` and stores the result on the `Root` process.
     ///
     /// # Examples
     ///
-    /// See `examples/reduce.rs`
+    /// See `examples/immediate_reduce.rs`
+    ///
+    /// This function must be called on the root process.
     ///
     /// # Standard section(s)
     ///
-    /// 5.9.1
-    fn reduce_into_root<S: ?Sized, R: ?Sized, O>(&self, sendbuf: &S, recvbuf: &mut R, op: O)
+    /// 5.12.7
+    fn immediate_reduce_into_root<'a, Sc, S: ?Sized, R: ?Sized, O>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> Request<'a, R, Sc>
     where
-        S: Buffer,
-        R: BufferMut,
-        O: Operation,
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            ffi::MPI_Reduce(
-                sendbuf.pointer(),
-                recvbuf.pointer_mut(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                op.as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-            );
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ireduce(
+                        sendbuf.pointer(),
+                        recvbuf.pointer_mut(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                recvbuf,
+                scope,
+            )
         }
     }
 
-    /// Initiate broadcast of a value from the `Root` process to all other processes.
+    /// Create a persistent broadcast of a value from the `Root` process to all other processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_broadcast.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.2
-    fn immediate_broadcast_into<'a, Buf: ?Sized, Sc>(
+    /// 6.13.2
+    #[cfg(mpi4)]
+    fn broadcast_init<'a, Buf: ?Sized, Sc>(
         &self,
         scope: Sc,
         buf: &'a mut Buf,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + BufferMut,
         Sc: Scope<'a>,
     {
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Ibcast(
+                    ffi::MPI_Bcast_init(
                         buf.pointer_mut(),
                         buf.count(),
                         buf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
@@ -1099,31 +3436,32 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    /// Create a persistent gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_gather.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.3
-    fn immediate_gather_into<'a, S: ?Sized, Sc>(
+    /// 6.13.3
+    #[cfg(mpi4)]
+    fn gather_init<'a, S: ?Sized, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
-    ) -> Request<'a, S, Sc>
+        sendbuf: &'a mut S,
+    ) -> PersistentRequest<'a, S, Sc>
     where
         S: 'a + Buffer,
         Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Igather(
+                    ffi::MPI_Gather_init(
                         sendbuf.pointer(),
                         sendbuf.count(),
                         sendbuf.as_datatype().as_raw(),
@@ -1132,6 +3470,7 @@ pub trait Root: AsCommunicator {
                         u8::equivalent_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
@@ -1142,34 +3481,35 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    /// Create a persistent gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
-    /// This function must be called on the root processes.
+    /// This function must be called on the root process.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_gather.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.3
-    fn immediate_gather_into_root<'a, S: ?Sized, R: ?Sized, Sc>(
+    /// 6.13.3
+    #[cfg(mpi4)]
+    fn gather_init_root<'a, S: ?Sized, R: ?Sized, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
+        sendbuf: &'a mut S,
         recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
     where
         S: 'a + Buffer,
         R: 'a + BufferMut,
         Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let recvcount = recvbuf.count() / self.as_communicator().target_size();
         unsafe {
-            let recvcount = recvbuf.count() / self.as_communicator().target_size();
-            Request::from_raw(
+            PersistentCollectiveRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Igather(
+                    ffi::MPI_Gather_init(
                         sendbuf.pointer(),
                         sendbuf.count(),
                         sendbuf.as_datatype().as_raw(),
@@ -1178,41 +3518,44 @@ pub trait Root: AsCommunicator {
                         recvbuf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
                 .1,
+                sendbuf,
                 recvbuf,
                 scope,
             )
         }
     }
 
-    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    /// Create a persistent gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_gather_varcount.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.3
-    fn immediate_gather_varcount_into<'a, Sc, S: ?Sized>(
+    /// 6.13.3
+    #[cfg(mpi4)]
+    fn gather_varcount_init<'a, S: ?Sized, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
-    ) -> Request<'a, S, Sc>
+        sendbuf: &'a mut S,
+    ) -> PersistentRequest<'a, S, Sc>
     where
         S: 'a + Buffer,
         Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Igatherv(
+                    ffi::MPI_Gatherv_init(
                         sendbuf.pointer(),
                         sendbuf.count(),
                         sendbuf.as_datatype().as_raw(),
@@ -1222,6 +3565,7 @@ pub trait Root: AsCommunicator {
                         u8::equivalent_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
@@ -1232,33 +3576,34 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    /// Create a persistent gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
-    /// This function must be called on the root processes.
+    /// This function must be called on the root process.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_gather_varcount.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.3
-    fn immediate_gather_varcount_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+    /// 6.13.3
+    #[cfg(mpi4)]
+    fn gather_varcount_init_root<'a, S: ?Sized, R: ?Sized, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
+        sendbuf: &'a mut S,
         recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
     where
         S: 'a + Buffer,
-        R: 'a + PartitionedBufferMut,
+        R: 'a + PartitionedBufferMut<Count = Count>,
         Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentCollectiveRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Igatherv(
+                    ffi::MPI_Gatherv_init(
                         sendbuf.pointer(),
                         sendbuf.count(),
                         sendbuf.as_datatype().as_raw(),
@@ -1268,41 +3613,44 @@ pub trait Root: AsCommunicator {
                         recvbuf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
                 .1,
+                sendbuf,
                 recvbuf,
                 scope,
             )
         }
     }
 
-    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
+    /// Create a persistent scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_scatter.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.4
-    fn immediate_scatter_into<'a, Sc, R: ?Sized>(
+    /// 6.13.4
+    #[cfg(mpi4)]
+    fn scatter_init<'a, R: ?Sized, Sc>(
         &self,
         scope: Sc,
         recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentRequest<'a, R, Sc>
     where
         R: 'a + BufferMut,
         Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Iscatter(
+                    ffi::MPI_Scatter_init(
                         ptr::null(),
                         0,
                         u8::equivalent_datatype().as_raw(),
@@ -1311,6 +3659,7 @@ pub trait Root: AsCommunicator {
                         recvbuf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
@@ -1321,34 +3670,35 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
+    /// Create a persistent scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
-    /// This function must be called on the root processes.
+    /// This function must be called on the root process.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_scatter.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.4
-    fn immediate_scatter_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+    /// 6.13.4
+    #[cfg(mpi4)]
+    fn scatter_init_root<'a, S: ?Sized, R: ?Sized, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
+        sendbuf: &'a mut S,
         recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
     where
         S: 'a + Buffer,
         R: 'a + BufferMut,
         Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let sendcount = sendbuf.count() / self.as_communicator().target_size();
         unsafe {
-            let sendcount = sendbuf.count() / self.as_communicator().target_size();
-            Request::from_raw(
+            PersistentCollectiveRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Iscatter(
+                    ffi::MPI_Scatter_init(
                         sendbuf.pointer(),
                         sendcount,
                         sendbuf.as_datatype().as_raw(),
@@ -1357,41 +3707,44 @@ pub trait Root: AsCommunicator {
                         recvbuf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
                 .1,
+                sendbuf,
                 recvbuf,
                 scope,
             )
         }
     }
 
-    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
+    /// Create a persistent scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_scatter_varcount.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.4
-    fn immediate_scatter_varcount_into<'a, Sc, R: ?Sized>(
+    /// 6.13.4
+    #[cfg(mpi4)]
+    fn scatter_varcount_init<'a, R: ?Sized, Sc>(
         &self,
         scope: Sc,
         recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentRequest<'a, R, Sc>
     where
         R: 'a + BufferMut,
         Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Iscatterv(
+                    ffi::MPI_Scatterv_init(
                         ptr::null(),
                         ptr::null(),
                         ptr::null(),
@@ -1401,6 +3754,7 @@ pub trait Root: AsCommunicator {
                         recvbuf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
@@ -1411,33 +3765,34 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
+    /// Create a persistent scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
-    /// This function must be called on the root processes.
+    /// This function must be called on the root process.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_scatter_varcount.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.4
-    fn immediate_scatter_varcount_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+    /// 6.13.4
+    #[cfg(mpi4)]
+    fn scatter_varcount_init_root<'a, S: ?Sized, R: ?Sized, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
+        sendbuf: &'a mut S,
         recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
     where
-        S: 'a + PartitionedBuffer,
+        S: 'a + PartitionedBuffer<Count = Count>,
         R: 'a + BufferMut,
         Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentCollectiveRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Iscatterv(
+                    ffi::MPI_Scatterv_init(
                         sendbuf.pointer(),
                         sendbuf.counts().as_ptr(),
                         sendbuf.displs().as_ptr(),
@@ -1447,34 +3802,37 @@ pub trait Root: AsCommunicator {
                         recvbuf.as_datatype().as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
                 .1,
+                sendbuf,
                 recvbuf,
                 scope,
             )
         }
     }
 
-    /// Initiates a non-blacking global reduction under the operation `op` of the input data in
-    /// `sendbuf` and stores the result on the `Root` process.
+    /// Create a persistent global reduction under the operation `op` of the input data in
+    /// `sendbuf` that stores the result on the `Root` process.
     ///
     /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_reduce.rs`
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.7
-    fn immediate_reduce_into<'a, Sc, S: ?Sized, O>(
+    /// 6.13.7
+    #[cfg(mpi4)]
+    fn reduce_init<'a, S: ?Sized, O, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
+        sendbuf: &'a mut S,
         op: O,
-    ) -> Request<'a, S, Sc>
+    ) -> PersistentRequest<'a, S, Sc>
     where
         S: 'a + Buffer,
         O: 'a + Operation,
@@ -1482,9 +3840,9 @@ pub trait Root: AsCommunicator {
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Ireduce(
+                    ffi::MPI_Reduce_init(
                         sendbuf.pointer(),
                         ptr::null_mut(),
                         sendbuf.count(),
@@ -1492,6 +3850,7 @@ pub trait Root: AsCommunicator {
                         op.as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
@@ -1502,25 +3861,26 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Initiates a non-blocking global reduction under the operation `op` of the input data in
-    /// `sendbuf` and stores the result on the `Root` process.
+    /// Create a persistent global reduction under the operation `op` of the input data in
+    /// `sendbuf` that stores the result on the `Root` process.
     ///
-    /// # Examples
+    /// This function must be called on the root process.
     ///
-    /// See `examples/immediate_reduce.rs`
+    /// # Examples
     ///
-    /// This function must be called on the root process.
+    /// See `examples/persistent_collectives.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 5.12.7
-    fn immediate_reduce_into_root<'a, Sc, S: ?Sized, R: ?Sized, O>(
+    /// 6.13.7
+    #[cfg(mpi4)]
+    fn reduce_init_root<'a, S: ?Sized, R: ?Sized, O, Sc>(
         &self,
         scope: Sc,
-        sendbuf: &'a S,
+        sendbuf: &'a mut S,
         recvbuf: &'a mut R,
         op: O,
-    ) -> Request<'a, R, Sc>
+    ) -> PersistentCollectiveRequest<'a, S, R, Sc>
     where
         S: 'a + Buffer,
         R: 'a + BufferMut,
@@ -1529,9 +3889,9 @@ pub trait Root: AsCommunicator {
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            Request::from_raw(
+            PersistentCollectiveRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Ireduce(
+                    ffi::MPI_Reduce_init(
                         sendbuf.pointer(),
                         recvbuf.pointer_mut(),
                         sendbuf.count(),
@@ -1539,10 +3899,12 @@ pub trait Root: AsCommunicator {
                         op.as_raw(),
                         self.root_rank(),
                         self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
                         request,
                     )
                 })
                 .1,
+                sendbuf,
                 recvbuf,
                 scope,
             )
@@ -1554,6 +3916,20 @@ pub trait Root: AsCommunicator {
     /// # Standard sections
     /// 10.3.2, see MPI_Comm_spawn
     fn spawn(&self, command: &Command, maxprocs: Rank) -> Result<InterCommunicator, MpiError> {
+        self.spawn_with_info(command, maxprocs, &Info::new())
+    }
+
+    /// Spawns child processes, passing the hints in `info` to MPI, e.g. where and how to start
+    /// the processes.
+    ///
+    /// # Standard sections
+    /// 10.3.2, see MPI_Comm_spawn
+    fn spawn_with_info(
+        &self,
+        command: &Command,
+        maxprocs: Rank,
+        info: &Info,
+    ) -> Result<InterCommunicator, MpiError> {
         // Environment variables can be handled using the info key
         assert_eq!(
             command.get_envs().len(),
@@ -1596,7 +3972,7 @@ pub trait Root: AsCommunicator {
                 prog.as_ptr(),
                 argv.as_mut_ptr(),
                 maxprocs,
-                ffi::RSMPI_INFO_NULL,
+                info.as_raw(),
                 self.root_rank(),
                 self.as_communicator().as_raw(),
                 &mut result,
@@ -1622,8 +3998,23 @@ pub trait Root: AsCommunicator {
         &self,
         commands: &[Command],
         maxprocs: &[Rank],
+    ) -> Result<InterCommunicator, MpiError> {
+        let infos: Vec<Info> = commands.iter().map(|_| Info::new()).collect();
+        self.spawn_multiple_with_info(commands, maxprocs, &infos)
+    }
+
+    /// Spawns child processes, passing the hints in `infos[i]` to MPI for `commands[i]`.
+    ///
+    /// # Standard sections
+    /// 10.3.3, see MPI_Comm_spawn_multiple
+    fn spawn_multiple_with_info(
+        &self,
+        commands: &[Command],
+        maxprocs: &[Rank],
+        infos: &[Info],
     ) -> Result<InterCommunicator, MpiError> {
         assert_eq!(commands.len(), maxprocs.len());
+        assert_eq!(commands.len(), infos.len());
 
         let progs = commands
             .iter()
@@ -1651,10 +4042,6 @@ pub trait Root: AsCommunicator {
         let mut argvv: Vec<*mut *mut c_char> =
             argvs.iter_mut().map(|argv| argv.as_mut_ptr()).collect();
 
-        let infos: Vec<_> = (0..commands.len())
-            .map(|_| unsafe { ffi::RSMPI_INFO_NULL })
-            .collect();
-
         let mut result = unsafe { ffi::RSMPI_COMM_NULL };
         let sum_maxprocs: Rank = maxprocs.iter().sum();
         let mut errcodes = vec![0; usize::try_from(sum_maxprocs).unwrap()];
@@ -1665,7 +4052,7 @@ pub trait Root: AsCommunicator {
                 progp.as_mut_ptr(),
                 argvv.as_mut_ptr(),
                 maxprocs.as_ptr(),
-                infos.as_ptr(),
+                infos.as_ptr() as *const MPI_Info,
                 self.root_rank(),
                 self.as_communicator().as_raw(),
                 &mut result,
@@ -2053,13 +4440,16 @@ where
     R: BufferMut,
     O: Operation,
 {
+    let datatype = inbuf.as_datatype();
     unsafe {
-        ffi::MPI_Reduce_local(
-            inbuf.pointer(),
-            inoutbuf.pointer_mut(),
-            inbuf.count(),
-            inbuf.as_datatype().as_raw(),
-            op.as_raw(),
-        );
+        reduce_chunked(inbuf.large_count(), datatype.as_raw(), |offset, count| {
+            large::MPI_Reduce_local(
+                byte_offset(inbuf.pointer(), offset),
+                byte_offset_mut(inoutbuf.pointer_mut(), offset),
+                count,
+                datatype.as_raw(),
+                op.as_raw(),
+            )
+        });
     }
 }
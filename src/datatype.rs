@@ -52,38 +52,41 @@
 //!
 //! # Unfinished features
 //!
-//! - **4.1.3**: Subarray datatype constructors, `MPI_Type_create_subarray()`,
-//! - **4.1.4**: Distributed array datatype constructors, `MPI_Type_create_darray()`
-//! - **4.1.5**: Address and size functions, `MPI_Get_address()`, `MPI_Aint_add()`,
-//! `MPI_Aint_diff()`, `MPI_Type_size()`, `MPI_Type_size_x()`
-//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent()`,
-//! `MPI_Type_get_extent_x()`, `MPI_Type_create_resized()`
-//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent()`,
-//! `MPI_Type_get_true_extent_x()`
+//! - **4.1.5**: Address and size functions, `MPI_Aint_add()`, `MPI_Aint_diff()`,
+//! `MPI_Type_size_x()`
+//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent_x()`
+//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent_x()`
 //! - **4.1.11**: `MPI_Get_elements()`, `MPI_Get_elements_x()`
-//! - **4.1.13**: Decoding a datatype, `MPI_Type_get_envelope()`, `MPI_Type_get_contents()`
-//! - **4.3**: Canonical pack and unpack, `MPI_Pack_external()`, `MPI_Unpack_external()`,
-//! `MPI_Pack_external_size()`
 
-use std::{borrow::Borrow, marker::PhantomData, mem, os::raw::c_void, slice};
+use std::{
+    borrow::Borrow,
+    ffi::CStr,
+    fmt,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    os::raw::{c_char, c_int, c_void},
+    slice,
+};
 
 use conv::ConvUtil;
 
-use super::{Address, Count};
-use crate::{ffi, ffi::MPI_Datatype, raw::traits::*, with_uninitialized};
+use super::{Address, Count, LargeCount, Rank};
+use crate::{ffi, ffi::MPI_Datatype, raw::traits::*, with_uninitialized, with_uninitialized2};
 
 /// Datatype traits
 pub mod traits {
     pub use super::{
-        AsDatatype, Buffer, BufferMut, Collection, Datatype, Equivalence, Partitioned,
-        PartitionedBuffer, PartitionedBufferMut, Pointer, PointerMut, UncommittedDatatype,
+        AsDatatype, Buffer, BufferMut, Collection, Datatype, Equivalence, EquivalentEnum,
+        FixedBuffer, Partitioned, PartitionedBuffer, PartitionedBufferMut, Pointer, PointerMut,
+        UncommittedDatatype,
     };
 }
 
 /// A reference to an MPI data type.
 ///
 /// This is similar to a raw `MPI_Datatype` but is guaranteed to be a valid for `'a`.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(transparent)]
 pub struct DatatypeRef<'a> {
     datatype: MPI_Datatype,
     phantom: PhantomData<&'a ()>,
@@ -240,6 +243,115 @@ pub mod complex_datatype {
     equivalent_system_datatype!(Complex64, ffi::RSMPI_DOUBLE_COMPLEX);
 }
 
+/// An enum without fields that is represented by an integer type, e.g. `#[repr(u8)]`
+///
+/// An enum does not implement `Equivalence` itself, since receiving a message into it could produce
+/// a value with an invalid discriminant. Instead, it is communicated as a
+/// [`Checked`](struct.Checked.html) value, which holds the integer representation and only turns
+/// it back into a value of the enum if the discriminant is valid.
+///
+/// # Example
+/// This trait can be derived for enums with an explicit `repr` using the `derive` crate feature.
+/// ```ignore
+/// use mpi_derive::Equivalence;
+///
+/// #[derive(Equivalence)]
+/// #[repr(u8)]
+/// enum State {
+///     Idle,
+///     Running = 4,
+///     Done,
+/// }
+/// ```
+pub trait EquivalentEnum: Sized {
+    /// The integer type the enum is represented by
+    type Repr: Equivalence + Copy + Default + PartialEq + fmt::Debug;
+    /// The discriminant of `self`
+    fn to_repr(&self) -> Self::Repr;
+    /// The value of the enum with the discriminant `repr`, if there is one
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+}
+
+/// The integer representation of a value of the enum `E`
+///
+/// Unlike `E`, `Checked<E>` implements `Equivalence`, so it can be sent, received and used as the
+/// field of a struct that derives `Equivalence`. Any integer can be received into a `Checked<E>`,
+/// but [`get()`](#method.get) only returns a value of `E` if the integer is one of its
+/// discriminants.
+///
+/// # Examples
+///
+/// See `examples/enum.rs`
+#[repr(transparent)]
+pub struct Checked<E: EquivalentEnum> {
+    repr: E::Repr,
+    phantom: PhantomData<E>,
+}
+
+impl<E: EquivalentEnum> Checked<E> {
+    /// The representation of `value`
+    pub fn new(value: E) -> Self {
+        Self::from_repr(value.to_repr())
+    }
+
+    /// A representation that may or may not be the discriminant of a value of `E`
+    pub fn from_repr(repr: E::Repr) -> Self {
+        Checked {
+            repr,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The value of `E`, or `None` if the representation is not a valid discriminant
+    pub fn get(&self) -> Option<E> {
+        E::from_repr(self.repr)
+    }
+
+    /// The integer representation
+    pub fn repr(&self) -> E::Repr {
+        self.repr
+    }
+}
+
+impl<E: EquivalentEnum> From<E> for Checked<E> {
+    fn from(value: E) -> Self {
+        Checked::new(value)
+    }
+}
+
+impl<E: EquivalentEnum> Clone for Checked<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: EquivalentEnum> Copy for Checked<E> {}
+
+impl<E: EquivalentEnum> Default for Checked<E> {
+    fn default() -> Self {
+        Checked::from_repr(E::Repr::default())
+    }
+}
+
+impl<E: EquivalentEnum> PartialEq for Checked<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<E: EquivalentEnum> fmt::Debug for Checked<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_tuple("Checked").field(&self.repr).finish()
+    }
+}
+
+unsafe impl<E: EquivalentEnum> Equivalence for Checked<E> {
+    type Out = <E::Repr as Equivalence>::Out;
+    fn equivalent_datatype() -> Self::Out {
+        E::Repr::equivalent_datatype()
+    }
+}
+
 /// A user defined MPI datatype
 ///
 /// # Standard section(s)
@@ -382,6 +494,72 @@ impl UserDatatype {
         UncommittedUserDatatype::structured(blocklengths, displacements, types).commit()
     }
 
+    /// Constructs a new datatype describing the subarray of `subsizes` elements starting at
+    /// `starts` of an array of `sizes` elements of `oldtype` that is stored in `order`.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::subarray(sizes, subsizes, starts, order, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the part of an array of `gsizes` elements of `oldtype`
+    /// that process `rank` holds when the array is distributed across a grid of `size` processes.
+    /// Dimension `i` of the array is distributed across `psizes[i]` processes as described by
+    /// `distribs[i]`.
+    ///
+    /// # Examples
+    /// See `examples/darray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.4
+    pub fn darray<D>(
+        size: Rank,
+        rank: Rank,
+        gsizes: &[Count],
+        distribs: &[Distribution],
+        psizes: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::darray(size, rank, gsizes, distribs, psizes, order, oldtype)
+            .commit()
+    }
+
+    /// Constructs a new datatype with the same type map as `oldtype`, but with lower bound `lb`
+    /// and extent `extent` in bytes. This controls where consecutive elements of the new datatype
+    /// start, e.g. when sending several columns of a matrix.
+    ///
+    /// # Examples
+    /// See `examples/datatype_extent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D>(lb: Address, extent: Address, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::resized(lb, extent, oldtype).commit()
+    }
+
     /// Creates a DatatypeRef from this datatype object.
     pub fn as_ref(&self) -> DatatypeRef<'_> {
         unsafe { DatatypeRef::from_raw(self.as_raw()) }
@@ -693,6 +871,136 @@ impl UncommittedUserDatatype {
         }
     }
 
+    /// Constructs a new datatype describing the subarray of `subsizes` elements starting at
+    /// `starts` of an array of `sizes` elements of `oldtype` that is stored in `order`.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            sizes.len(),
+            subsizes.len(),
+            "'sizes', 'subsizes', and 'starts' must be the same length"
+        );
+        assert_eq!(
+            sizes.len(),
+            starts.len(),
+            "'sizes', 'subsizes', and 'starts' must be the same length"
+        );
+
+        unsafe {
+            UncommittedUserDatatype(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_subarray(
+                        sizes.count(),
+                        sizes.as_ptr(),
+                        subsizes.as_ptr(),
+                        starts.as_ptr(),
+                        order.as_raw(),
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Constructs a new datatype describing the part of an array of `gsizes` elements of `oldtype`
+    /// that process `rank` holds when the array is distributed across a grid of `size` processes.
+    /// Dimension `i` of the array is distributed across `psizes[i]` processes as described by
+    /// `distribs[i]`.
+    ///
+    /// # Examples
+    /// See `examples/darray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.4
+    pub fn darray<D>(
+        size: Rank,
+        rank: Rank,
+        gsizes: &[Count],
+        distribs: &[Distribution],
+        psizes: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            gsizes.len(),
+            distribs.len(),
+            "'gsizes', 'distribs', and 'psizes' must be the same length"
+        );
+        assert_eq!(
+            gsizes.len(),
+            psizes.len(),
+            "'gsizes', 'distribs', and 'psizes' must be the same length"
+        );
+
+        let (distribs, dargs): (Vec<c_int>, Vec<c_int>) =
+            distribs.iter().map(|distrib| distrib.as_raw()).unzip();
+
+        unsafe {
+            UncommittedUserDatatype(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_darray(
+                        size,
+                        rank,
+                        gsizes.count(),
+                        gsizes.as_ptr(),
+                        distribs.as_ptr(),
+                        dargs.as_ptr(),
+                        psizes.as_ptr(),
+                        order.as_raw(),
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Constructs a new datatype with the same type map as `oldtype`, but with lower bound `lb`
+    /// and extent `extent` in bytes. This controls where consecutive elements of the new datatype
+    /// start, e.g. when sending several columns of a matrix.
+    ///
+    /// # Examples
+    /// See `examples/datatype_extent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D>(lb: Address, extent: Address, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_resized(oldtype.as_raw(), lb, extent, newtype)
+                })
+                .1,
+            )
+        }
+    }
+
     /// Commits a datatype to a specific representation so that it can be used in MPI calls.
     ///
     /// # Standard section(s)
@@ -753,6 +1061,498 @@ impl<'a> From<&'a UncommittedUserDatatype> for UncommittedDatatypeRef<'a> {
     }
 }
 
+/// The storage order of a multidimensional array
+///
+/// # Standard section(s)
+///
+/// 4.1.3
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// Row-major order, the last dimension varies fastest (`MPI_ORDER_C`).
+    C,
+    /// Column-major order, the first dimension varies fastest (`MPI_ORDER_FORTRAN`).
+    Fortran,
+}
+
+impl Order {
+    fn as_raw(self) -> c_int {
+        match self {
+            Order::C => unsafe { ffi::RSMPI_ORDER_C },
+            Order::Fortran => unsafe { ffi::RSMPI_ORDER_FORTRAN },
+        }
+    }
+
+    fn from_raw(order: c_int) -> Self {
+        if order == unsafe { ffi::RSMPI_ORDER_FORTRAN } {
+            Order::Fortran
+        } else {
+            Order::C
+        }
+    }
+}
+
+/// How one dimension of a distributed array is split across processes
+///
+/// # Standard section(s)
+///
+/// 4.1.4
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Distribution {
+    /// The dimension is not distributed (`MPI_DISTRIBUTE_NONE`).
+    NotDistributed,
+    /// Each process holds one contiguous block of about the same size (`MPI_DISTRIBUTE_BLOCK`).
+    Block,
+    /// Each process holds one contiguous block of the given size (`MPI_DISTRIBUTE_BLOCK`).
+    BlockOf(Count),
+    /// Elements are dealt out to the processes one at a time (`MPI_DISTRIBUTE_CYCLIC`).
+    Cyclic,
+    /// Blocks of the given size are dealt out to the processes in turn
+    /// (`MPI_DISTRIBUTE_CYCLIC`).
+    CyclicOf(Count),
+}
+
+impl Distribution {
+    fn as_raw(self) -> (c_int, c_int) {
+        unsafe {
+            match self {
+                Distribution::NotDistributed => {
+                    (ffi::RSMPI_DISTRIBUTE_NONE, ffi::RSMPI_DISTRIBUTE_DFLT_DARG)
+                }
+                Distribution::Block => {
+                    (ffi::RSMPI_DISTRIBUTE_BLOCK, ffi::RSMPI_DISTRIBUTE_DFLT_DARG)
+                }
+                Distribution::BlockOf(size) => (ffi::RSMPI_DISTRIBUTE_BLOCK, size),
+                Distribution::Cyclic => (
+                    ffi::RSMPI_DISTRIBUTE_CYCLIC,
+                    ffi::RSMPI_DISTRIBUTE_DFLT_DARG,
+                ),
+                Distribution::CyclicOf(size) => (ffi::RSMPI_DISTRIBUTE_CYCLIC, size),
+            }
+        }
+    }
+
+    fn from_raw(distrib: c_int, darg: c_int) -> Self {
+        unsafe {
+            let default = darg == ffi::RSMPI_DISTRIBUTE_DFLT_DARG;
+            if distrib == ffi::RSMPI_DISTRIBUTE_BLOCK {
+                if default {
+                    Distribution::Block
+                } else {
+                    Distribution::BlockOf(darg)
+                }
+            } else if distrib == ffi::RSMPI_DISTRIBUTE_CYCLIC {
+                if default {
+                    Distribution::Cyclic
+                } else {
+                    Distribution::CyclicOf(darg)
+                }
+            } else {
+                Distribution::NotDistributed
+            }
+        }
+    }
+}
+
+/// The structure of a datatype, following the constructor calls that created it
+///
+/// Returned by [`UncommittedDatatype::decode()`](trait.UncommittedDatatype.html#method.decode).
+/// Derived datatypes are decoded recursively down to the predefined datatypes they are built from.
+/// The `Display` implementation renders the tree as nested constructor calls, e.g.
+/// `vector(2, 2, 3, MPI_INT)`.
+///
+/// # Examples
+/// See `examples/decode.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.13
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedDatatype {
+    /// A predefined datatype, e.g. `MPI_INT`
+    Named(SystemDatatype),
+    /// A duplicate of a datatype, see `UncommittedDatatype::dup()`
+    Duplicate(Box<DecodedDatatype>),
+    /// See `UserDatatype::contiguous()`
+    Contiguous {
+        /// Number of repetitions of `oldtype`
+        count: Count,
+        /// The repeated datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::vector()`
+    Vector {
+        /// Number of blocks
+        count: Count,
+        /// Number of elements of `oldtype` in each block
+        blocklength: Count,
+        /// Distance between the starts of consecutive blocks in elements of `oldtype`
+        stride: Count,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::heterogeneous_vector()`
+    HeterogeneousVector {
+        /// Number of blocks
+        count: Count,
+        /// Number of elements of `oldtype` in each block
+        blocklength: Count,
+        /// Distance between the starts of consecutive blocks in bytes
+        stride: Address,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::indexed()`
+    Indexed {
+        /// Number of elements of `oldtype` in each block
+        blocklengths: Vec<Count>,
+        /// Displacement of each block in elements of `oldtype`
+        displacements: Vec<Count>,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::heterogeneous_indexed()`
+    HeterogeneousIndexed {
+        /// Number of elements of `oldtype` in each block
+        blocklengths: Vec<Count>,
+        /// Displacement of each block in bytes
+        displacements: Vec<Address>,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::indexed_block()`
+    IndexedBlock {
+        /// Number of elements of `oldtype` in every block
+        blocklength: Count,
+        /// Displacement of each block in elements of `oldtype`
+        displacements: Vec<Count>,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::heterogeneous_indexed_block()`
+    HeterogeneousIndexedBlock {
+        /// Number of elements of `oldtype` in every block
+        blocklength: Count,
+        /// Displacement of each block in bytes
+        displacements: Vec<Address>,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::structured()`
+    Structured {
+        /// Number of elements in each block
+        blocklengths: Vec<Count>,
+        /// Displacement of each block in bytes
+        displacements: Vec<Address>,
+        /// The element datatype of each block
+        types: Vec<DecodedDatatype>,
+    },
+    /// See `UserDatatype::subarray()`
+    Subarray {
+        /// Number of elements of `oldtype` in each dimension of the full array
+        sizes: Vec<Count>,
+        /// Number of elements of `oldtype` in each dimension of the subarray
+        subsizes: Vec<Count>,
+        /// Start of the subarray in each dimension
+        starts: Vec<Count>,
+        /// The storage order of the array
+        order: Order,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::darray()`
+    Darray {
+        /// Number of processes in the process grid
+        size: Rank,
+        /// Rank of the process whose part of the array is described
+        rank: Rank,
+        /// Number of elements of `oldtype` in each dimension of the global array
+        gsizes: Vec<Count>,
+        /// Distribution of each dimension
+        distribs: Vec<Distribution>,
+        /// Number of processes in each dimension of the process grid
+        psizes: Vec<Count>,
+        /// The storage order of the array
+        order: Order,
+        /// The element datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// See `UserDatatype::resized()`
+    Resized {
+        /// The lower bound in bytes
+        lb: Address,
+        /// The extent in bytes
+        extent: Address,
+        /// The resized datatype
+        oldtype: Box<DecodedDatatype>,
+    },
+    /// A datatype created by a constructor without a corresponding variant, e.g.
+    /// `MPI_Type_create_f90_real()`
+    Other {
+        /// The raw `MPI_COMBINER_*` value reported by MPI
+        combiner: c_int,
+    },
+}
+
+impl DecodedDatatype {
+    /// Decodes `datatype` and all datatypes it is built from.
+    ///
+    /// # Safety
+    /// `datatype` must be a live datatype handle.
+    unsafe fn decode(datatype: MPI_Datatype) -> Self {
+        let mut num_integers: c_int = 0;
+        let mut num_addresses: c_int = 0;
+        let mut num_datatypes: c_int = 0;
+        let mut combiner: c_int = 0;
+        ffi::MPI_Type_get_envelope(
+            datatype,
+            &mut num_integers,
+            &mut num_addresses,
+            &mut num_datatypes,
+            &mut combiner,
+        );
+        if combiner == ffi::RSMPI_COMBINER_NAMED {
+            return DecodedDatatype::Named(DatatypeRef::from_raw(datatype));
+        }
+
+        let len = |num: c_int| -> usize {
+            num.value_as()
+                .expect("Received unexpected value from MPI_Type_get_envelope")
+        };
+        let mut integers: Vec<c_int> = vec![0; len(num_integers)];
+        let mut addresses: Vec<Address> = vec![0; len(num_addresses)];
+        let mut datatypes = vec![ffi::RSMPI_DATATYPE_NULL; len(num_datatypes)];
+        ffi::MPI_Type_get_contents(
+            datatype,
+            num_integers,
+            num_addresses,
+            num_datatypes,
+            integers.as_mut_ptr(),
+            addresses.as_mut_ptr(),
+            datatypes.as_mut_ptr(),
+        );
+
+        // MPI returns new handles for the datatypes that are not predefined.
+        let mut types = datatypes
+            .into_iter()
+            .map(|mut datatype| {
+                let decoded = DecodedDatatype::decode(datatype);
+                if !matches!(decoded, DecodedDatatype::Named(_)) {
+                    ffi::MPI_Type_free(&mut datatype);
+                }
+                decoded
+            })
+            .collect::<Vec<_>>();
+
+        if combiner == ffi::RSMPI_COMBINER_STRUCT {
+            let n = len(integers[0]);
+            return DecodedDatatype::Structured {
+                blocklengths: integers[1..=n].to_vec(),
+                displacements: addresses,
+                types,
+            };
+        }
+
+        let oldtype = match types.pop() {
+            Some(oldtype) => Box::new(oldtype),
+            None => return DecodedDatatype::Other { combiner },
+        };
+
+        if combiner == ffi::RSMPI_COMBINER_DUP {
+            DecodedDatatype::Duplicate(oldtype)
+        } else if combiner == ffi::RSMPI_COMBINER_CONTIGUOUS {
+            DecodedDatatype::Contiguous {
+                count: integers[0],
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_VECTOR {
+            DecodedDatatype::Vector {
+                count: integers[0],
+                blocklength: integers[1],
+                stride: integers[2],
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_HVECTOR {
+            DecodedDatatype::HeterogeneousVector {
+                count: integers[0],
+                blocklength: integers[1],
+                stride: addresses[0],
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_INDEXED {
+            let n = len(integers[0]);
+            DecodedDatatype::Indexed {
+                blocklengths: integers[1..=n].to_vec(),
+                displacements: integers[n + 1..=2 * n].to_vec(),
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_HINDEXED {
+            let n = len(integers[0]);
+            DecodedDatatype::HeterogeneousIndexed {
+                blocklengths: integers[1..=n].to_vec(),
+                displacements: addresses,
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_INDEXED_BLOCK {
+            let n = len(integers[0]);
+            DecodedDatatype::IndexedBlock {
+                blocklength: integers[1],
+                displacements: integers[2..2 + n].to_vec(),
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_HINDEXED_BLOCK {
+            DecodedDatatype::HeterogeneousIndexedBlock {
+                blocklength: integers[1],
+                displacements: addresses,
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_SUBARRAY {
+            let n = len(integers[0]);
+            DecodedDatatype::Subarray {
+                sizes: integers[1..=n].to_vec(),
+                subsizes: integers[n + 1..=2 * n].to_vec(),
+                starts: integers[2 * n + 1..=3 * n].to_vec(),
+                order: Order::from_raw(integers[3 * n + 1]),
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_DARRAY {
+            let n = len(integers[2]);
+            DecodedDatatype::Darray {
+                size: integers[0],
+                rank: integers[1],
+                gsizes: integers[3..3 + n].to_vec(),
+                distribs: integers[3 + n..3 + 2 * n]
+                    .iter()
+                    .zip(&integers[3 + 2 * n..3 + 3 * n])
+                    .map(|(&distrib, &darg)| Distribution::from_raw(distrib, darg))
+                    .collect(),
+                psizes: integers[3 + 3 * n..3 + 4 * n].to_vec(),
+                order: Order::from_raw(integers[3 + 4 * n]),
+                oldtype,
+            }
+        } else if combiner == ffi::RSMPI_COMBINER_RESIZED {
+            DecodedDatatype::Resized {
+                lb: addresses[0],
+                extent: addresses[1],
+                oldtype,
+            }
+        } else {
+            DecodedDatatype::Other { combiner }
+        }
+    }
+}
+
+impl fmt::Display for DecodedDatatype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodedDatatype::Named(datatype) => write!(f, "{}", datatype.get_name()),
+            DecodedDatatype::Duplicate(oldtype) => write!(f, "dup({})", oldtype),
+            DecodedDatatype::Contiguous { count, oldtype } => {
+                write!(f, "contiguous({}, {})", count, oldtype)
+            }
+            DecodedDatatype::Vector {
+                count,
+                blocklength,
+                stride,
+                oldtype,
+            } => write!(
+                f,
+                "vector({}, {}, {}, {})",
+                count, blocklength, stride, oldtype
+            ),
+            DecodedDatatype::HeterogeneousVector {
+                count,
+                blocklength,
+                stride,
+                oldtype,
+            } => write!(
+                f,
+                "heterogeneous_vector({}, {}, {}, {})",
+                count, blocklength, stride, oldtype
+            ),
+            DecodedDatatype::Indexed {
+                blocklengths,
+                displacements,
+                oldtype,
+            } => write!(
+                f,
+                "indexed({:?}, {:?}, {})",
+                blocklengths, displacements, oldtype
+            ),
+            DecodedDatatype::HeterogeneousIndexed {
+                blocklengths,
+                displacements,
+                oldtype,
+            } => write!(
+                f,
+                "heterogeneous_indexed({:?}, {:?}, {})",
+                blocklengths, displacements, oldtype
+            ),
+            DecodedDatatype::IndexedBlock {
+                blocklength,
+                displacements,
+                oldtype,
+            } => write!(
+                f,
+                "indexed_block({}, {:?}, {})",
+                blocklength, displacements, oldtype
+            ),
+            DecodedDatatype::HeterogeneousIndexedBlock {
+                blocklength,
+                displacements,
+                oldtype,
+            } => write!(
+                f,
+                "heterogeneous_indexed_block({}, {:?}, {})",
+                blocklength, displacements, oldtype
+            ),
+            DecodedDatatype::Structured {
+                blocklengths,
+                displacements,
+                types,
+            } => {
+                write!(f, "structured({:?}, {:?}, [", blocklengths, displacements)?;
+                for (i, datatype) in types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", datatype)?;
+                }
+                write!(f, "])")
+            }
+            DecodedDatatype::Subarray {
+                sizes,
+                subsizes,
+                starts,
+                order,
+                oldtype,
+            } => write!(
+                f,
+                "subarray({:?}, {:?}, {:?}, {:?}, {})",
+                sizes, subsizes, starts, order, oldtype
+            ),
+            DecodedDatatype::Darray {
+                size,
+                rank,
+                gsizes,
+                distribs,
+                psizes,
+                order,
+                oldtype,
+            } => write!(
+                f,
+                "darray({}, {}, {:?}, {:?}, {:?}, {:?}, {})",
+                size, rank, gsizes, distribs, psizes, order, oldtype
+            ),
+            DecodedDatatype::Resized {
+                lb,
+                extent,
+                oldtype,
+            } => write!(f, "resized({}, {}, {})", lb, extent, oldtype),
+            DecodedDatatype::Other { combiner } => write!(f, "<combiner {}>", combiner),
+        }
+    }
+}
+
 /// A Datatype describes the layout of messages in memory.
 ///
 /// `Datatype` always represents a committed datatype that can be immediately used for sending and
@@ -779,6 +1579,87 @@ pub trait UncommittedDatatype: AsRaw<Raw = MPI_Datatype> {
             )
         }
     }
+
+    /// The total size in bytes of the data described by one element of the datatype, excluding
+    /// any gaps.
+    ///
+    /// # Examples
+    /// See `examples/datatype_extent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.5
+    fn size(&self) -> Count {
+        unsafe { with_uninitialized(|size| ffi::MPI_Type_size(self.as_raw(), size)).1 }
+    }
+
+    /// The lower bound and the extent of the datatype in bytes, i.e. the offset of the first byte
+    /// and the distance between the starts of consecutive elements of the datatype in a buffer.
+    ///
+    /// # Examples
+    /// See `examples/datatype_extent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    fn extent(&self) -> (Address, Address) {
+        let (_, lb, extent) = unsafe {
+            with_uninitialized2(|lb, extent| ffi::MPI_Type_get_extent(self.as_raw(), lb, extent))
+        };
+        (lb, extent)
+    }
+
+    /// The true lower bound and the true extent of the datatype in bytes, i.e. the offset of the
+    /// first byte and the size of the smallest memory region that contains all data described by
+    /// the datatype, ignoring any resizing.
+    ///
+    /// # Examples
+    /// See `examples/datatype_extent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.8
+    fn true_extent(&self) -> (Address, Address) {
+        let (_, true_lb, true_extent) = unsafe {
+            with_uninitialized2(|true_lb, true_extent| {
+                ffi::MPI_Type_get_true_extent(self.as_raw(), true_lb, true_extent)
+            })
+        };
+        (true_lb, true_extent)
+    }
+
+    /// The name of the datatype, e.g. `MPI_INT` for predefined datatypes. Derived datatypes have
+    /// an empty name.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.8, see the `MPI_Type_get_name` function
+    fn get_name(&self) -> String {
+        type BufType = [c_char; ffi::MPI_MAX_OBJECT_NAME as usize];
+
+        unsafe {
+            let mut buf = MaybeUninit::<BufType>::uninit();
+
+            let (_, _resultlen) = with_uninitialized(|resultlen| {
+                ffi::MPI_Type_get_name(self.as_raw(), &mut (*buf.as_mut_ptr())[0], resultlen)
+            });
+
+            let buf_cstr = CStr::from_ptr(buf.assume_init().as_ptr());
+            buf_cstr.to_string_lossy().into_owned()
+        }
+    }
+
+    /// Decodes the datatype into a tree describing the constructor calls that created it.
+    ///
+    /// # Examples
+    /// See `examples/decode.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.13
+    fn decode(&self) -> DecodedDatatype {
+        unsafe { DecodedDatatype::decode(self.as_raw()) }
+    }
 }
 impl<'a, D> UncommittedDatatype for &'a D
 where
@@ -837,6 +1718,66 @@ where
 
 #[doc(hidden)]
 pub mod internal {
+    #[cfg(feature = "derive")]
+    use std::{any::TypeId, collections::HashMap, sync::RwLock};
+
+    #[cfg(feature = "derive")]
+    use once_cell::sync::Lazy;
+
+    #[cfg(feature = "derive")]
+    use super::{DatatypeRef, UncommittedDatatype, UncommittedDatatypeRef, UserDatatype};
+    #[cfg(feature = "derive")]
+    use crate::raw::traits::*;
+
+    /// The datatype of the instantiation `T` of a generic type that derives `Equivalence`
+    ///
+    /// The datatypes are built by `build` on first use and cached by `TypeId`, since a `static` in
+    /// a generic function is shared by all instantiations.
+    #[cfg(feature = "derive")]
+    pub fn generic_equivalent_datatype<T: 'static>(
+        build: impl FnOnce() -> UserDatatype,
+    ) -> DatatypeRef<'static> {
+        static DATATYPES: Lazy<RwLock<HashMap<TypeId, UserDatatype>>> = Lazy::new(Default::default);
+
+        let type_id = TypeId::of::<T>();
+        if let Some(datatype) = DATATYPES.read().unwrap().get(&type_id) {
+            return unsafe { DatatypeRef::from_raw(datatype.as_raw()) };
+        }
+
+        // Fields may be generic types themselves, so the lock is not held while building. If
+        // another thread has been faster, the new datatype is freed again.
+        let datatype = build();
+        let mut datatypes = DATATYPES.write().unwrap();
+        let datatype = datatypes.entry(type_id).or_insert(datatype);
+        unsafe { DatatypeRef::from_raw(datatype.as_raw()) }
+    }
+
+    /// Checks that the datatype given by `#[mpi(unsafe_datatype = ...)]` for `field` only covers
+    /// the `size` bytes of the field.
+    #[cfg(feature = "derive")]
+    pub fn check_field_datatype<'a>(
+        datatype: UncommittedDatatypeRef<'a>,
+        size: usize,
+        field: &str,
+    ) -> UncommittedDatatypeRef<'a> {
+        let (true_lb, true_extent) = datatype.true_extent();
+        let fits = true_lb >= 0
+            && true_lb
+                .checked_add(true_extent)
+                .and_then(|end| usize::try_from(end).ok())
+                .is_some_and(|end| end <= size);
+        assert!(
+            fits,
+            "rsmpi derive: The datatype of `{}` covers bytes {} to {}, which do not lie within the \
+             {} bytes of the field.",
+            field,
+            true_lb,
+            true_lb.saturating_add(true_extent),
+            size
+        );
+        datatype
+    }
+
     #[cfg(feature = "derive")]
     pub fn check_derive_equivalence_universe_state(type_name: &str) {
         use crate::environment::UNIVERSE_STATE;
@@ -844,14 +1785,22 @@ pub mod internal {
         // Take lock on UNIVERSE_STATE to prevent racing with mpi::init and mpi::finalize.
         let universe_state = UNIVERSE_STATE.read().unwrap();
 
+        // Without the world model, a live session allows building datatypes.
+        #[cfg(mpi4)]
+        if (!crate::environment::is_initialized() || crate::environment::is_finalized())
+            && crate::session::retain_for_derived_datatypes()
+        {
+            return;
+        }
+
         if !crate::environment::is_initialized() {
             panic!(
                 "\n\
                  RSMPI PANIC: Pre-MPI_Init datatype initialization\n\
                  \n\
                  Application attempted to initialize datatype of #[derive(Equivalence)] for \
-                 `{}` before initializing rsmpi. You must first initialize rsmpi before attempting \
-                 to use a custom type in an MPI call.\n",
+                 `{}` before initializing rsmpi. You must first initialize rsmpi, or with MPI-4 \
+                 a `Session`, before attempting to use a custom type in an MPI call.\n",
                 type_name
             );
         }
@@ -894,6 +1843,12 @@ pub mod internal {
 pub unsafe trait Collection {
     /// How many things are in this collection.
     fn count(&self) -> Count;
+
+    /// How many things are in this collection, also for collections that exceed the range of
+    /// `Count`.
+    fn large_count(&self) -> LargeCount {
+        self.count().into()
+    }
 }
 
 unsafe impl<T> Collection for T
@@ -914,6 +1869,12 @@ where
             .value_as()
             .expect("Length of slice cannot be expressed as an MPI Count.")
     }
+
+    fn large_count(&self) -> LargeCount {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
 }
 
 unsafe impl<T> Collection for Vec<T>
@@ -925,6 +1886,12 @@ where
             .value_as()
             .expect("Length of slice cannot be expressed as an MPI Count.")
     }
+
+    fn large_count(&self) -> LargeCount {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
 }
 
 unsafe impl<T, const D: usize> Collection for [T; D]
@@ -936,6 +1903,140 @@ where
         D.value_as()
             .expect("Length of slice cannot be expressed as an MPI Count.")
     }
+
+    fn large_count(&self) -> LargeCount {
+        D.value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+/// The type of the count passed along with a `CountedBuffer`
+#[cfg(mpi4)]
+pub(crate) type RawCount = LargeCount;
+/// The type of the count passed along with a `CountedBuffer`
+#[cfg(not(mpi4))]
+pub(crate) type RawCount = Count;
+
+/// A buffer lowered to a count and a datatype that fit the arguments of the MPI functions
+///
+/// With MPI-4, the count is passed to the large-count `_c` variants of the functions. Older
+/// libraries receive buffers that exceed the range of `Count` as a single element of a derived
+/// datatype, which lives as long as the `CountedBuffer`.
+pub(crate) struct CountedBuffer<D> {
+    pub(crate) count: RawCount,
+    datatype: D,
+    #[cfg(not(mpi4))]
+    large: Option<UserDatatype>,
+}
+
+impl<D: Datatype> CountedBuffer<D> {
+    /// Lowers `buf` to a count and a datatype.
+    pub(crate) fn new<B>(buf: &B) -> Self
+    where
+        B: ?Sized + Collection + AsDatatype<Out = D>,
+    {
+        Self::with_count(buf.large_count(), buf.as_datatype())
+    }
+
+    /// Lowers `count` elements of `datatype` to a count and a datatype.
+    #[cfg(mpi4)]
+    pub(crate) fn with_count(count: LargeCount, datatype: D) -> Self {
+        CountedBuffer { count, datatype }
+    }
+
+    /// Lowers `count` elements of `datatype` to a count and a datatype.
+    #[cfg(not(mpi4))]
+    pub(crate) fn with_count(count: LargeCount, datatype: D) -> Self {
+        match count.value_as() {
+            Ok(count) => CountedBuffer {
+                count,
+                datatype,
+                large: None,
+            },
+            Err(_) => CountedBuffer {
+                count: 1,
+                large: Some(large_datatype(count, &datatype)),
+                datatype,
+            },
+        }
+    }
+
+    /// The datatype to pass along with `count`
+    pub(crate) fn datatype(&self) -> MPI_Datatype {
+        #[cfg(not(mpi4))]
+        if let Some(ref large) = self.large {
+            return large.as_raw();
+        }
+        self.datatype.as_raw()
+    }
+}
+
+/// A datatype that describes `count` consecutive elements of `datatype` as a single element,
+/// built from chunks that fit the range of `Count`
+#[cfg(not(mpi4))]
+fn large_datatype<D: Datatype>(count: LargeCount, datatype: &D) -> UserDatatype {
+    const CHUNK: Count = 1 << 30;
+    let chunks: Count = (count / LargeCount::from(CHUNK))
+        .value_as()
+        .expect("Length of buffer cannot be expressed as an MPI datatype.");
+    let rest: Count = (count % LargeCount::from(CHUNK))
+        .value_as()
+        .expect("rsmpi internal error: remainder exceeds the chunk size");
+
+    let chunk = UserDatatype::contiguous(CHUNK, datatype);
+    let all_chunks = UserDatatype::contiguous(chunks, &chunk);
+    let remainder = UserDatatype::contiguous(rest, datatype);
+
+    let (_, extent) = chunk.extent();
+    let displacement = chunks
+        .value_as::<Address>()
+        .ok()
+        .and_then(|chunks| chunks.checked_mul(extent))
+        .expect("Size of buffer cannot be expressed as an MPI Address.");
+    UserDatatype::structured(
+        &[1, 1],
+        &[0, displacement],
+        &[all_chunks.as_ref(), remainder.as_ref()],
+    )
+}
+
+/// The type of the displacements passed along with the counts of a `RawPartition`
+#[cfg(mpi4)]
+pub(crate) type RawDispl = Address;
+/// The type of the displacements passed along with the counts of a `RawPartition`
+#[cfg(not(mpi4))]
+pub(crate) type RawDispl = Count;
+
+/// The counts and displacements of a `Partitioned` buffer converted to the arguments of the MPI
+/// functions
+///
+/// With MPI-4, they are passed to the large-count `_c` variants of the functions. Older libraries
+/// only accept partitions that lie within the range of `Count`.
+pub(crate) struct RawPartition {
+    pub(crate) counts: Vec<RawCount>,
+    pub(crate) displs: Vec<RawDispl>,
+}
+
+impl RawPartition {
+    /// Converts the partitioning of `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a count or displacement exceeds the range of `Count` and the MPI library does not
+    /// implement MPI-4.
+    pub(crate) fn new<P: ?Sized + Partitioned>(buf: &P) -> Self {
+        RawPartition {
+            counts: buf.counts().iter().map(|&c| raw_value(c.into())).collect(),
+            displs: buf.displs().iter().map(|&d| raw_value(d.into())).collect(),
+        }
+    }
+}
+
+/// Converts `value` to the type of a count or displacement of the MPI functions
+fn raw_value<T: TryFrom<LargeCount>>(value: LargeCount) -> T {
+    T::try_from(value).unwrap_or_else(|_| {
+        panic!("Partitions beyond the range of `Count` require an MPI-4 library.")
+    })
 }
 
 /// Provides a pointer to the starting address in memory.
@@ -1040,6 +2141,16 @@ unsafe impl<T> BufferMut for [T] where T: Equivalence {}
 unsafe impl<T> BufferMut for Vec<T> where T: Equivalence {}
 unsafe impl<T, const D: usize> BufferMut for [T; D] where T: Equivalence {}
 
+/// A buffer whose contents can be overwritten in place without changing the region in memory
+/// that it describes.
+///
+/// Persistent requests hand out mutable access to buffers of this kind between starts, which would
+/// be unsound for buffers such as `Vec<T>` that can reallocate their storage.
+pub unsafe trait FixedBuffer {}
+unsafe impl<T> FixedBuffer for T where T: Equivalence {}
+unsafe impl<T> FixedBuffer for [T] where T: Equivalence {}
+unsafe impl<T, const D: usize> FixedBuffer for [T; D] where T: Equivalence {}
+
 /// An immutable dynamically-typed buffer.
 ///
 /// The buffer has a definite length and MPI datatype, but it is not yet known which Rust type it
@@ -1427,10 +2538,13 @@ where
 /// Describes how a `Buffer` is partitioned by specifying the count of elements and displacement
 /// from the start of the buffer for each partition.
 pub trait Partitioned {
+    /// The type of the counts and displacements, `Count` or `LargeCount` for partitions beyond its
+    /// range
+    type Count: Copy + Into<LargeCount>;
     /// The count of elements in each partition.
-    fn counts(&self) -> &[Count];
+    fn counts(&self) -> &[Self::Count];
     /// The displacement from the start of the buffer for each partition.
-    fn displs(&self) -> &[Count];
+    fn displs(&self) -> &[Self::Count];
 }
 
 /// A buffer that is `Partitioned`
@@ -1440,36 +2554,45 @@ pub trait PartitionedBuffer: Partitioned + Pointer + AsDatatype {}
 pub trait PartitionedBufferMut: Partitioned + PointerMut + AsDatatype {}
 
 /// Adds a partitioning to an existing `Buffer` so that it becomes `Partitioned`
-pub struct Partition<'b, B: 'b + ?Sized, C, D> {
+///
+/// The counts and displacements are of type `N`, which is `Count` unless the partitions exceed
+/// its range and are given as `LargeCount`.
+pub struct Partition<'b, B: 'b + ?Sized, C, D, N = Count> {
     buf: &'b B,
     counts: C,
     displs: D,
+    phantom: PhantomData<N>,
 }
 
-impl<'b, B: ?Sized, C, D> Partition<'b, B, C, D>
+impl<'b, B: ?Sized, C, D, N> Partition<'b, B, C, D, N>
 where
     B: 'b + Buffer,
-    C: Borrow<[Count]>,
-    D: Borrow<[Count]>,
+    C: Borrow<[N]>,
+    D: Borrow<[N]>,
+    N: Copy + Into<LargeCount>,
 {
     /// Partition `buf` using `counts` and `displs`
-    pub fn new(buf: &B, counts: C, displs: D) -> Partition<B, C, D> {
-        let n = buf.count();
+    pub fn new(buf: &B, counts: C, displs: D) -> Partition<'_, B, C, D, N> {
+        let n = buf.large_count();
         assert!(counts
             .borrow()
             .iter()
             .zip(displs.borrow().iter())
-            .all(|(&c, &d)| c + d <= n));
+            .all(|(&c, &d)| {
+                let (c, d): (LargeCount, LargeCount) = (c.into(), d.into());
+                c + d <= n
+            }));
 
         Partition {
             buf,
             counts,
             displs,
+            phantom: PhantomData,
         }
     }
 }
 
-unsafe impl<'b, B: ?Sized, C, D> AsDatatype for Partition<'b, B, C, D>
+unsafe impl<'b, B: ?Sized, C, D, N> AsDatatype for Partition<'b, B, C, D, N>
 where
     B: 'b + AsDatatype,
 {
@@ -1479,7 +2602,7 @@ where
     }
 }
 
-unsafe impl<'b, B: ?Sized, C, D> Pointer for Partition<'b, B, C, D>
+unsafe impl<'b, B: ?Sized, C, D, N> Pointer for Partition<'b, B, C, D, N>
 where
     B: 'b + Pointer,
 {
@@ -1488,59 +2611,71 @@ where
     }
 }
 
-impl<'b, B: ?Sized, C, D> Partitioned for Partition<'b, B, C, D>
+impl<'b, B: ?Sized, C, D, N> Partitioned for Partition<'b, B, C, D, N>
 where
     B: 'b,
-    C: Borrow<[Count]>,
-    D: Borrow<[Count]>,
+    C: Borrow<[N]>,
+    D: Borrow<[N]>,
+    N: Copy + Into<LargeCount>,
 {
-    fn counts(&self) -> &[Count] {
+    type Count = N;
+    fn counts(&self) -> &[N] {
         self.counts.borrow()
     }
-    fn displs(&self) -> &[Count] {
+    fn displs(&self) -> &[N] {
         self.displs.borrow()
     }
 }
 
-impl<'b, B: ?Sized, C, D> PartitionedBuffer for Partition<'b, B, C, D>
+impl<'b, B: ?Sized, C, D, N> PartitionedBuffer for Partition<'b, B, C, D, N>
 where
     B: 'b + Pointer + AsDatatype,
-    C: Borrow<[Count]>,
-    D: Borrow<[Count]>,
+    C: Borrow<[N]>,
+    D: Borrow<[N]>,
+    N: Copy + Into<LargeCount>,
 {
 }
 
 /// Adds a partitioning to an existing `BufferMut` so that it becomes `Partitioned`
-pub struct PartitionMut<'b, B: 'b + ?Sized, C, D> {
+///
+/// The counts and displacements are of type `N`, which is `Count` unless the partitions exceed
+/// its range and are given as `LargeCount`.
+pub struct PartitionMut<'b, B: 'b + ?Sized, C, D, N = Count> {
     buf: &'b mut B,
     counts: C,
     displs: D,
+    phantom: PhantomData<N>,
 }
 
-impl<'b, B: ?Sized, C, D> PartitionMut<'b, B, C, D>
+impl<'b, B: ?Sized, C, D, N> PartitionMut<'b, B, C, D, N>
 where
     B: 'b + BufferMut,
-    C: Borrow<[Count]>,
-    D: Borrow<[Count]>,
+    C: Borrow<[N]>,
+    D: Borrow<[N]>,
+    N: Copy + Into<LargeCount>,
 {
     /// Partition `buf` using `counts` and `displs`
-    pub fn new(buf: &mut B, counts: C, displs: D) -> PartitionMut<B, C, D> {
-        let n = buf.count();
+    pub fn new(buf: &mut B, counts: C, displs: D) -> PartitionMut<'_, B, C, D, N> {
+        let n = buf.large_count();
         assert!(counts
             .borrow()
             .iter()
             .zip(displs.borrow().iter())
-            .all(|(&c, &d)| c + d <= n));
+            .all(|(&c, &d)| {
+                let (c, d): (LargeCount, LargeCount) = (c.into(), d.into());
+                c + d <= n
+            }));
 
         PartitionMut {
             buf,
             counts,
             displs,
+            phantom: PhantomData,
         }
     }
 }
 
-unsafe impl<'b, B: ?Sized, C, D> AsDatatype for PartitionMut<'b, B, C, D>
+unsafe impl<'b, B: ?Sized, C, D, N> AsDatatype for PartitionMut<'b, B, C, D, N>
 where
     B: 'b + AsDatatype,
 {
@@ -1550,7 +2685,7 @@ where
     }
 }
 
-unsafe impl<'b, B: ?Sized, C, D> PointerMut for PartitionMut<'b, B, C, D>
+unsafe impl<'b, B: ?Sized, C, D, N> PointerMut for PartitionMut<'b, B, C, D, N>
 where
     B: 'b + PointerMut,
 {
@@ -1559,25 +2694,28 @@ where
     }
 }
 
-impl<'b, B: ?Sized, C, D> Partitioned for PartitionMut<'b, B, C, D>
+impl<'b, B: ?Sized, C, D, N> Partitioned for PartitionMut<'b, B, C, D, N>
 where
     B: 'b,
-    C: Borrow<[Count]>,
-    D: Borrow<[Count]>,
+    C: Borrow<[N]>,
+    D: Borrow<[N]>,
+    N: Copy + Into<LargeCount>,
 {
-    fn counts(&self) -> &[Count] {
+    type Count = N;
+    fn counts(&self) -> &[N] {
         self.counts.borrow()
     }
-    fn displs(&self) -> &[Count] {
+    fn displs(&self) -> &[N] {
         self.displs.borrow()
     }
 }
 
-impl<'b, B: ?Sized, C, D> PartitionedBufferMut for PartitionMut<'b, B, C, D>
+impl<'b, B: ?Sized, C, D, N> PartitionedBufferMut for PartitionMut<'b, B, C, D, N>
 where
     B: 'b + PointerMut + AsDatatype,
-    C: Borrow<[Count]>,
-    D: Borrow<[Count]>,
+    C: Borrow<[N]>,
+    D: Borrow<[N]>,
+    N: Copy + Into<LargeCount>,
 {
 }
 
@@ -1593,3 +2731,135 @@ pub fn address_of<T>(x: &T) -> Address {
     let x: *const T = x;
     unsafe { with_uninitialized(|address| ffi::MPI_Get_address(x as *const c_void, address)).1 }
 }
+
+/// The name of the canonical data representation used by the `*_external` functions
+const EXTERNAL32: &[u8] = b"external32\0";
+
+/// Gets the buffer size required to pack `incount` elements of type `datatype` in the canonical
+/// `external32` data representation.
+///
+/// # Standard section(s)
+///
+/// 4.3, see the `MPI_Pack_external_size` function
+pub fn pack_external_size<D>(incount: Count, datatype: &D) -> Address
+where
+    D: Datatype,
+{
+    unsafe {
+        with_uninitialized(|size| {
+            ffi::MPI_Pack_external_size(
+                EXTERNAL32.as_ptr() as *const c_char,
+                incount,
+                datatype.as_raw(),
+                size,
+            )
+        })
+        .1
+    }
+}
+
+/// Packs `inbuf` into a byte array in the canonical `external32` data representation, which is
+/// the same on every platform and MPI implementation. Often paired with `unpack_external_into` to
+/// convert back into a specific datatype.
+///
+/// # Examples
+/// See `examples/pack_external.rs`
+///
+/// # Standard section(s)
+///
+/// 4.3, see the `MPI_Pack_external` function
+pub fn pack_external<Buf>(inbuf: &Buf) -> Vec<u8>
+where
+    Buf: ?Sized + Buffer,
+{
+    let mut outbuf = vec![
+        0;
+        pack_external_size(inbuf.count(), &inbuf.as_datatype())
+            .value_as::<usize>()
+            .expect("MPI_Pack_external_size returned a negative buffer size!")
+    ];
+
+    let position = pack_external_into(inbuf, &mut outbuf[..], 0);
+
+    outbuf.resize(
+        position
+            .value_as()
+            .expect("MPI_Pack_external returned a negative position!"),
+        0,
+    );
+
+    outbuf
+}
+
+/// Packs `inbuf` into `outbuf` at byte offset `position` in the canonical `external32` data
+/// representation. Returns the position after the packed data.
+///
+/// # Examples
+/// See `examples/pack_external.rs`
+///
+/// # Standard section(s)
+///
+/// 4.3, see the `MPI_Pack_external` function
+pub fn pack_external_into<Buf>(inbuf: &Buf, outbuf: &mut [u8], position: Address) -> Address
+where
+    Buf: ?Sized + Buffer,
+{
+    let inbuf_dt = inbuf.as_datatype();
+
+    let mut position = position;
+    unsafe {
+        ffi::MPI_Pack_external(
+            EXTERNAL32.as_ptr() as *const c_char,
+            inbuf.pointer(),
+            inbuf.count(),
+            inbuf_dt.as_raw(),
+            outbuf.as_mut_ptr() as *mut _,
+            outbuf
+                .len()
+                .value_as()
+                .expect("Length of outbuf cannot be expressed as an MPI Address."),
+            &mut position,
+        );
+    }
+    position
+}
+
+/// Unpacks data in the canonical `external32` data representation from `pack_external` or
+/// `pack_external_into` at byte offset `position` into a buffer of a specific datatype. Returns
+/// the position after the unpacked data.
+///
+/// # Examples
+/// See `examples/pack_external.rs`
+///
+/// # Standard section(s)
+///
+/// 4.3, see the `MPI_Unpack_external` function
+///
+/// # Safety
+///
+/// `inbuf` must contain values that are valid for the datatype of `outbuf`.
+pub unsafe fn unpack_external_into<Buf>(
+    inbuf: &[u8],
+    outbuf: &mut Buf,
+    position: Address,
+) -> Address
+where
+    Buf: ?Sized + BufferMut,
+{
+    let outbuf_dt = outbuf.as_datatype();
+
+    let mut position = position;
+    ffi::MPI_Unpack_external(
+        EXTERNAL32.as_ptr() as *const c_char,
+        inbuf.as_ptr() as *const _,
+        inbuf
+            .len()
+            .value_as()
+            .expect("Length of inbuf cannot be expressed as an MPI Address."),
+        &mut position,
+        outbuf.pointer_mut(),
+        outbuf.count(),
+        outbuf_dt.as_raw(),
+    );
+    position
+}
@@ -6,10 +6,12 @@
 //!
 //! - **8.1.2**: `MPI_TAG_UB`, ...
 //! - **8.2**: Memory allocation
-//! - **8.3, 8.4, and 8.5**: Error handling
 
+#[cfg(feature = "user-operations")]
+use std::mem::{self, ManuallyDrop};
 use std::{
     cmp::Ordering,
+    ffi::CString,
     os::raw::{c_char, c_double, c_int, c_void},
     ptr,
     string::FromUtf8Error,
@@ -18,14 +20,23 @@ use std::{
 };
 
 use conv::ConvUtil;
+#[cfg(feature = "user-operations")]
+use libffi::middle::{Cif, Closure, Type};
 use once_cell::sync::Lazy;
 
 use crate::{
     attribute::{AppNum, UniverseSize},
     ffi,
+    ffi::MPI_Errhandler,
     topology::{traits::AnyCommunicator, Communicator, InterCommunicator, SimpleCommunicator},
     traits::{AsRaw, FromRaw},
-    with_uninitialized, with_uninitialized2,
+    with_uninitialized, with_uninitialized2, Error, MpiError,
+};
+#[cfg(feature = "user-operations")]
+use crate::{
+    ffi::{MPI_Comm, MPI_File},
+    io::File,
+    topology::sealed::CommunicatorHandle,
 };
 
 /// Internal data structure used to uphold certain MPI invariants.
@@ -202,7 +213,7 @@ pub enum Threading {
 
 impl Threading {
     /// The raw value understood by the MPI C API
-    fn as_raw(self) -> c_int {
+    pub(crate) fn as_raw(self) -> c_int {
         match self {
             Threading::Single => unsafe { ffi::RSMPI_THREAD_SINGLE },
             Threading::Funneled => unsafe { ffi::RSMPI_THREAD_FUNNELED },
@@ -413,3 +424,297 @@ pub fn time() -> c_double {
 pub fn time_resolution() -> c_double {
     unsafe { ffi::RSMPI_Wtick() }
 }
+
+/// An error handler that can be attached to communicators, windows or files.
+///
+/// MPI invokes the error handler of a communicator, window or file when an operation on that
+/// object fails. Besides the predefined error handlers, which can be attached to any of them, an
+/// error handler can be created from a Rust closure for one kind of object.
+///
+/// # Examples
+///
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.3
+#[derive(Debug)]
+pub struct ErrorHandler(MPI_Errhandler);
+
+impl ErrorHandler {
+    /// The `MPI_ERRORS_ARE_FATAL` error handler, which aborts the program.
+    ///
+    /// This is the default error handler of all communicators.
+    pub fn errors_are_fatal() -> Self {
+        ErrorHandler(unsafe { ffi::RSMPI_ERRORS_ARE_FATAL })
+    }
+
+    /// The `MPI_ERRORS_RETURN` error handler, which makes the failing operation return the error
+    /// code.
+    ///
+    /// The error codes are reported by the `try_` variants of operations, e.g.
+    /// [`try_send`](../point_to_point/trait.Destination.html#method.try_send).
+    pub fn errors_return() -> Self {
+        ErrorHandler(unsafe { ffi::RSMPI_ERRORS_RETURN })
+    }
+
+    /// Creates an error handler for communicators from a closure.
+    ///
+    /// The closure receives the communicator on which the error occurred and the error code. When
+    /// the closure returns, the failing operation returns the error code as if the communicator
+    /// used `MPI_ERRORS_RETURN`, which is why attaching the handler is `unsafe`.
+    ///
+    /// The closure is never deallocated, since MPI can invoke it for as long as the error handler
+    /// is attached to any communicator, including duplicates of communicators it was attached to.
+    ///
+    /// **Note:** If the closure panics, the entire program will abort.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1
+    #[cfg(feature = "user-operations")]
+    pub fn new<F>(function: F) -> Self
+    where
+        F: Fn(&dyn Communicator, Error) + Send + Sync + 'static,
+    {
+        unsafe extern "C" fn trampoline<F: Fn(&dyn Communicator, Error)>(
+            cif: &libffi::low::ffi_cif,
+            _result: &mut c_void,
+            args: *const *const c_void,
+            user_function: &F,
+        ) {
+            debug_assert_eq!(2, cif.nargs);
+
+            let (comm, code) = (
+                *(*args.offset(0) as *const *mut MPI_Comm),
+                *(*args.offset(1) as *const *mut c_int),
+            );
+
+            // The communicator is only borrowed from the failing operation, so it must not be
+            // freed when the wrapper goes out of scope.
+            match CommunicatorHandle::try_from_raw(*comm) {
+                Some(
+                    handle @ (CommunicatorHandle::InterComm(_) | CommunicatorHandle::Parent(_)),
+                ) => {
+                    let comm = ManuallyDrop::new(InterCommunicator(handle));
+                    user_function(&*comm, *code)
+                }
+                Some(handle) => {
+                    let comm = ManuallyDrop::new(SimpleCommunicator(handle));
+                    user_function(&*comm, *code)
+                }
+                None => {}
+            }
+        }
+
+        let ffi_closure = error_handler_closure(trampoline::<F>, function);
+        let handler = unsafe {
+            with_uninitialized(|handler| {
+                ffi::MPI_Comm_create_errhandler(Some(*ffi_closure.instantiate_code_ptr()), handler)
+            })
+            .1
+        };
+        // Like the closure, the code it is called through has to stay alive.
+        mem::forget(ffi_closure);
+        ErrorHandler(handler)
+    }
+
+    /// Creates an error handler for windows from a closure.
+    ///
+    /// The closure receives the error code. Unlike for communicators, the failing window is not
+    /// passed to the closure, since windows are typed by the elements they expose. When the
+    /// closure returns, the failing operation returns the error code as if the window used
+    /// `MPI_ERRORS_RETURN`.
+    ///
+    /// The closure is never deallocated, since MPI can invoke it for as long as the error handler
+    /// is attached to any window.
+    ///
+    /// **Note:** If the closure panics, the entire program will abort.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2
+    #[cfg(feature = "user-operations")]
+    pub fn new_for_windows<F>(function: F) -> Self
+    where
+        F: Fn(Error) + Send + Sync + 'static,
+    {
+        unsafe extern "C" fn trampoline<F: Fn(Error)>(
+            cif: &libffi::low::ffi_cif,
+            _result: &mut c_void,
+            args: *const *const c_void,
+            user_function: &F,
+        ) {
+            debug_assert_eq!(2, cif.nargs);
+
+            let code = *(*args.offset(1) as *const *mut c_int);
+            user_function(*code)
+        }
+
+        let ffi_closure = error_handler_closure(trampoline::<F>, function);
+        let handler = unsafe {
+            with_uninitialized(|handler| {
+                ffi::MPI_Win_create_errhandler(Some(*ffi_closure.instantiate_code_ptr()), handler)
+            })
+            .1
+        };
+        mem::forget(ffi_closure);
+        ErrorHandler(handler)
+    }
+
+    /// Creates an error handler for files from a closure.
+    ///
+    /// The closure receives the file on which the error occurred and the error code. When the
+    /// closure returns, the failing operation returns the error code as if the file used
+    /// `MPI_ERRORS_RETURN`.
+    ///
+    /// The closure is never deallocated, since MPI can invoke it for as long as the error handler
+    /// is attached to any file.
+    ///
+    /// **Note:** If the closure panics, the entire program will abort.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.4
+    #[cfg(feature = "user-operations")]
+    pub fn new_for_files<F>(function: F) -> Self
+    where
+        F: Fn(&File, Error) + Send + Sync + 'static,
+    {
+        unsafe extern "C" fn trampoline<F: Fn(&File, Error)>(
+            cif: &libffi::low::ffi_cif,
+            _result: &mut c_void,
+            args: *const *const c_void,
+            user_function: &F,
+        ) {
+            debug_assert_eq!(2, cif.nargs);
+
+            let (file, code) = (
+                *(*args.offset(0) as *const *mut MPI_File),
+                *(*args.offset(1) as *const *mut c_int),
+            );
+
+            // The file is only borrowed from the failing operation, so it must not be closed when
+            // the wrapper goes out of scope.
+            let file = ManuallyDrop::new(File(*file));
+            user_function(&*file, *code)
+        }
+
+        let ffi_closure = error_handler_closure(trampoline::<F>, function);
+        let handler = unsafe {
+            with_uninitialized(|handler| {
+                ffi::MPI_File_create_errhandler(Some(*ffi_closure.instantiate_code_ptr()), handler)
+            })
+            .1
+        };
+        mem::forget(ffi_closure);
+        ErrorHandler(handler)
+    }
+}
+
+/// Leaks `function` and wraps it in a closure that MPI can call with a pointer to the failing
+/// object and a pointer to the error code.
+#[cfg(feature = "user-operations")]
+fn error_handler_closure<F>(
+    trampoline: libffi::low::Callback<F, c_void>,
+    function: F,
+) -> Closure<'static> {
+    let args = [
+        Type::pointer(), // MPI_Comm *, MPI_Win * or MPI_File *
+        Type::pointer(), // int *
+    ];
+    #[allow(unused_mut)]
+    let mut cif = Cif::new(args.iter().cloned(), Type::void());
+    // MS-MPI uses "stdcall" calling convention on 32-bit x86
+    #[cfg(all(msmpi, target_arch = "x86"))]
+    cif.set_abi(libffi::raw::ffi_abi_FFI_STDCALL);
+
+    let function: &'static F = Box::leak(Box::new(function));
+    Closure::new(cif, trampoline, function)
+}
+
+impl Drop for ErrorHandler {
+    fn drop(&mut self) {
+        unsafe {
+            // The predefined error handlers are never deallocated.
+            if self.0 != ffi::RSMPI_ERRORS_ARE_FATAL && self.0 != ffi::RSMPI_ERRORS_RETURN {
+                ffi::MPI_Errhandler_free(&mut self.0);
+            }
+        }
+    }
+}
+
+unsafe impl AsRaw for ErrorHandler {
+    type Raw = MPI_Errhandler;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl FromRaw for ErrorHandler {
+    /// Takes ownership of the error handler `handle`, e.g. one returned by
+    /// `MPI_Comm_get_errhandler()`.
+    ///
+    /// # Safety
+    /// - `handle` must be a live `MPI_Errhandler` object
+    /// - `handle` must not be used after calling this function
+    unsafe fn from_raw(handle: MPI_Errhandler) -> Self {
+        ErrorHandler(handle)
+    }
+}
+
+/// Creates a new error class and returns its value.
+///
+/// Libraries can use their own error classes and codes to report errors through the error
+/// handlers of communicators, see
+/// [`call_error_handler`](../topology/trait.Communicator.html#method.call_error_handler).
+///
+/// # Examples
+///
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.5, see the `MPI_Add_error_class` function
+pub fn add_error_class() -> Error {
+    unsafe { with_uninitialized(|class| ffi::MPI_Add_error_class(class)).1 }
+}
+
+/// Creates a new error code in the error class `class` and returns its value.
+///
+/// # Standard section(s)
+///
+/// 8.5, see the `MPI_Add_error_code` function
+pub fn add_error_code(class: Error) -> Error {
+    unsafe { with_uninitialized(|code| ffi::MPI_Add_error_code(class, code)).1 }
+}
+
+/// Associates the error string `string` with the error code or class `code`.
+///
+/// The string is returned for `code` by `MPI_Error_string()`, e.g. as the message of an
+/// [`MpiError`](../enum.MpiError.html). It must be shorter than `MPI_MAX_ERROR_STRING`.
+///
+/// Returns an `Err` if `string` contains an interior 0 byte.
+///
+/// # Standard section(s)
+///
+/// 8.5, see the `MPI_Add_error_string` function
+pub fn add_error_string(code: Error, string: &str) -> Result<(), MpiError> {
+    let string = CString::new(string)?;
+    unsafe {
+        ffi::MPI_Add_error_string(code, string.as_ptr());
+    }
+    Ok(())
+}
@@ -0,0 +1,319 @@
+//! Info objects
+//!
+//! An [`Info`](struct.Info.html) object is an unordered set of `(key, value)` pairs of strings.
+//! Info objects pass hints to MPI, e.g. when duplicating a communicator or spawning processes. MPI
+//! ignores hints it does not understand, so the same hints can be used with different MPI
+//! libraries.
+//!
+//! # Unfinished features
+//!
+//! - **9**: `MPI_Info_get_string()`, `MPI_Info_create_env()` (MPI-4)
+
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    os::raw::{c_char, c_int},
+};
+
+use conv::ConvUtil;
+
+use crate::{ffi, ffi::MPI_Info, raw::traits::*, with_uninitialized, Error, MpiError};
+
+/// An owned set of `(key, value)` hints for MPI
+///
+/// # Examples
+///
+/// See `examples/info.rs`
+///
+/// # Standard section(s)
+///
+/// 9
+#[repr(transparent)]
+pub struct Info(MPI_Info);
+
+impl Info {
+    /// Creates an empty `Info` object.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_create` function
+    pub fn new() -> Self {
+        Info(unsafe { with_uninitialized(|info| ffi::MPI_Info_create(info)).1 })
+    }
+
+    /// Returns a builder for an `Info` object.
+    pub fn builder() -> InfoBuilder {
+        InfoBuilder::new()
+    }
+
+    /// Sets the value of `key` to `value`, replacing any previous value.
+    ///
+    /// Returns an `Err` if `key` or `value` contain an interior 0 byte, if `key` is empty, or if
+    /// `key` or `value` do not fit into `MPI_MAX_INFO_KEY` or `MPI_MAX_INFO_VAL` characters,
+    /// respectively.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_set` function
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), MpiError> {
+        let key = info_key(key)?;
+        if value.len() >= max_len(unsafe { ffi::RSMPI_MAX_INFO_VAL }) {
+            return Err(MpiError::from_code(ffi::MPI_ERR_INFO_VALUE as Error));
+        }
+        let value = CString::new(value)?;
+        unsafe {
+            ffi::MPI_Info_set(self.0, key.as_ptr(), value.as_ptr());
+        }
+        Ok(())
+    }
+
+    /// Returns the value of `key`, or `None` if `key` is not set.
+    ///
+    /// Values that are not valid UTF-8 are converted lossily.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_get` function
+    pub fn get(&self, key: &str) -> Option<String> {
+        let key = info_key(key).ok()?;
+        let valuelen = self.value_len(&key)?;
+
+        let len: usize = valuelen
+            .value_as()
+            .expect("Received unexpected value from MPI_Info_get_valuelen");
+        let mut buf = vec![0u8; len + 1];
+        let mut flag: c_int = 0;
+        unsafe {
+            ffi::MPI_Info_get(
+                self.0,
+                key.as_ptr(),
+                valuelen,
+                buf.as_mut_ptr() as *mut c_char,
+                &mut flag,
+            );
+        }
+        buf.truncate(len);
+        Some(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    /// Removes `key`, returning whether it was set.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_delete` function
+    pub fn delete(&mut self, key: &str) -> bool {
+        match info_key(key) {
+            // Deleting a key that is not set is an error in MPI.
+            Ok(key) if self.value_len(&key).is_some() => {
+                unsafe {
+                    ffi::MPI_Info_delete(self.0, key.as_ptr());
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The number of keys that are set.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_get_nkeys` function
+    pub fn len(&self) -> usize {
+        self.num_keys()
+            .value_as()
+            .expect("Received unexpected value from MPI_Info_get_nkeys")
+    }
+
+    /// Returns `true` if no keys are set.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the keys that are set.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_get_nthkey` function
+    pub fn keys(&self) -> Keys<'_> {
+        Keys {
+            info: self,
+            next: 0,
+            end: self.num_keys(),
+        }
+    }
+
+    fn num_keys(&self) -> c_int {
+        unsafe { with_uninitialized(|nkeys| ffi::MPI_Info_get_nkeys(self.0, nkeys)).1 }
+    }
+
+    fn value_len(&self, key: &CStr) -> Option<c_int> {
+        let mut valuelen: c_int = 0;
+        let mut flag: c_int = 0;
+        unsafe {
+            ffi::MPI_Info_get_valuelen(self.0, key.as_ptr(), &mut valuelen, &mut flag);
+        }
+        if flag != 0 {
+            Some(valuelen)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Info {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Info {
+    /// Duplicates the `Info` object.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_dup` function
+    fn clone(&self) -> Self {
+        Info(unsafe { with_uninitialized(|newinfo| ffi::MPI_Info_dup(self.0, newinfo)).1 })
+    }
+}
+
+impl fmt::Debug for Info {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.keys().map(|key| {
+                let value = self.get(&key);
+                (key, value)
+            }))
+            .finish()
+    }
+}
+
+impl Drop for Info {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Info_free(&mut self.0);
+        }
+        assert_eq!(self.0, unsafe { ffi::RSMPI_INFO_NULL });
+    }
+}
+
+unsafe impl AsRaw for Info {
+    type Raw = MPI_Info;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+unsafe impl MatchesRaw for Info {}
+
+impl FromRaw for Info {
+    /// Takes ownership of the `Info` object `handle`, e.g. one returned by `MPI_Comm_get_info()`.
+    ///
+    /// # Safety
+    /// - `handle` must be a live `MPI_Info` object, not `MPI_INFO_NULL`
+    /// - `handle` must not be used after calling this function
+    unsafe fn from_raw(handle: MPI_Info) -> Self {
+        debug_assert_ne!(handle, ffi::RSMPI_INFO_NULL);
+        Info(handle)
+    }
+}
+
+/// An iterator over the keys of an [`Info`](struct.Info.html) object
+///
+/// Created by [`Info::keys()`](struct.Info.html#method.keys).
+#[derive(Debug)]
+pub struct Keys<'a> {
+    info: &'a Info,
+    next: c_int,
+    end: c_int,
+}
+
+impl<'a> Iterator for Keys<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let mut buf = vec![0u8; max_len(unsafe { ffi::RSMPI_MAX_INFO_KEY }) + 1];
+        unsafe {
+            ffi::MPI_Info_get_nthkey(
+                self.info.as_raw(),
+                self.next,
+                buf.as_mut_ptr() as *mut c_char,
+            );
+        }
+        self.next += 1;
+
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        buf.truncate(len);
+        Some(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.next).value_as().unwrap_or(0);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Keys<'a> {}
+
+/// A builder for [`Info`](struct.Info.html) objects
+///
+/// # Examples
+///
+/// ```no_run
+/// use mpi::info::Info;
+///
+/// let universe = mpi::initialize().unwrap();
+///
+/// let info = Info::builder()
+///     .set("wdir", "/tmp")
+///     .set("host", "localhost")
+///     .build()
+///     .unwrap();
+/// assert_eq!(Some("/tmp".to_string()), info.get("wdir"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InfoBuilder {
+    entries: Vec<(String, String)>,
+}
+
+impl InfoBuilder {
+    /// Creates a builder without any keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of `key` to `value`. Later values for the same key replace earlier ones.
+    pub fn set(&mut self, key: &str, value: &str) -> &mut Self {
+        self.entries.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Creates the `Info` object.
+    ///
+    /// Returns an `Err` under the same conditions as [`Info::set()`](struct.Info.html#method.set).
+    pub fn build(&self) -> Result<Info, MpiError> {
+        let mut info = Info::new();
+        for (key, value) in &self.entries {
+            info.set(key, value)?;
+        }
+        Ok(info)
+    }
+}
+
+fn max_len(max: c_int) -> usize {
+    max.value_as()
+        .unwrap_or_else(|_| panic!("{} cannot be expressed as a usize.", max))
+}
+
+/// Checks that MPI accepts `key` as an info key before passing it on.
+fn info_key(key: &str) -> Result<CString, MpiError> {
+    if key.is_empty() || key.len() >= max_len(unsafe { ffi::RSMPI_MAX_INFO_KEY }) {
+        return Err(MpiError::from_code(ffi::MPI_ERR_INFO_KEY as Error));
+    }
+    Ok(CString::new(key)?)
+}
@@ -0,0 +1,821 @@
+//! Parallel I/O
+//!
+//! A [`File`](struct.File.html) is opened collectively by all processes of a communicator. Data
+//! can be accessed at explicit offsets, through the individual file pointer of each process, or
+//! through the file pointer shared by all processes. Each of these comes in an independent
+//! variant and a collective variant (suffixed `_all` or named `_ordered`) that has to be called by
+//! all processes of the communicator the file was opened on.
+//!
+//! Offsets and file pointers are counted in units of the elementary datatype of the current file
+//! view, which is a byte unless the view has been changed with
+//! [`set_view()`](struct.File.html#method.set_view).
+//!
+//! Files are opened with the `MPI_ERRORS_ARE_FATAL` error handler like all other objects in this
+//! crate. Only opening and deleting a file report failures through a `Result`. Another error
+//! handler can be attached with [`set_error_handler()`](struct.File.html#method.set_error_handler).
+//!
+//! # Unfinished features
+//!
+//! - **14.3**: `MPI_File_get_view()`
+//! - **14.4.2 - 14.4.4**: Nonblocking data access, `MPI_File_iread_at()`, `MPI_File_iwrite()`, ...
+//! - **14.4.5**: Split collective data access, `MPI_File_read_all_begin()`, ...
+//! - **14.5**: File interoperability, data representations other than `"native"`
+//! - **14.6**: Consistency and semantics, `MPI_File_set_atomicity()`
+
+use std::{
+    ffi::CString,
+    os::raw::{c_char, c_int},
+    path::Path,
+};
+
+use crate::{
+    check_error,
+    datatype::traits::*,
+    environment::ErrorHandler,
+    ffi,
+    ffi::{MPI_File, MPI_Offset},
+    info::Info,
+    point_to_point::Status,
+    raw::traits::*,
+    topology::traits::*,
+    with_uninitialized, Error, MpiError,
+};
+
+/// An offset into a file, counted in units of the elementary datatype of the file view
+pub type Offset = MPI_Offset;
+
+/// Options that determine how a [`File`](struct.File.html) is opened
+///
+/// The options mirror `std::fs::OpenOptions` and translate into the access mode passed to
+/// `MPI_File_open()`. All processes that open a file together have to use the same options.
+///
+/// # Standard section(s)
+///
+/// 14.2.1
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    create: bool,
+    create_new: bool,
+    append: bool,
+    delete_on_close: bool,
+    unique_open: bool,
+    sequential: bool,
+}
+
+impl OpenOptions {
+    /// Create a blank set of options, equivalent to read-only access.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the file for reading.
+    pub fn read(&mut self, read: bool) -> &mut Self {
+        self.read = read;
+        self
+    }
+
+    /// Open the file for writing.
+    pub fn write(&mut self, write: bool) -> &mut Self {
+        self.write = write;
+        self
+    }
+
+    /// Create the file if it does not exist (`MPI_MODE_CREATE`).
+    pub fn create(&mut self, create: bool) -> &mut Self {
+        self.create = create;
+        self
+    }
+
+    /// Create the file and fail if it already exists (`MPI_MODE_CREATE | MPI_MODE_EXCL`).
+    pub fn create_new(&mut self, create_new: bool) -> &mut Self {
+        self.create_new = create_new;
+        self
+    }
+
+    /// Set the initial position of all file pointers to the end of the file (`MPI_MODE_APPEND`).
+    pub fn append(&mut self, append: bool) -> &mut Self {
+        self.append = append;
+        self
+    }
+
+    /// Delete the file when it is closed (`MPI_MODE_DELETE_ON_CLOSE`).
+    pub fn delete_on_close(&mut self, delete_on_close: bool) -> &mut Self {
+        self.delete_on_close = delete_on_close;
+        self
+    }
+
+    /// Promise that the file is not opened concurrently elsewhere (`MPI_MODE_UNIQUE_OPEN`).
+    pub fn unique_open(&mut self, unique_open: bool) -> &mut Self {
+        self.unique_open = unique_open;
+        self
+    }
+
+    /// Promise that the file is only accessed sequentially (`MPI_MODE_SEQUENTIAL`).
+    pub fn sequential(&mut self, sequential: bool) -> &mut Self {
+        self.sequential = sequential;
+        self
+    }
+
+    fn as_raw(&self) -> c_int {
+        let mut amode = unsafe {
+            match (self.read, self.write) {
+                (true, true) => ffi::RSMPI_MODE_RDWR,
+                (false, true) => ffi::RSMPI_MODE_WRONLY,
+                _ => ffi::RSMPI_MODE_RDONLY,
+            }
+        };
+        let flags = unsafe {
+            [
+                (self.create || self.create_new, ffi::RSMPI_MODE_CREATE),
+                (self.create_new, ffi::RSMPI_MODE_EXCL),
+                (self.append, ffi::RSMPI_MODE_APPEND),
+                (self.delete_on_close, ffi::RSMPI_MODE_DELETE_ON_CLOSE),
+                (self.unique_open, ffi::RSMPI_MODE_UNIQUE_OPEN),
+                (self.sequential, ffi::RSMPI_MODE_SEQUENTIAL),
+            ]
+        };
+        for (set, flag) in flags {
+            if set {
+                amode |= flag;
+            }
+        }
+        amode
+    }
+
+    /// Collectively open the file at `path` on all processes of `comm` with these options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `MpiError::Mpi` if the MPI library could not open the file, e.g. because it
+    /// does not exist and `create` was not set, and an `MpiError::StringNul` if `path` contains a
+    /// 0 byte.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/file.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.1
+    pub fn open<C, P>(&self, comm: &C, path: P) -> Result<File, MpiError>
+    where
+        C: Communicator + ?Sized,
+        P: AsRef<Path>,
+    {
+        self.open_with_info(comm, path, &Info::new())
+    }
+
+    /// Collectively open the file at `path` on all processes of `comm` with these options,
+    /// passing the hints in `info` to MPI, e.g. how the file should be striped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `MpiError::Mpi` if the MPI library could not open the file, and an
+    /// `MpiError::StringNul` if `path` contains a 0 byte.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.1, 14.2.8
+    pub fn open_with_info<C, P>(&self, comm: &C, path: P, info: &Info) -> Result<File, MpiError>
+    where
+        C: Communicator + ?Sized,
+        P: AsRef<Path>,
+    {
+        let path = path_to_cstring(path.as_ref())?;
+        let (code, raw) = unsafe {
+            with_uninitialized(|fh| {
+                ffi::MPI_File_open(
+                    comm.as_raw(),
+                    path.as_ptr(),
+                    self.as_raw(),
+                    info.as_raw(),
+                    fh,
+                )
+            })
+        };
+        check_error(code)?;
+        unsafe {
+            ffi::MPI_File_set_errhandler(raw, ffi::RSMPI_ERRORS_ARE_FATAL);
+        }
+        Ok(File(raw))
+    }
+}
+
+/// Turns `path` into the file name passed to MPI.
+///
+/// On Unix the bytes of the path are passed unchanged.
+#[cfg(unix)]
+fn path_to_cstring(path: &Path) -> Result<CString, MpiError> {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(CString::new(path.as_os_str().as_bytes())?)
+}
+
+/// Turns `path` into the file name passed to MPI.
+///
+/// Paths that are not valid Unicode cannot be passed unchanged and are rejected with
+/// `MPI_ERR_BAD_FILE`.
+#[cfg(not(unix))]
+fn path_to_cstring(path: &Path) -> Result<CString, MpiError> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| MpiError::from_code(ffi::MPI_ERR_BAD_FILE as Error))?;
+    Ok(CString::new(path)?)
+}
+
+/// Where a seek operation starts from
+///
+/// # Standard section(s)
+///
+/// 14.4.3
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Whence {
+    /// Seek relative to the start of the file view (`MPI_SEEK_SET`)
+    Start,
+    /// Seek relative to the current position of the file pointer (`MPI_SEEK_CUR`)
+    Current,
+    /// Seek relative to the end of the file (`MPI_SEEK_END`)
+    End,
+}
+
+impl Whence {
+    fn as_raw(self) -> c_int {
+        unsafe {
+            match self {
+                Whence::Start => ffi::RSMPI_SEEK_SET,
+                Whence::Current => ffi::RSMPI_SEEK_CUR,
+                Whence::End => ffi::RSMPI_SEEK_END,
+            }
+        }
+    }
+}
+
+/// A file opened collectively by the processes of a communicator
+///
+/// The file is closed when it is dropped, which is a collective operation on the communicator the
+/// file was opened on.
+///
+/// # Examples
+///
+/// See `examples/file.rs`
+///
+/// # Standard section(s)
+///
+/// 14.2
+pub struct File(pub(crate) MPI_File);
+
+impl File {
+    /// Collectively open an existing file at `path` for reading on all processes of `comm`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.1
+    pub fn open<C, P>(comm: &C, path: P) -> Result<File, MpiError>
+    where
+        C: Communicator + ?Sized,
+        P: AsRef<Path>,
+    {
+        OpenOptions::new().read(true).open(comm, path)
+    }
+
+    /// Collectively create (or open an existing) file at `path` for reading and writing on all
+    /// processes of `comm`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.1
+    pub fn create<C, P>(comm: &C, path: P) -> Result<File, MpiError>
+    where
+        C: Communicator + ?Sized,
+        P: AsRef<Path>,
+    {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(comm, path)
+    }
+
+    /// Delete the file at `path`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.3
+    pub fn delete<P: AsRef<Path>>(path: P) -> Result<(), MpiError> {
+        let path = path_to_cstring(path.as_ref())?;
+        check_error(unsafe { ffi::MPI_File_delete(path.as_ptr(), ffi::RSMPI_INFO_NULL) })
+    }
+
+    /// The current size of the file in bytes
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.5
+    pub fn size(&self) -> Offset {
+        unsafe { with_uninitialized(|size| ffi::MPI_File_get_size(self.0, size)).1 }
+    }
+
+    /// Collectively resize the file to `size` bytes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.4
+    pub fn set_size(&self, size: Offset) {
+        unsafe {
+            ffi::MPI_File_set_size(self.0, size);
+        }
+    }
+
+    /// Collectively set the hints of the file.
+    ///
+    /// MPI may ignore some or all of the hints.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.8, see the `MPI_File_set_info` function
+    pub fn set_info(&self, info: &Info) {
+        unsafe {
+            ffi::MPI_File_set_info(self.0, info.as_raw());
+        }
+    }
+
+    /// The hints of the file that are actually used by MPI
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.2.8, see the `MPI_File_get_info` function
+    pub fn get_info(&self) -> Info {
+        unsafe {
+            Info::from_raw(
+                with_uninitialized(|info_used| ffi::MPI_File_get_info(self.0, info_used)).1,
+            )
+        }
+    }
+
+    /// Collectively transfer all data written by the processes to the storage device.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.6.1
+    pub fn sync(&self) {
+        unsafe {
+            ffi::MPI_File_sync(self.0);
+        }
+    }
+
+    /// Collectively change the view of the processes on the file.
+    ///
+    /// The view starts `disp` bytes into the file and consists of repetitions of `filetype`, which
+    /// is built from elements of `etype`. Offsets and file pointers are counted in units of
+    /// `etype` afterwards and both file pointers are reset to zero. Data is stored in the
+    /// `"native"` representation.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/file.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.3
+    pub fn set_view<E, F>(&self, disp: Offset, etype: &E, filetype: &F)
+    where
+        E: Datatype,
+        F: Datatype,
+    {
+        unsafe {
+            ffi::MPI_File_set_view(
+                self.0,
+                disp,
+                etype.as_raw(),
+                filetype.as_raw(),
+                b"native\0".as_ptr().cast::<c_char>(),
+                ffi::RSMPI_INFO_NULL,
+            );
+        }
+    }
+
+    /// Read from the file at `offset` into `buf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.2
+    pub fn read_at<Buf: ?Sized>(&self, offset: Offset, buf: &mut Buf) -> Status
+    where
+        Buf: BufferMut,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_read_at(
+                        self.0,
+                        offset,
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively read from the file at `offset` into `buf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/file.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.2
+    pub fn read_at_all<Buf: ?Sized>(&self, offset: Offset, buf: &mut Buf) -> Status
+    where
+        Buf: BufferMut,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_read_at_all(
+                        self.0,
+                        offset,
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Write the contents of `buf` to the file at `offset`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.2
+    pub fn write_at<Buf: ?Sized>(&self, offset: Offset, buf: &Buf) -> Status
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_write_at(
+                        self.0,
+                        offset,
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively write the contents of `buf` to the file at `offset`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/file.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.2
+    pub fn write_at_all<Buf: ?Sized>(&self, offset: Offset, buf: &Buf) -> Status
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_write_at_all(
+                        self.0,
+                        offset,
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Read from the file at the individual file pointer into `buf` and advance the pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.3
+    pub fn read<Buf: ?Sized>(&self, buf: &mut Buf) -> Status
+    where
+        Buf: BufferMut,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_read(
+                        self.0,
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively read from the file at the individual file pointer into `buf` and advance the
+    /// pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.3
+    pub fn read_all<Buf: ?Sized>(&self, buf: &mut Buf) -> Status
+    where
+        Buf: BufferMut,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_read_all(
+                        self.0,
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Write the contents of `buf` to the file at the individual file pointer and advance the
+    /// pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.3
+    pub fn write<Buf: ?Sized>(&self, buf: &Buf) -> Status
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_write(
+                        self.0,
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively write the contents of `buf` to the file at the individual file pointer and
+    /// advance the pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.3
+    pub fn write_all<Buf: ?Sized>(&self, buf: &Buf) -> Status
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_write_all(
+                        self.0,
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Move the individual file pointer to `offset` relative to `whence`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.3
+    pub fn seek(&self, offset: Offset, whence: Whence) {
+        unsafe {
+            ffi::MPI_File_seek(self.0, offset, whence.as_raw());
+        }
+    }
+
+    /// The current position of the individual file pointer
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.3
+    pub fn position(&self) -> Offset {
+        unsafe { with_uninitialized(|offset| ffi::MPI_File_get_position(self.0, offset)).1 }
+    }
+
+    /// Read from the file at the shared file pointer into `buf` and advance the pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.4
+    pub fn read_shared<Buf: ?Sized>(&self, buf: &mut Buf) -> Status
+    where
+        Buf: BufferMut,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_read_shared(
+                        self.0,
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively read from the file at the shared file pointer into `buf` in rank order and
+    /// advance the pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.4
+    pub fn read_ordered<Buf: ?Sized>(&self, buf: &mut Buf) -> Status
+    where
+        Buf: BufferMut,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_read_ordered(
+                        self.0,
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Write the contents of `buf` to the file at the shared file pointer and advance the pointer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.4
+    pub fn write_shared<Buf: ?Sized>(&self, buf: &Buf) -> Status
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_write_shared(
+                        self.0,
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively write the contents of `buf` to the file at the shared file pointer in rank
+    /// order and advance the pointer.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/file.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.4
+    pub fn write_ordered<Buf: ?Sized>(&self, buf: &Buf) -> Status
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| {
+                    ffi::MPI_File_write_ordered(
+                        self.0,
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Collectively move the shared file pointer to `offset` relative to `whence`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.4
+    pub fn seek_shared(&self, offset: Offset, whence: Whence) {
+        unsafe {
+            ffi::MPI_File_seek_shared(self.0, offset, whence.as_raw());
+        }
+    }
+
+    /// The current position of the shared file pointer
+    ///
+    /// # Standard section(s)
+    ///
+    /// 14.4.4
+    pub fn position_shared(&self) -> Offset {
+        unsafe { with_uninitialized(|offset| ffi::MPI_File_get_position_shared(self.0, offset)).1 }
+    }
+
+    /// Attaches the error handler `handler` to this file, replacing its current error handler.
+    ///
+    /// # Safety
+    /// - `handler` must be a predefined error handler or have been created with
+    /// [`ErrorHandler::new_for_files()`](../environment/struct.ErrorHandler.html#method.new_for_files).
+    /// - If `handler` returns instead of aborting the program, the failing operation returns as
+    /// well, but the operations on files ignore the error codes and use their outputs, e.g. a
+    /// `Status`, as if MPI had written them. The caller must ensure that no operation on this
+    /// file fails then, except for those returning a `Result`.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.4, see the `MPI_File_set_errhandler` function
+    pub unsafe fn set_error_handler(&self, handler: &ErrorHandler) {
+        ffi::MPI_File_set_errhandler(self.0, handler.as_raw());
+    }
+
+    /// Returns the error handler currently attached to this file.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.4, see the `MPI_File_get_errhandler` function
+    pub fn get_error_handler(&self) -> ErrorHandler {
+        unsafe {
+            ErrorHandler::from_raw(
+                with_uninitialized(|handler| ffi::MPI_File_get_errhandler(self.0, handler)).1,
+            )
+        }
+    }
+
+    /// Invokes the error handler of this file with the error code `code`.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.5, see the `MPI_File_call_errhandler` function
+    pub fn call_error_handler(&self, code: Error) {
+        unsafe {
+            ffi::MPI_File_call_errhandler(self.0, code);
+        }
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_File_close(&mut self.0);
+        }
+        assert_eq!(self.0, unsafe { ffi::RSMPI_FILE_NULL });
+    }
+}
+
+unsafe impl AsRaw for File {
+    type Raw = MPI_File;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
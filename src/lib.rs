@@ -87,13 +87,16 @@
 //! - **Groups, Contexts, Communicators**:
 //!   - Group and (Intra-)Communicator management from section 6 is mostly complete.
 //!   - no Inter-Communicators
-//!   - no process topologies
+//!   - Cartesian, graph and distributed graph process topologies
 //! - **Point to point communication**:
 //!   - standard, buffered, synchronous and ready mode send in blocking and non-blocking variants
 //!   - receive in blocking and non-blocking variants
 //!   - send-receive
 //!   - probe
 //!   - matched probe/receive
+//!   - persistent requests
+//!   - partitioned communication (MPI-4)
+//!   - `async`/`await` support for non-blocking requests
 //! - **Collective communication**:
 //!   - barrier
 //!   - broadcast
@@ -103,13 +106,31 @@
 //!   - varying counts operations
 //!   - reductions/scans
 //!   - blocking and non-blocking variants
-//! - **Datatypes**: Bridging between Rust types and MPI basic types as well as custom MPI datatypes
-//! which can act as views into buffers.
+//!   - persistent variants (MPI-4)
+//!   - neighborhood collectives on Cartesian and graph communicators
+//! - **Datatypes**: Bridging between Rust types and MPI basic types as well as custom MPI
+//!   datatypes which can act as views into buffers.
+//! - **One-sided communication**:
+//!   - window creation and allocation
+//!   - put, get and accumulate
+//!   - fence, post-start-complete-wait and lock synchronization
+//! - **Parallel I/O**:
+//!   - collective file opening and closing
+//!   - independent and collective reads and writes at explicit offsets, through individual file
+//!     pointers and through shared file pointers
+//!   - file views
+//! - **Error handling**:
+//!   - opt-in `MPI_ERRORS_RETURN` mode with fallible `try_` variants of common operations
+//!   - user-defined error handlers on communicators, windows and files
+//!   - user-defined error classes, codes and strings
+//! - **Info objects**: hints for communicators, process spawning, windows and files
+//! - **Sessions** (MPI-4): initialization without the world model, process sets and communicators
+//!   created from groups
+//! - **Tool information interface**: enumerating, reading and writing control variables,
+//!   performance variable sessions
 //!
 //! Not supported (yet):
 //!
-//! - One-sided communication (RMA)
-//! - MPI parallel I/O
 //! - A million small things
 //!
 //! The sub-modules contain a more detailed description of which features are and are not
@@ -123,7 +144,12 @@
 //!
 //! [MPIspec]: http://www.mpi-forum.org/docs/docs.html
 
-use std::{mem::MaybeUninit, os::raw::c_int};
+use std::{
+    mem::MaybeUninit,
+    os::raw::{c_char, c_int},
+};
+
+use conv::ConvUtil;
 
 /// The raw C language MPI API
 ///
@@ -140,10 +166,17 @@ pub mod attribute;
 pub mod collective;
 pub mod datatype;
 pub mod environment;
+pub mod info;
+pub mod io;
 pub mod point_to_point;
 pub mod raw;
 pub mod request;
+#[cfg(mpi4)]
+pub mod session;
+#[cfg(not(msmpi))]
+pub mod tools;
 pub mod topology;
+pub mod window;
 
 /// Re-exports all traits.
 pub mod traits {
@@ -153,7 +186,7 @@ pub mod traits {
 
     pub use crate::{
         attribute::traits::*, collective::traits::*, datatype::traits::*,
-        point_to_point::traits::*, raw::traits::*, topology::traits::*,
+        point_to_point::traits::*, raw::traits::*, topology::traits::*, window::traits::*,
     };
 }
 
@@ -176,6 +209,8 @@ use crate::ffi::MPI_Aint;
 pub type Error = c_int;
 /// Encodes number of values in multi-value messages.
 pub type Count = c_int;
+/// Encodes number of values in messages that may exceed the range of `Count`.
+pub type LargeCount = ffi::MPI_Count;
 /// Can be used to tag messages on the sender side and match on the receiver side.
 pub type Tag = c_int;
 /// An address in memory
@@ -212,17 +247,21 @@ where
 
 /// Errors
 ///
-/// RSMPI is currently configured with MPI_ERRORS_ARE_FATAL, but:
-///
-/// 1. we intend to remove this restriction at some point
+/// By default, communicators use the `MPI_ERRORS_ARE_FATAL` error handler, so an error in an MPI
+/// function aborts the program. A communicator can instead be switched to `MPI_ERRORS_RETURN` with
+/// [`set_errors_return`](topology/trait.Communicator.html#method.set_errors_return). The `try_`
+/// variants of communication operations then report failures as `MpiError::Mpi`, while all other
+/// operations keep ignoring the returned error codes, which is why switching is `unsafe`.
 ///
-/// 2. we need to be able to return parse errors and it seems better to make a
-/// stable error type than to propagate raw types like ``std::ffi::NulError` in
-/// our public interface.
+/// This type is also used to return parse errors, since it seems better to make a stable error
+/// type than to propagate raw types like `std::ffi::NulError` in our public interface. With the
+/// `serde` feature, values that cannot be serialized or deserialized are reported as
+/// `MpiError::Serde`. The variant exists regardless of the feature, so that enabling it does not
+/// change the type.
 ///
 /// # Standard section(s)
 ///
-/// 9.3
+/// 8.3, 9.3
 #[derive(thiserror::Error, Debug)]
 pub enum MpiError {
     /// Failed to spawn some processes
@@ -231,4 +270,65 @@ pub enum MpiError {
     /// CString::new fails if a Rust string contains interior 0 bytes
     #[error("An interior 0 byte was found in string")]
     StringNul(#[from] std::ffi::NulError),
+    /// An MPI function returned an error code
+    #[error("MPI error {code} (class {class}): {message}")]
+    Mpi {
+        /// The error code returned by the MPI function
+        code: Error,
+        /// The error class of `code`
+        class: Error,
+        /// The error string associated with `code` by `MPI_Error_string()`
+        message: String,
+    },
+    /// A value could not be serialized or deserialized
+    #[error("Failed to serialize or deserialize a value: {0}")]
+    Serde(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+#[cfg(feature = "serde")]
+impl From<bincode::Error> for MpiError {
+    fn from(error: bincode::Error) -> Self {
+        MpiError::Serde(error)
+    }
+}
+
+impl MpiError {
+    /// Describe the error code `code` returned by an MPI function or passed to an error handler.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9.4
+    pub fn from_code(code: Error) -> Self {
+        let class = unsafe { with_uninitialized(|class| ffi::MPI_Error_class(code, class)).1 };
+
+        let bufsize = unsafe { ffi::RSMPI_MAX_ERROR_STRING }
+            .value_as()
+            .unwrap_or_else(|_| {
+                panic!(
+                    "MPI_MAX_ERROR_STRING ({}) cannot be expressed as a usize.",
+                    unsafe { ffi::RSMPI_MAX_ERROR_STRING }
+                )
+            });
+        let mut buf = vec![0u8; bufsize];
+        let mut len: c_int = 0;
+        unsafe {
+            ffi::MPI_Error_string(code, buf.as_mut_ptr() as *mut c_char, &mut len);
+        }
+        buf.truncate(len.value_as().unwrap_or(0));
+
+        MpiError::Mpi {
+            code,
+            class,
+            message: String::from_utf8_lossy(&buf).into_owned(),
+        }
+    }
+}
+
+/// Turn the return value of an MPI function into a `Result`.
+pub(crate) fn check_error(code: Error) -> Result<(), MpiError> {
+    if code == ffi::MPI_SUCCESS as Error {
+        Ok(())
+    } else {
+        Err(MpiError::from_code(code))
+    }
 }
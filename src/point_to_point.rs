@@ -4,33 +4,86 @@
 //! `Destination` trait. Communication operations are implemented as default methods on those
 //! traits.
 //!
+//! Buffers are not limited to the range of `Count`. Libraries that implement MPI-4 are passed
+//! the number of elements through the large-count `_c` variants of the functions, e.g.
+//! `MPI_Send_c()`, older libraries receive larger buffers as a single element of a derived
+//! datatype.
+//!
 //! # Unfinished features
 //!
 //! - **3.2.6**: `MPI_STATUS_IGNORE`
 //! - **3.6**: Buffer usage, `MPI_Buffer_attach()`, `MPI_Buffer_detach()`
-//! - **3.9**: Persistent requests, `MPI_Send_init()`, `MPI_Bsend_init()`, `MPI_Ssend_init()`,
-//! `MPI_Rsend_init()`, `MPI_Recv_init()`, `MPI_Start()`, `MPI_Startall()`
+//! - **3.2.5**: `MPI_Get_count_c()` for datatypes that are not predefined on libraries without
+//! MPI-4
 
 use std::{
     alloc::{self, Layout},
     fmt,
+    future::{Future, IntoFuture},
     mem::{transmute, MaybeUninit},
+    pin::Pin,
     ptr,
+    task::{Context, Poll},
 };
 
 use conv::ConvUtil;
 
-use super::{Count, Tag};
+use super::{Count, LargeCount, Tag};
+#[cfg(mpi4)]
+use crate::request::PartitionedRequest;
 use crate::{
-    datatype::traits::*,
+    check_error,
+    datatype::{traits::*, CountedBuffer},
     ffi,
     ffi::{MPI_Message, MPI_Status},
     raw::traits::*,
-    request::{Request, Scope, StaticScope},
+    request::{PersistentRequest, Request, RequestFuture, Scope, StaticScope},
     topology::{traits::*, AnyProcess, CommunicatorRelation, Process, Rank},
-    with_uninitialized, with_uninitialized2,
+    with_uninitialized, with_uninitialized2, MpiError,
 };
 
+/// The functions used with a `CountedBuffer`: the large-count variants with MPI-4, the classic
+/// ones otherwise
+mod large {
+    #[cfg(not(mpi4))]
+    pub(super) use crate::ffi::{
+        MPI_Bsend, MPI_Bsend_init, MPI_Ibsend, MPI_Imrecv, MPI_Irecv, MPI_Irsend, MPI_Isend,
+        MPI_Issend, MPI_Mrecv, MPI_Recv, MPI_Recv_init, MPI_Rsend, MPI_Rsend_init, MPI_Send,
+        MPI_Send_init, MPI_Sendrecv, MPI_Sendrecv_replace, MPI_Ssend, MPI_Ssend_init,
+    };
+    #[cfg(mpi4)]
+    pub(super) use crate::ffi::{
+        MPI_Bsend_c as MPI_Bsend, MPI_Bsend_init_c as MPI_Bsend_init, MPI_Ibsend_c as MPI_Ibsend,
+        MPI_Imrecv_c as MPI_Imrecv, MPI_Irecv_c as MPI_Irecv, MPI_Irsend_c as MPI_Irsend,
+        MPI_Isend_c as MPI_Isend, MPI_Issend_c as MPI_Issend, MPI_Mrecv_c as MPI_Mrecv,
+        MPI_Recv_c as MPI_Recv, MPI_Recv_init_c as MPI_Recv_init, MPI_Rsend_c as MPI_Rsend,
+        MPI_Rsend_init_c as MPI_Rsend_init, MPI_Send_c as MPI_Send,
+        MPI_Send_init_c as MPI_Send_init, MPI_Sendrecv_c as MPI_Sendrecv,
+        MPI_Sendrecv_replace_c as MPI_Sendrecv_replace, MPI_Ssend_c as MPI_Ssend,
+        MPI_Ssend_init_c as MPI_Ssend_init,
+    };
+}
+
+/// The number of partitions and the number of elements per partition of a buffer of `len`
+/// elements that is split into `partitions` partitions
+#[cfg(mpi4)]
+fn partition_counts(len: usize, partitions: usize) -> (Count, LargeCount) {
+    assert!(
+        partitions > 0 && len % partitions == 0,
+        "{} partitions do not evenly divide a buffer of {} elements",
+        partitions,
+        len
+    );
+    (
+        partitions
+            .value_as()
+            .expect("Number of partitions cannot be expressed as an MPI Count."),
+        (len / partitions)
+            .value_as()
+            .expect("Length of partition cannot be expressed as an MPI Count."),
+    )
+}
+
 // TODO: rein in _with_tag ugliness, use optional tags or make tag part of Source and Destination
 
 /// Point to point communication traits
@@ -206,13 +259,14 @@ pub unsafe trait Source: AsCommunicator {
     where
         Buf: BufferMut,
     {
+        let counted = CountedBuffer::new(&*buf);
         unsafe {
             Status(
                 with_uninitialized(|status| {
-                    ffi::MPI_Recv(
+                    large::MPI_Recv(
                         buf.pointer_mut(),
-                        buf.count(),
-                        buf.as_datatype().as_raw(),
+                        counted.count,
+                        counted.datatype(),
                         self.source_rank(),
                         tag,
                         self.as_communicator().as_raw(),
@@ -238,6 +292,58 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_into_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Like [`receive_into_with_tag`](#method.receive_into_with_tag), but returns the error
+    /// reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 8.3
+    fn try_receive_into_with_tag<Buf: ?Sized>(
+        &self,
+        buf: &mut Buf,
+        tag: Tag,
+    ) -> Result<Status, MpiError>
+    where
+        Buf: BufferMut,
+    {
+        let counted = CountedBuffer::new(&*buf);
+        let mut status = MaybeUninit::uninit();
+        check_error(unsafe {
+            large::MPI_Recv(
+                buf.pointer_mut(),
+                counted.count,
+                counted.datatype(),
+                self.source_rank(),
+                tag,
+                self.as_communicator().as_raw(),
+                status.as_mut_ptr(),
+            )
+        })?;
+        // The status is only written if the receive succeeded.
+        Ok(Status(unsafe { status.assume_init() }))
+    }
+
+    /// Like [`receive_into`](#method.receive_into), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Examples
+    /// See `examples/errors_return.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 8.3
+    fn try_receive_into<Buf: ?Sized>(&self, buf: &mut Buf) -> Result<Status, MpiError>
+    where
+        Buf: BufferMut,
+    {
+        self.try_receive_into_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
     /// Receive a message containing multiple instances of type `Msg` into a `Vec`.
     ///
     /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
@@ -271,6 +377,49 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_vec_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Receive a message matching `tag` containing a serialized value of type `T` and deserialize
+    /// it.
+    ///
+    /// The message is probed first to size the receive buffer, so it can be as large as needed.
+    /// Returns an `Err` if the message does not contain a valid serialization of `T`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 3.8.2
+    #[cfg(feature = "serde")]
+    fn receive_deserialized_with_tag<T>(&self, tag: Tag) -> Result<(T, Status), MpiError>
+    where
+        T: DeserializeOwned,
+    {
+        let (message, status) = self.matched_probe_with_tag(tag);
+        let len = status
+            .large_count(u8::equivalent_datatype())
+            .value_as()
+            .expect("Message length cannot be expressed as a usize.");
+        let mut bytes = vec![0u8; len];
+        let status = message.matched_receive_into(&mut bytes[..]);
+        Ok((bincode::deserialize(&bytes)?, status))
+    }
+
+    /// Receive a message containing a serialized value of type `T` and deserialize it.
+    ///
+    /// Returns an `Err` if the message does not contain a valid serialization of `T`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 3.8.2
+    #[cfg(feature = "serde")]
+    fn receive_deserialized<T>(&self) -> Result<(T, Status), MpiError>
+    where
+        T: DeserializeOwned,
+    {
+        self.receive_deserialized_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
     /// Initiate an immediate (non-blocking) receive operation.
     ///
     /// Initiate receiving a message matching `tag` into `buf`.
@@ -288,13 +437,14 @@ pub unsafe trait Source: AsCommunicator {
         Buf: 'a + BufferMut,
         Sc: Scope<'a>,
     {
+        let counted = CountedBuffer::new(&*buf);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Irecv(
+                    large::MPI_Irecv(
                         buf.pointer_mut(),
-                        buf.count(),
-                        buf.as_datatype().as_raw(),
+                        counted.count,
+                        counted.datatype(),
                         self.source_rank(),
                         tag,
                         self.as_communicator().as_raw(),
@@ -354,7 +504,7 @@ pub unsafe trait Source: AsCommunicator {
             });
             ReceiveFuture {
                 val,
-                req: Request::from_raw(request, &(), StaticScope),
+                req: Request::from_raw(request, &(), StaticScope).into_future(),
             }
         }
     }
@@ -465,6 +615,148 @@ pub unsafe trait Source: AsCommunicator {
     fn immediate_matched_probe(&self) -> Option<(Message, Status)> {
         self.immediate_matched_probe_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
     }
+
+    /// Create a persistent request for receiving messages matching `tag` into `buf`.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn receive_init_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        let counted = CountedBuffer::new(&*buf);
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    large::MPI_Recv_init(
+                        buf.pointer_mut(),
+                        counted.count,
+                        counted.datatype(),
+                        self.source_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent request for receiving messages into `buf`.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start).
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn receive_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        self.receive_init_with_tag(scope, buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Create a partitioned request for receiving a message matching `tag` into `buf` as
+    /// `partitions` equally sized partitions (MPI-4).
+    ///
+    /// Partitioned communication does not support wildcards, so the source must be a `Process` and
+    /// `tag` must not be `MPI_ANY_TAG`. The number of partitions may differ from the sender's, but
+    /// the total size of the message has to match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `partitions` is 0 or does not divide the length of `buf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Precv_init` function
+    #[cfg(mpi4)]
+    fn partitioned_receive_init_with_tag<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: usize,
+        tag: Tag,
+    ) -> PartitionedRequest<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        let (raw_partitions, count) = partition_counts(buf.len(), partitions);
+        unsafe {
+            PartitionedRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Precv_init(
+                        buf.pointer_mut(),
+                        raw_partitions,
+                        count,
+                        T::equivalent_datatype().as_raw(),
+                        self.source_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                partitions,
+                false,
+                scope,
+            )
+        }
+    }
+
+    /// Create a partitioned request for receiving a message with the default tag into `buf` as
+    /// `partitions` equally sized partitions (MPI-4).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `partitions` is 0 or does not divide the length of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Precv_init` function
+    #[cfg(mpi4)]
+    fn partitioned_receive_init<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: usize,
+    ) -> PartitionedRequest<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        self.partitioned_receive_init_with_tag(scope, buf, partitions, Tag::default())
+    }
 }
 
 unsafe impl<'a> Source for AnyProcess<'a> {
@@ -502,11 +794,12 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
-            ffi::MPI_Send(
+            large::MPI_Send(
                 buf.pointer(),
-                buf.count(),
-                buf.as_datatype().as_raw(),
+                counted.count,
+                counted.datatype(),
                 self.destination_rank(),
                 tag,
                 self.as_communicator().as_raw(),
@@ -542,6 +835,89 @@ pub trait Destination: AsCommunicator {
         self.send_with_tag(buf, Tag::default())
     }
 
+    /// Serialize `value` and send it to the `Destination` `&self`, tagging it `tag`.
+    ///
+    /// Returns an `Err` without sending anything if `value` cannot be serialized.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "serde")]
+    fn send_serialized_with_tag<T: ?Sized>(&self, value: &T, tag: Tag) -> Result<(), MpiError>
+    where
+        T: Serialize,
+    {
+        let bytes = bincode::serialize(value)?;
+        self.send_with_tag(&bytes[..], tag);
+        Ok(())
+    }
+
+    /// Serialize `value` and send it to the `Destination` `&self`.
+    ///
+    /// Unlike [`send()`](#method.send), this works for any value that implements
+    /// `serde::Serialize`, e.g. a `String` or a `HashMap`. The value is received with
+    /// [`Source::receive_deserialized()`](trait.Source.html#method.receive_deserialized).
+    ///
+    /// Returns an `Err` without sending anything if `value` cannot be serialized.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "serde")]
+    fn send_serialized<T: ?Sized>(&self, value: &T) -> Result<(), MpiError>
+    where
+        T: Serialize,
+    {
+        self.send_serialized_with_tag(value, Tag::default())
+    }
+
+    /// Like [`send_with_tag`](#method.send_with_tag), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1, 8.3
+    fn try_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), MpiError>
+    where
+        Buf: Buffer,
+    {
+        let counted = CountedBuffer::new(buf);
+        check_error(unsafe {
+            large::MPI_Send(
+                buf.pointer(),
+                counted.count,
+                counted.datatype(),
+                self.destination_rank(),
+                tag,
+                self.as_communicator().as_raw(),
+            )
+        })
+    }
+
+    /// Like [`send`](#method.send), but returns the error reported by MPI.
+    ///
+    /// Errors are only reported if the communicator uses the `MPI_ERRORS_RETURN` error handler,
+    /// see [`set_errors_return`](../topology/trait.Communicator.html#method.set_errors_return).
+    ///
+    /// # Examples
+    /// See `examples/errors_return.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1, 8.3
+    fn try_send<Buf: ?Sized>(&self, buf: &Buf) -> Result<(), MpiError>
+    where
+        Buf: Buffer,
+    {
+        self.try_send_with_tag(buf, Tag::default())
+    }
+
     /// Blocking buffered mode send operation
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
@@ -553,11 +929,12 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
-            ffi::MPI_Bsend(
+            large::MPI_Bsend(
                 buf.pointer(),
-                buf.count(),
-                buf.as_datatype().as_raw(),
+                counted.count,
+                counted.datatype(),
                 self.destination_rank(),
                 tag,
                 self.as_communicator().as_raw(),
@@ -592,11 +969,12 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
-            ffi::MPI_Ssend(
+            large::MPI_Ssend(
                 buf.pointer(),
-                buf.count(),
-                buf.as_datatype().as_raw(),
+                counted.count,
+                counted.datatype(),
                 self.destination_rank(),
                 tag,
                 self.as_communicator().as_raw(),
@@ -650,11 +1028,12 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
-            ffi::MPI_Rsend(
+            large::MPI_Rsend(
                 buf.pointer(),
-                buf.count(),
-                buf.as_datatype().as_raw(),
+                counted.count,
+                counted.datatype(),
                 self.destination_rank(),
                 tag,
                 self.as_communicator().as_raw(),
@@ -712,13 +1091,14 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Isend(
+                    large::MPI_Isend(
                         buf.pointer(),
-                        buf.count(),
-                        buf.as_datatype().as_raw(),
+                        counted.count,
+                        counted.datatype(),
                         self.destination_rank(),
                         tag,
                         self.as_communicator().as_raw(),
@@ -767,13 +1147,14 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Ibsend(
+                    large::MPI_Ibsend(
                         buf.pointer(),
-                        buf.count(),
-                        buf.as_datatype().as_raw(),
+                        counted.count,
+                        counted.datatype(),
                         self.destination_rank(),
                         tag,
                         self.as_communicator().as_raw(),
@@ -823,13 +1204,14 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Issend(
+                    large::MPI_Issend(
                         buf.pointer(),
-                        buf.count(),
-                        buf.as_datatype().as_raw(),
+                        counted.count,
+                        counted.datatype(),
                         self.destination_rank(),
                         tag,
                         self.as_communicator().as_raw(),
@@ -898,13 +1280,14 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        let counted = CountedBuffer::new(buf);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Irsend(
+                    large::MPI_Irsend(
                         buf.pointer(),
-                        buf.count(),
-                        buf.as_datatype().as_raw(),
+                        counted.count,
+                        counted.datatype(),
                         self.destination_rank(),
                         tag,
                         self.as_communicator().as_raw(),
@@ -959,6 +1342,375 @@ pub trait Destination: AsCommunicator {
     {
         self.immediate_ready_send_with_tag(scope, buf, Tag::default())
     }
+
+    /// Create a persistent request for sending the data in `buf` in standard mode and tagging it.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start). `buf`
+    /// is borrowed mutably so that its contents can be updated between starts through
+    /// [`PersistentRequest::data_mut()`](../request/struct.PersistentRequest.html#method.data_mut).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn send_init_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        let counted = CountedBuffer::new(buf);
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    large::MPI_Send_init(
+                        buf.pointer(),
+                        counted.count,
+                        counted.datatype(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent request for sending the data in `buf` in standard mode.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start).
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn send_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.send_init_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Create a persistent request for sending the data in `buf` in buffered mode and tagging it.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start). `buf`
+    /// is borrowed mutably so that its contents can be updated between starts through
+    /// [`PersistentRequest::data_mut()`](../request/struct.PersistentRequest.html#method.data_mut).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn buffered_send_init_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        let counted = CountedBuffer::new(buf);
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    large::MPI_Bsend_init(
+                        buf.pointer(),
+                        counted.count,
+                        counted.datatype(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent request for sending the data in `buf` in buffered mode.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn buffered_send_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.buffered_send_init_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Create a persistent request for sending the data in `buf` in synchronous mode and tagging it.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start). `buf`
+    /// is borrowed mutably so that its contents can be updated between starts through
+    /// [`PersistentRequest::data_mut()`](../request/struct.PersistentRequest.html#method.data_mut).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn synchronous_send_init_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        let counted = CountedBuffer::new(buf);
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    large::MPI_Ssend_init(
+                        buf.pointer(),
+                        counted.count,
+                        counted.datatype(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent request for sending the data in `buf` in synchronous mode.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn synchronous_send_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.synchronous_send_init_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Create a persistent request for sending the data in `buf` in ready mode and tagging it.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start). `buf`
+    /// is borrowed mutably so that its contents can be updated between starts through
+    /// [`PersistentRequest::data_mut()`](../request/struct.PersistentRequest.html#method.data_mut).
+    ///
+    /// # Safety
+    ///
+    /// Every start of the request is erroneous if the matching receive operation has not already
+    /// been started. MPI-4.1 §3.4 Communication Modes:
+    ///
+    /// > A send that uses the *ready* communication mode may be started only if
+    /// > the matching receive is already started. Otherwise, the operation is
+    /// > erroneous and its outcome is undefined. On some systems, this allows the
+    /// > removal of a hand-shake protocol that is otherwise required and results
+    /// > in improved performance.
+    ///
+    /// Moreover, in case such an error is detected, it may not be reportable.
+    /// MPI-4.1 §2.8 Error Reporting:
+    ///
+    /// > In a few cases, the error may occur after all calls that relate to the
+    /// > operation have returned, so that no error value can be used to indicate
+    /// > the nature of the error (e.g., an erroneous program on the receiver in a
+    /// > send with the *ready* mode).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.4, 3.9
+    unsafe fn ready_send_init_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        let counted = CountedBuffer::new(buf);
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    large::MPI_Rsend_init(
+                        buf.pointer(),
+                        counted.count,
+                        counted.datatype(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent request for sending the data in `buf` in ready mode.
+    ///
+    /// The request is inactive until it is started with
+    /// [`PersistentRequest::start()`](../request/struct.PersistentRequest.html#method.start).
+    ///
+    /// # Safety
+    ///
+    /// Every start of the request is erroneous if the matching receive operation has not already
+    /// been started. MPI-4.1 §3.4 Communication Modes:
+    ///
+    /// > A send that uses the *ready* communication mode may be started only if
+    /// > the matching receive is already started. Otherwise, the operation is
+    /// > erroneous and its outcome is undefined. On some systems, this allows the
+    /// > removal of a hand-shake protocol that is otherwise required and results
+    /// > in improved performance.
+    ///
+    /// Moreover, in case such an error is detected, it may not be reportable.
+    /// MPI-4.1 §2.8 Error Reporting:
+    ///
+    /// > In a few cases, the error may occur after all calls that relate to the
+    /// > operation have returned, so that no error value can be used to indicate
+    /// > the nature of the error (e.g., an erroneous program on the receiver in a
+    /// > send with the *ready* mode).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.4, 3.9
+    unsafe fn ready_send_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.ready_send_init_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Create a partitioned request for sending the data in `buf` as `partitions` equally sized
+    /// partitions and tagging it (MPI-4).
+    ///
+    /// Once the request has been started with
+    /// [`PartitionedRequest::start()`](../request/struct.PartitionedRequest.html#method.start),
+    /// each partition is transferred as soon as it has been marked ready.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `partitions` is 0 or does not divide the length of `buf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Psend_init` function
+    #[cfg(mpi4)]
+    fn partitioned_send_init_with_tag<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: usize,
+        tag: Tag,
+    ) -> PartitionedRequest<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        let (raw_partitions, count) = partition_counts(buf.len(), partitions);
+        unsafe {
+            PartitionedRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Psend_init(
+                        buf.pointer(),
+                        raw_partitions,
+                        count,
+                        T::equivalent_datatype().as_raw(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                partitions,
+                true,
+                scope,
+            )
+        }
+    }
+
+    /// Create a partitioned request for sending the data in `buf` as `partitions` equally sized
+    /// partitions (MPI-4).
+    ///
+    /// Once the request has been started with
+    /// [`PartitionedRequest::start()`](../request/struct.PartitionedRequest.html#method.start),
+    /// each partition is transferred as soon as it has been marked ready.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `partitions` is 0 or does not divide the length of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Psend_init` function
+    #[cfg(mpi4)]
+    fn partitioned_send_init<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: usize,
+    ) -> PartitionedRequest<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        self.partitioned_send_init_with_tag(scope, buf, partitions, Tag::default())
+    }
 }
 
 impl<'a> Destination for Process<'a> {
@@ -995,6 +1747,53 @@ impl Status {
     pub fn count<D: Datatype>(&self, d: D) -> Count {
         unsafe { with_uninitialized(|count| ffi::MPI_Get_count(&self.0, d.as_raw(), count)).1 }
     }
+
+    /// Number of instances of the type contained in the message, also for messages with more
+    /// instances than fit into a `Count`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5, see the `MPI_Get_count_c` function
+    #[cfg(mpi4)]
+    pub fn large_count<D: Datatype>(&self, d: D) -> LargeCount {
+        unsafe { with_uninitialized(|count| ffi::MPI_Get_count_c(&self.0, d.as_raw(), count)).1 }
+    }
+
+    /// Number of instances of the type contained in the message, also for messages with more
+    /// instances than fit into a `Count`
+    ///
+    /// Before MPI-4, counts beyond the range of `Count` are only available for predefined
+    /// datatypes. Like [`count()`](#method.count), this returns `MPI_UNDEFINED` for larger
+    /// messages of other datatypes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5, 4.1.11, see the `MPI_Get_elements_x` function
+    #[cfg(not(mpi4))]
+    pub fn large_count<D: Datatype>(&self, d: D) -> LargeCount {
+        let count = self.count(&d);
+        if count != ffi::MPI_UNDEFINED {
+            return count.into();
+        }
+        let mut num_integers = 0;
+        let mut num_addresses = 0;
+        let mut num_datatypes = 0;
+        let mut combiner = 0;
+        unsafe {
+            ffi::MPI_Type_get_envelope(
+                d.as_raw(),
+                &mut num_integers,
+                &mut num_addresses,
+                &mut num_datatypes,
+                &mut combiner,
+            );
+            if combiner != ffi::RSMPI_COMBINER_NAMED {
+                return count.into();
+            }
+            // A predefined datatype is its own basic element.
+            with_uninitialized(|count| ffi::MPI_Get_elements_x(&self.0, d.as_raw(), count)).1
+        }
+    }
 }
 
 impl fmt::Debug for Status {
@@ -1062,13 +1861,14 @@ impl Message {
     where
         Buf: BufferMut,
     {
+        let counted = CountedBuffer::new(&*buf);
         let status;
         unsafe {
             status = with_uninitialized(|status| {
-                ffi::MPI_Mrecv(
+                large::MPI_Mrecv(
                     buf.pointer_mut(),
-                    buf.count(),
-                    buf.as_datatype().as_raw(),
+                    counted.count,
+                    counted.datatype(),
                     self.as_raw_mut(),
                     status,
                 )
@@ -1095,12 +1895,13 @@ impl Message {
         Buf: BufferMut,
         Sc: Scope<'a>,
     {
+        let counted = CountedBuffer::new(&*buf);
         unsafe {
             let request = with_uninitialized(|request| {
-                ffi::MPI_Imrecv(
+                large::MPI_Imrecv(
                     buf.pointer_mut(),
-                    buf.count(),
-                    buf.as_datatype().as_raw(),
+                    counted.count,
+                    counted.datatype(),
                     self.as_raw_mut(),
                     request,
                 )
@@ -1276,18 +2077,20 @@ where
             .compare(destination.as_communicator()),
         CommunicatorRelation::Identical
     );
+    let msg_counted = CountedBuffer::new(msg);
+    let buf_counted = CountedBuffer::new(&*buf);
     unsafe {
         Status(
             with_uninitialized(|status| {
-                ffi::MPI_Sendrecv(
+                large::MPI_Sendrecv(
                     msg.pointer(),
-                    msg.count(),
-                    msg.as_datatype().as_raw(),
+                    msg_counted.count,
+                    msg_counted.datatype(),
                     destination.destination_rank(),
                     sendtag,
                     buf.pointer_mut(),
-                    buf.count(),
-                    buf.as_datatype().as_raw(),
+                    buf_counted.count,
+                    buf_counted.datatype(),
                     source.source_rank(),
                     receivetag,
                     source.as_communicator().as_raw(),
@@ -1348,13 +2151,14 @@ where
             .compare(destination.as_communicator()),
         CommunicatorRelation::Identical
     );
+    let counted = CountedBuffer::new(&*buf);
     unsafe {
         Status(
             with_uninitialized(|status| {
-                ffi::MPI_Sendrecv_replace(
+                large::MPI_Sendrecv_replace(
                     buf.pointer_mut(),
-                    buf.count(),
-                    buf.as_datatype().as_raw(),
+                    counted.count,
+                    counted.datatype(),
                     destination.destination_rank(),
                     sendtag,
                     source.source_rank(),
@@ -1391,10 +2195,18 @@ where
 }
 
 /// Will contain a value of type `T` received via a non-blocking receive operation.
+///
+/// The value can be retrieved with [`get()`](#method.get) or [`r#try()`](#method.try), or by
+/// awaiting the future, which is driven in the same way as a
+/// [`RequestFuture`](../request/struct.RequestFuture.html).
+///
+/// # Examples
+///
+/// See `examples/immediate.rs` and `examples/async_request.rs`
 #[must_use]
 pub struct ReceiveFuture<T> {
     val: *mut T,
-    req: Request<'static, ()>,
+    req: RequestFuture<'static, ()>,
 }
 
 impl<T> ReceiveFuture<T>
@@ -1429,3 +2241,20 @@ where
         }
     }
 }
+
+impl<T> Future for ReceiveFuture<T>
+where
+    T: Equivalence,
+{
+    type Output = (T, Status);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<(T, Status)> {
+        let val = self.val;
+        Pin::new(&mut self.req).poll(cx).map(|status| {
+            if status.count(T::equivalent_datatype()) == 0 {
+                panic!("Received an empty message into a ReceiveFuture.");
+            }
+            (unsafe { ptr::read(val) }, status)
+        })
+    }
+}
@@ -19,6 +19,21 @@
 //! follow the respective policy for completing the operation.  When the guard is dropped, the
 //! request will be automatically unregistered from its `Scope`.
 //!
+//! Persistent requests created by the `*_init` methods, e.g.
+//! [`send_init()`](../point_to_point/trait.Destination.html#method.send_init), are represented by
+//! [`PersistentRequest`](struct.PersistentRequest.html).  They are registered with a `Scope` in the
+//! same way, but can be started and completed any number of times before they are freed on drop.
+//! [`PersistentRequestCollection`](struct.PersistentRequestCollection.html) starts and completes a
+//! batch of them at once.  Persistent collective operations with separate send and receive
+//! buffers are represented by
+//! [`PersistentCollectiveRequest`](struct.PersistentCollectiveRequest.html). Partitioned
+//! communications (MPI-4) are represented by
+//! [`PartitionedRequest`](struct.PartitionedRequest.html).
+//!
+//! Requests can also be `.await`ed. The resulting [`RequestFuture`](struct.RequestFuture.html)
+//! stays registered with the `Scope` of the request until the operation has completed, or until
+//! it has been cancelled when the future is dropped early.
+//!
 //! # Unfinished features
 //!
 //! - **3.7**: Nonblocking mode:
@@ -28,15 +43,25 @@
 //!   - Cancellation, `MPI_Test_cancelled()`
 
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
     fmt,
+    future::{Future, IntoFuture},
     marker::PhantomData,
     mem::{self, MaybeUninit},
     os::raw::c_int,
+    pin::Pin,
     ptr,
+    task::{Context, Poll, Waker},
+};
+#[cfg(mpi4)]
+use std::{
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
 };
 
 use crate::{
+    datatype::FixedBuffer,
     ffi,
     ffi::{MPI_Request, MPI_Status},
     point_to_point::Status,
@@ -258,150 +283,1203 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
         }
     }
 
-    /// Test whether an operation has finished.
-    ///
-    /// If the operation has finished, a tuple (`Status`, saved_data) is returned.
-    /// Otherwise returns the unfinished `Request`.
+    /// Test whether an operation has finished.
+    ///
+    /// If the operation has finished, a tuple (`Status`, saved_data) is returned.
+    /// Otherwise returns the unfinished `Request`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test_with_data(self) -> Result<(Status, &'a D), Self> {
+        unsafe {
+            let mut status = MaybeUninit::uninit();
+            let mut request = self.as_raw();
+
+            let (_, flag) =
+                with_uninitialized(|flag| ffi::MPI_Test(&mut request, flag, status.as_mut_ptr()));
+            if flag != 0 {
+                assert!(is_null(request)); // persistent requests are not supported
+                let (_, data, _) = self.into_raw();
+                Ok((Status::from_raw(status.assume_init()), data))
+            } else {
+                Err(self)
+            }
+        }
+    }
+
+    /// Initiate cancellation of the request.
+    ///
+    /// The MPI implementation is not guaranteed to fulfill this operation.  It may not even be
+    /// valid for certain types of requests.  In the future, the MPI forum may [deprecate
+    /// cancellation of sends][mpi26] entirely.
+    ///
+    /// [mpi26]: https://github.com/mpi-forum/mpi-issues/issues/26
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.4
+    pub fn cancel(&self) {
+        let mut request = self.as_raw();
+        unsafe {
+            ffi::MPI_Cancel(&mut request);
+        }
+    }
+
+    /// Reduce the scope of a request.
+    pub fn shrink_scope_to<'b, S2>(self, scope: S2) -> Request<'b, D, S2>
+    where
+        'a: 'b,
+        S2: Scope<'b>,
+    {
+        unsafe {
+            let (request, data, _) = self.into_raw();
+            Request::from_raw(request, data, scope)
+        }
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> IntoFuture for Request<'a, D, S> {
+    type Output = Status;
+    type IntoFuture = RequestFuture<'a, D, S>;
+
+    /// Turns the request into a future that completes along with the operation.
+    fn into_future(self) -> Self::IntoFuture {
+        RequestFuture {
+            request: Some(self),
+            key: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// A future that completes along with a non-blocking operation
+///
+/// Created by awaiting a [`Request`](struct.Request.html). The request stays registered with its
+/// `Scope` until the operation has completed, so the buffers involved are protected in the same
+/// way as before.
+///
+/// MPI cannot notify an executor when an operation completes. Instead, the pending futures of a
+/// thread are registered with a progress reactor that tests all of their requests at once with
+/// `MPI_Testsome()` whenever one of them is polled, and wakes the tasks whose operations have
+/// completed. To keep the operations progressing, the oldest pending future also wakes its own
+/// task, so the executor keeps polling as long as any future on the thread is pending.
+///
+/// The reactor belongs to the thread the future was created on, so the future cannot be sent to
+/// another thread.
+///
+/// Dropping the future before the operation has completed tries to cancel the operation with
+/// `MPI_Cancel()` and waits for it to finish, like a [`CancelGuard`](struct.CancelGuard.html).
+/// Non-blocking collective operations cannot be cancelled, so their futures should be driven to
+/// completion.
+///
+/// # Examples
+///
+/// See `examples/async_request.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.3, 3.7.5
+#[must_use]
+#[derive(Debug)]
+pub struct RequestFuture<'a, D: ?Sized, S: Scope<'a> = StaticScope> {
+    request: Option<Request<'a, D, S>>,
+    key: Option<u64>,
+    phantom: PhantomData<*const ()>,
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> RequestFuture<'a, D, S> {
+    /// Wait for the operation to finish, even if the future has been polled before.
+    pub(crate) fn wait(mut self) -> Status {
+        match self.deregister() {
+            Some(status) => status,
+            None => self
+                .request
+                .take()
+                .expect("rsmpi internal error: RequestFuture without a request")
+                .wait(),
+        }
+    }
+
+    /// Test whether the operation has finished, even if the future has been polled before.
+    pub(crate) fn test(mut self) -> Result<Status, Self> {
+        if let Some(status) = self.deregister() {
+            return Ok(status);
+        }
+        match self
+            .request
+            .take()
+            .expect("rsmpi internal error: RequestFuture without a request")
+            .test()
+        {
+            Ok(status) => Ok(status),
+            Err(request) => {
+                self.request = Some(request);
+                Err(self)
+            }
+        }
+    }
+
+    /// Removes the request from the reactor. Returns its status if the reactor completed it.
+    fn deregister(&mut self) -> Option<Status> {
+        let status = REACTOR.with(|reactor| reactor.borrow_mut().remove(self.key.take()?))?;
+        Some(self.finish(status))
+    }
+
+    /// Unregisters a request that the reactor completed from its scope.
+    fn finish(&mut self, status: MPI_Status) -> Status {
+        let request = self
+            .request
+            .take()
+            .expect("rsmpi internal error: RequestFuture without a request");
+        unsafe {
+            // MPI has already freed the request, only the scope still needs to know.
+            request.into_raw();
+        }
+        Status::from_raw(status)
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> Future for RequestFuture<'a, D, S> {
+    type Output = Status;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Status> {
+        let this = self.get_mut();
+        let request = this
+            .request
+            .as_ref()
+            .expect("RequestFuture polled after completion")
+            .as_raw();
+        let waker = cx.waker().clone();
+        let key = REACTOR.with(|reactor| {
+            let mut reactor = reactor.borrow_mut();
+            match this.key {
+                Some(key) => {
+                    reactor.set_waker(key, waker);
+                    key
+                }
+                None => reactor.register(request, waker),
+            }
+        });
+        this.key = Some(key);
+
+        progress();
+
+        match REACTOR.with(|reactor| reactor.borrow_mut().take_completed(key)) {
+            Some(status) => {
+                this.key = None;
+                Poll::Ready(this.finish(status))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+// The future never relies on its address, only the `Request` inside is needed.
+impl<'a, D: ?Sized, S: Scope<'a>> Unpin for RequestFuture<'a, D, S> {}
+
+impl<'a, D: ?Sized, S: Scope<'a>> Drop for RequestFuture<'a, D, S> {
+    fn drop(&mut self) {
+        if self.deregister().is_some() {
+            return;
+        }
+        // The operation is still pending, so its buffers must stay borrowed until it has finished.
+        if let Some(request) = self.request.take() {
+            request.cancel();
+            request.wait();
+        }
+    }
+}
+
+thread_local! {
+    static REACTOR: RefCell<Reactor> = RefCell::new(Reactor::default());
+}
+
+/// The requests of the pending `RequestFuture`s of a thread
+#[derive(Default)]
+struct Reactor {
+    next_key: u64,
+    pending: BTreeMap<u64, (MPI_Request, Waker)>,
+    completed: BTreeMap<u64, MPI_Status>,
+}
+
+impl Reactor {
+    fn register(&mut self, request: MPI_Request, waker: Waker) -> u64 {
+        let key = self.next_key;
+        self.next_key += 1;
+        self.pending.insert(key, (request, waker));
+        key
+    }
+
+    fn set_waker(&mut self, key: u64, waker: Waker) {
+        if let Some((_, old)) = self.pending.get_mut(&key) {
+            if !old.will_wake(&waker) {
+                *old = waker;
+            }
+        }
+    }
+
+    fn take_completed(&mut self, key: u64) -> Option<MPI_Status> {
+        self.completed.remove(&key)
+    }
+
+    fn remove(&mut self, key: u64) -> Option<MPI_Status> {
+        self.pending.remove(&key);
+        self.completed.remove(&key)
+    }
+
+    /// Tests all pending requests at once and returns the wakers of the tasks to wake.
+    fn progress(&mut self) -> Vec<Waker> {
+        if self.pending.is_empty() {
+            return Vec::new();
+        }
+
+        let keys: Vec<u64> = self.pending.keys().copied().collect();
+        let mut requests: Vec<MPI_Request> =
+            self.pending.values().map(|&(request, _)| request).collect();
+        let mut indices: Vec<c_int> = vec![0; requests.len()];
+        let mut statuses = vec![MaybeUninit::<MPI_Status>::uninit(); requests.len()];
+        let mut outcount: c_int = 0;
+        unsafe {
+            ffi::MPI_Testsome(
+                requests
+                    .len()
+                    .try_into()
+                    .expect("Error while casting usize to i32"),
+                requests.as_mut_ptr(),
+                &mut outcount,
+                indices.as_mut_ptr(),
+                statuses.as_mut_ptr() as *mut MPI_Status,
+            );
+        }
+
+        let mut wakers = Vec::new();
+        if outcount != mpi_sys::MPI_UNDEFINED {
+            let outcount: usize = outcount.try_into().expect("could not cast c_int to usize");
+            // The statuses are stored in the order of `indices`, not at the indices themselves.
+            for (i, &index) in indices[..outcount].iter().enumerate() {
+                let index: usize = index.try_into().expect("could not cast c_int to usize");
+                let (_, waker) = self
+                    .pending
+                    .remove(&keys[index])
+                    .expect("rsmpi internal error: completed request is not pending");
+                self.completed
+                    .insert(keys[index], unsafe { statuses[i].assume_init() });
+                wakers.push(waker);
+            }
+        }
+
+        // Operations only progress while MPI is called, so the oldest pending future keeps its
+        // task awake.
+        if let Some((_, waker)) = self.pending.values().next() {
+            wakers.push(waker.clone());
+        }
+        wakers
+    }
+}
+
+/// Tests the pending requests of this thread and wakes the tasks of the completed ones.
+fn progress() {
+    // Wake after releasing the reactor, in case a waker polls its task right away.
+    let wakers = REACTOR.with(|reactor| reactor.borrow_mut().progress());
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+/// Guard object that waits for the completion of an operation when it is dropped
+///
+/// The guard can be constructed or deconstructed using the `From` and `Into` traits.
+///
+/// # Examples
+///
+/// See `examples/immediate.rs`
+#[derive(Debug)]
+pub struct WaitGuard<'a, D: ?Sized, S: Scope<'a> = StaticScope>(Option<Request<'a, D, S>>);
+
+impl<'a, D: ?Sized, S: Scope<'a>> Drop for WaitGuard<'a, D, S> {
+    fn drop(&mut self) {
+        self.0.take().expect("invalid WaitGuard").wait();
+    }
+}
+
+unsafe impl<'a, D: ?Sized, S: Scope<'a>> AsRaw for WaitGuard<'a, D, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.0.as_ref().expect("invalid WaitGuard").as_raw()
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> From<WaitGuard<'a, D, S>> for Request<'a, D, S> {
+    fn from(mut guard: WaitGuard<'a, D, S>) -> Self {
+        guard.0.take().expect("invalid WaitGuard")
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> From<Request<'a, D, S>> for WaitGuard<'a, D, S> {
+    fn from(req: Request<'a, D, S>) -> Self {
+        WaitGuard(Some(req))
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> WaitGuard<'a, D, S> {
+    fn cancel(&self) {
+        if let Some(ref req) = self.0 {
+            req.cancel();
+        }
+    }
+}
+
+/// Guard object that tries to cancel and waits for the completion of an operation when it is
+/// dropped
+///
+/// The guard can be constructed or deconstructed using the `From` and `Into` traits.
+///
+/// # Examples
+///
+/// See `examples/immediate.rs`
+#[derive(Debug)]
+pub struct CancelGuard<'a, D: ?Sized, S: Scope<'a> = StaticScope>(WaitGuard<'a, D, S>);
+
+impl<'a, D: ?Sized, S: Scope<'a>> Drop for CancelGuard<'a, D, S> {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> From<CancelGuard<'a, D, S>> for WaitGuard<'a, D, S> {
+    fn from(guard: CancelGuard<'a, D, S>) -> Self {
+        unsafe {
+            let inner = ptr::read(&guard.0);
+            mem::forget(guard);
+            inner
+        }
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> From<WaitGuard<'a, D, S>> for CancelGuard<'a, D, S> {
+    fn from(guard: WaitGuard<'a, D, S>) -> Self {
+        CancelGuard(guard)
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> From<Request<'a, D, S>> for CancelGuard<'a, D, S> {
+    fn from(req: Request<'a, D, S>) -> Self {
+        CancelGuard(WaitGuard::from(req))
+    }
+}
+
+/// A persistent request object for a communication that can be started many times, registered
+/// with a `Scope` of lifetime `'a`
+///
+/// A persistent request is created inactive by one of the `*_init` methods, e.g.
+/// [`Destination::send_init()`](../point_to_point/trait.Destination.html#method.send_init). It
+/// becomes active with `start()` and inactive again once the communication has been completed with
+/// `wait()` or `test()`. The buffer associated with the request is borrowed mutably for `'a` so
+/// that its contents can be read or updated through `data()` and `data_mut()` while the request is
+/// inactive.
+///
+/// When the request is dropped, an active communication is waited for and the request is freed.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+#[must_use]
+pub struct PersistentRequest<'a, D: ?Sized, S: Scope<'a> = StaticScope> {
+    request: MPI_Request,
+    data: &'a mut D,
+    active: bool,
+    scope: S,
+    phantom: PhantomData<Cell<&'a ()>>,
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> fmt::Debug for PersistentRequest<'a, D, S>
+where
+    D: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PersistentRequest")
+            .field("request", &self.request)
+            .field("data", &self.data)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+unsafe impl<'a, D: ?Sized, S: Scope<'a>> AsRaw for PersistentRequest<'a, D, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> Drop for PersistentRequest<'a, D, S> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.active {
+                ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+            }
+            ffi::MPI_Request_free(&mut self.request);
+            self.scope.unregister();
+        }
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> PersistentRequest<'a, D, S> {
+    /// Construct a persistent request object from the raw MPI type.
+    ///
+    /// # Requirements
+    ///
+    /// - The request is a valid, inactive persistent request.
+    /// - All buffers associated with the request must outlive `'a`.
+    /// - The request must not be registered with the given scope.
+    ///
+    /// # Safety
+    /// - `request` must be a live MPI object.
+    /// - `request` must not be used after calling `from_raw`.
+    /// - Any buffers owned by `request` must live longer than `scope`.
+    pub unsafe fn from_raw(request: MPI_Request, data: &'a mut D, scope: S) -> Self {
+        debug_assert!(!is_null(request));
+        scope.register();
+        Self {
+            request,
+            data,
+            active: false,
+            scope,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Unregister the inactive request object from its scope and deconstruct it into its raw
+    /// parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    ///
+    /// # Safety
+    /// - The returned `MPI_Request` must be freed within the lifetime of the returned scope.
+    pub unsafe fn into_raw(self) -> (MPI_Request, &'a mut D, S) {
+        assert!(!self.active, "persistent request is still active");
+        let request = ptr::read(&self.request);
+        let data = ptr::read(&self.data);
+        let scope = ptr::read(&self.scope);
+        let _ = ptr::read(&self.phantom);
+        mem::forget(self);
+        scope.unregister();
+        (request, data, scope)
+    }
+
+    /// Whether the communication has been started but not yet completed.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Start the communication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is already active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(&mut self) {
+        assert!(!self.active, "persistent request has already been started");
+        unsafe {
+            ffi::MPI_Start(&mut self.request);
+        }
+        self.active = true;
+    }
+
+    /// Wait for the started communication to finish.
+    ///
+    /// The request becomes inactive and can be started again. Waiting for an inactive request
+    /// returns immediately with an empty status.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 3.9
+    pub fn wait(&mut self) -> Status {
+        let status = unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| ffi::MPI_Wait(&mut self.request, status)).1,
+            )
+        };
+        self.active = false;
+        status
+    }
+
+    /// Wait for the started communication to finish, but don’t bother retrieving the `Status`
+    /// information.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 3.9
+    pub fn wait_without_status(&mut self) {
+        unsafe {
+            ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+        }
+        self.active = false;
+    }
+
+    /// Test whether the started communication has finished.
+    ///
+    /// If it has, the request becomes inactive and the `Status` is returned.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 3.9
+    pub fn test(&mut self) -> Option<Status> {
+        unsafe {
+            let mut status = MaybeUninit::uninit();
+            let (_, flag) = with_uninitialized(|flag| {
+                ffi::MPI_Test(&mut self.request, flag, status.as_mut_ptr())
+            });
+            if flag != 0 {
+                self.active = false;
+                Some(Status::from_raw(status.assume_init()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// The buffer associated with the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    pub fn data(&self) -> &D {
+        assert!(!self.active, "persistent request is still active");
+        &*self.data
+    }
+
+    /// The buffer associated with the request, e.g. to update the data sent by the next `start()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    pub fn data_mut(&mut self) -> &mut D
+    where
+        D: FixedBuffer,
+    {
+        assert!(!self.active, "persistent request is still active");
+        &mut *self.data
+    }
+}
+
+/// A persistent request object for a collective communication with separate send and receive
+/// buffers, registered with a `Scope` of lifetime `'a`
+///
+/// This behaves like a [`PersistentRequest`](struct.PersistentRequest.html) for the receive buffer,
+/// but additionally borrows the send buffer mutably for `'a`, so that the input of the next
+/// `start()` can be updated through `send_data_mut()` while the request is inactive.
+///
+/// # Examples
+///
+/// See `examples/persistent_collectives.rs`
+///
+/// # Standard section(s)
+///
+/// 6.13
+#[must_use]
+pub struct PersistentCollectiveRequest<'a, S: ?Sized, R: ?Sized, Sc: Scope<'a> = StaticScope> {
+    request: PersistentRequest<'a, R, Sc>,
+    send_data: &'a mut S,
+}
+
+impl<'a, S: ?Sized, R: ?Sized, Sc: Scope<'a>> fmt::Debug
+    for PersistentCollectiveRequest<'a, S, R, Sc>
+where
+    S: fmt::Debug,
+    R: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PersistentCollectiveRequest")
+            .field("request", &self.request.request)
+            .field("send_data", &self.send_data)
+            .field("data", &self.request.data)
+            .field("active", &self.request.active)
+            .finish()
+    }
+}
+
+unsafe impl<'a, S: ?Sized, R: ?Sized, Sc: Scope<'a>> AsRaw
+    for PersistentCollectiveRequest<'a, S, R, Sc>
+{
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request.as_raw()
+    }
+}
+
+impl<'a, S: ?Sized, R: ?Sized, Sc: Scope<'a>> PersistentCollectiveRequest<'a, S, R, Sc> {
+    /// Construct a persistent collective request object from the raw MPI type.
+    ///
+    /// # Requirements
+    ///
+    /// - The request is a valid, inactive persistent request.
+    /// - All buffers associated with the request must outlive `'a`.
+    /// - The request must not be registered with the given scope.
+    ///
+    /// # Safety
+    /// - `request` must be a live MPI object.
+    /// - `request` must not be used after calling `from_raw`.
+    /// - Any buffers owned by `request` must live longer than `scope`.
+    pub unsafe fn from_raw(
+        request: MPI_Request,
+        send_data: &'a mut S,
+        data: &'a mut R,
+        scope: Sc,
+    ) -> Self {
+        Self {
+            request: PersistentRequest::from_raw(request, data, scope),
+            send_data,
+        }
+    }
+
+    /// Unregister the inactive request object from its scope and deconstruct it into its raw
+    /// parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    ///
+    /// # Safety
+    /// - The returned `MPI_Request` must be freed within the lifetime of the returned scope.
+    pub unsafe fn into_raw(self) -> (MPI_Request, &'a mut S, &'a mut R, Sc) {
+        let (request, data, scope) = self.request.into_raw();
+        (request, self.send_data, data, scope)
+    }
+
+    /// Whether the communication has been started but not yet completed.
+    pub fn is_active(&self) -> bool {
+        self.request.is_active()
+    }
+
+    /// Start the communication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is already active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9, 6.13
+    pub fn start(&mut self) {
+        self.request.start();
+    }
+
+    /// Wait for the started communication to finish.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 6.13
+    pub fn wait(&mut self) -> Status {
+        self.request.wait()
+    }
+
+    /// Wait for the started communication to finish, but don’t bother retrieving the `Status`
+    /// information.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 6.13
+    pub fn wait_without_status(&mut self) {
+        self.request.wait_without_status();
+    }
+
+    /// Test whether the started communication has finished.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 6.13
+    pub fn test(&mut self) -> Option<Status> {
+        self.request.test()
+    }
+
+    /// The receive buffer associated with the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    pub fn data(&self) -> &R {
+        self.request.data()
+    }
+
+    /// The receive buffer associated with the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    pub fn data_mut(&mut self) -> &mut R
+    where
+        R: FixedBuffer,
+    {
+        self.request.data_mut()
+    }
+
+    /// The send buffer associated with the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    pub fn send_data(&self) -> &S {
+        assert!(!self.is_active(), "persistent request is still active");
+        &*self.send_data
+    }
+
+    /// The send buffer associated with the request, e.g. to update the input of the next
+    /// `start()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collectives.rs`
+    pub fn send_data_mut(&mut self) -> &mut S
+    where
+        S: FixedBuffer,
+    {
+        assert!(!self.is_active(), "persistent request is still active");
+        &mut *self.send_data
+    }
+}
+
+/// A persistent request object for a partitioned communication (MPI-4), registered with a
+/// `Scope` of lifetime `'a`
+///
+/// The buffer of a partitioned request is split into a number of equally sized partitions. Once
+/// the request has been started, the partitions of a send request are marked ready one by one,
+/// e.g. by different threads, and the partitions of a receive request can be used as soon as they
+/// have arrived. The partitions are handed out as disjoint
+/// [`RequestPartition`](struct.RequestPartition.html)s by `partitions_mut()`.
+///
+/// Like a [`PersistentRequest`](struct.PersistentRequest.html), the request is completed with
+/// `wait()` or `test()` and can then be started again. When the request is dropped, an active
+/// communication is waited for, so all partitions of a send request must have been marked ready.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2
+#[cfg(mpi4)]
+#[must_use]
+pub struct PartitionedRequest<'a, T, S: Scope<'a> = StaticScope> {
+    request: PersistentRequest<'a, [T], S>,
+    partitions: usize,
+    send: bool,
+    /// Which partitions of a send request have been marked ready since the request was started
+    ready: Vec<AtomicBool>,
+}
+
+#[cfg(mpi4)]
+impl<'a, T, S: Scope<'a>> fmt::Debug for PartitionedRequest<'a, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PartitionedRequest")
+            .field("request", &self.request.request)
+            .field("data", &self.request.data)
+            .field("partitions", &self.partitions)
+            .field("send", &self.send)
+            .field("active", &self.request.active)
+            .finish()
+    }
+}
+
+#[cfg(mpi4)]
+unsafe impl<'a, T, S: Scope<'a>> AsRaw for PartitionedRequest<'a, T, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request.as_raw()
+    }
+}
+
+#[cfg(mpi4)]
+impl<'a, T, S: Scope<'a>> PartitionedRequest<'a, T, S> {
+    /// Construct a partitioned request object from the raw MPI type.
+    ///
+    /// # Requirements
+    ///
+    /// - The request is a valid, inactive partitioned request for `partitions` partitions of
+    ///   `data`.
+    /// - `send` tells whether the request was created by `MPI_Psend_init()`.
+    /// - All buffers associated with the request must outlive `'a`.
+    /// - The request must not be registered with the given scope.
+    ///
+    /// # Safety
+    /// - `request` must be a live MPI object.
+    /// - `request` must not be used after calling `from_raw`.
+    /// - Any buffers owned by `request` must live longer than `scope`.
+    pub unsafe fn from_raw(
+        request: MPI_Request,
+        data: &'a mut [T],
+        partitions: usize,
+        send: bool,
+        scope: S,
+    ) -> Self {
+        debug_assert_eq!(data.len() % partitions, 0);
+        Self {
+            request: PersistentRequest::from_raw(request, data, scope),
+            partitions,
+            send,
+            ready: (0..partitions).map(|_| AtomicBool::new(false)).collect(),
+        }
+    }
+
+    /// Unregister the inactive request object from its scope and deconstruct it into its raw
+    /// parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is active.
+    ///
+    /// # Safety
+    /// - The returned `MPI_Request` must be freed within the lifetime of the returned scope.
+    pub unsafe fn into_raw(self) -> (MPI_Request, &'a mut [T], S) {
+        self.request.into_raw()
+    }
+
+    /// The number of partitions of the buffer
+    pub fn partitions(&self) -> usize {
+        self.partitions
+    }
+
+    /// Whether the communication has been started but not yet completed.
+    pub fn is_active(&self) -> bool {
+        self.request.is_active()
+    }
+
+    /// Start the communication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is already active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9, 4.2.2
+    pub fn start(&mut self) {
+        self.request.start();
+        for ready in &mut self.ready {
+            *ready.get_mut() = false;
+        }
+    }
+
+    /// Mark partition `partition` of an active send request as ready to be transferred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is not an active send request, `partition` is out of range or
+    /// `partition` has already been marked ready since the request was started.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Pready` function
+    pub fn ready(&self, partition: usize) {
+        assert!(
+            self.send,
+            "only partitions of a send request can be marked ready"
+        );
+        let raw_partition = self.checked_partition(partition);
+        mark_ready(&self.ready[partition], partition);
+        unsafe { pready(raw_partition, self.request.request) }
+    }
+
+    /// Whether partition `partition` of an active receive request has arrived.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is not an active receive request or `partition` is out of range.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Parrived` function
+    pub fn arrived(&self, partition: usize) -> bool {
+        assert!(
+            !self.send,
+            "only partitions of a receive request can arrive"
+        );
+        unsafe { parrived(self.request.request, self.checked_partition(partition)) }
+    }
+
+    /// The partitions of the buffer of the active request, in order.
+    ///
+    /// The partitions are disjoint, so they can be handed out to different threads, which
+    /// requires MPI to be initialized with `Threading::Multiple`. Partitions of a send request
+    /// that have already been marked ready are left out, since MPI may be reading them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request is not active.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    pub fn partitions_mut(&mut self) -> impl Iterator<Item = RequestPartition<'_, T>> {
+        assert!(self.is_active(), "partitioned request has not been started");
+        let request = self.request.request;
+        let send = self.send;
+        let len = self.request.data.len() / self.partitions;
+        self.request
+            .data
+            .chunks_mut(len.max(1))
+            .take(self.partitions)
+            .zip(&self.ready)
+            .enumerate()
+            .filter(move |(_, (_, ready))| !send || !ready.load(Ordering::SeqCst))
+            .map(move |(index, (data, ready))| RequestPartition {
+                request,
+                index,
+                data,
+                send,
+                arrived: send,
+                ready,
+            })
+    }
+
+    /// Wait for the started communication to finish.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate.rs`
+    /// See `examples/partitioned.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.3
-    pub fn test_with_data(self) -> Result<(Status, &'a D), Self> {
-        unsafe {
-            let mut status = MaybeUninit::uninit();
-            let mut request = self.as_raw();
+    /// 3.7.3, 4.2.2
+    pub fn wait(&mut self) -> Status {
+        self.request.wait()
+    }
 
-            let (_, flag) =
-                with_uninitialized(|flag| ffi::MPI_Test(&mut request, flag, status.as_mut_ptr()));
-            if flag != 0 {
-                assert!(is_null(request)); // persistent requests are not supported
-                let (_, data, _) = self.into_raw();
-                Ok((Status::from_raw(status.assume_init()), data))
-            } else {
-                Err(self)
-            }
-        }
+    /// Wait for the started communication to finish, but don’t bother retrieving the `Status`
+    /// information.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 4.2.2
+    pub fn wait_without_status(&mut self) {
+        self.request.wait_without_status();
     }
 
-    /// Initiate cancellation of the request.
+    /// Test whether the started communication has finished.
     ///
-    /// The MPI implementation is not guaranteed to fulfill this operation.  It may not even be
-    /// valid for certain types of requests.  In the future, the MPI forum may [deprecate
-    /// cancellation of sends][mpi26] entirely.
+    /// # Standard section(s)
     ///
-    /// [mpi26]: https://github.com/mpi-forum/mpi-issues/issues/26
+    /// 3.7.3, 4.2.2
+    pub fn test(&mut self) -> Option<Status> {
+        self.request.test()
+    }
+
+    /// The buffer associated with the request.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// See `examples/immediate.rs`
+    /// Panics if the request is active.
+    pub fn data(&self) -> &[T] {
+        self.request.data()
+    }
+
+    /// The buffer associated with the request, e.g. to prepare the data of all partitions before
+    /// the next `start()`.
     ///
-    /// # Standard section(s)
+    /// # Panics
     ///
-    /// 3.8.4
-    pub fn cancel(&self) {
-        let mut request = self.as_raw();
-        unsafe {
-            ffi::MPI_Cancel(&mut request);
-        }
+    /// Panics if the request is active.
+    pub fn data_mut(&mut self) -> &mut [T] {
+        assert!(!self.is_active(), "persistent request is still active");
+        &mut *self.request.data
     }
 
-    /// Reduce the scope of a request.
-    pub fn shrink_scope_to<'b, S2>(self, scope: S2) -> Request<'b, D, S2>
-    where
-        'a: 'b,
-        S2: Scope<'b>,
-    {
-        unsafe {
-            let (request, data, _) = self.into_raw();
-            Request::from_raw(request, data, scope)
-        }
+    fn checked_partition(&self, partition: usize) -> c_int {
+        assert!(self.is_active(), "partitioned request has not been started");
+        assert!(
+            partition < self.partitions,
+            "partition {} is out of range for {} partitions",
+            partition,
+            self.partitions
+        );
+        partition
+            .try_into()
+            .expect("Partition index cannot be expressed as an int.")
     }
 }
 
-/// Guard object that waits for the completion of an operation when it is dropped
+/// A partition of the buffer of an active [`PartitionedRequest`](struct.PartitionedRequest.html)
 ///
-/// The guard can be constructed or deconstructed using the `From` and `Into` traits.
+/// The partition of a send request can be written to until it is marked ready with `ready()`. The
+/// partition of a receive request can be accessed once `arrived()` has returned `true`. A send
+/// request only completes once all of its partitions have been marked ready.
 ///
 /// # Examples
 ///
-/// See `examples/immediate.rs`
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2
+#[cfg(mpi4)]
+#[must_use]
 #[derive(Debug)]
-pub struct WaitGuard<'a, D: ?Sized, S: Scope<'a> = StaticScope>(Option<Request<'a, D, S>>);
-
-impl<'a, D: ?Sized, S: Scope<'a>> Drop for WaitGuard<'a, D, S> {
-    fn drop(&mut self) {
-        self.0.take().expect("invalid WaitGuard").wait();
-    }
+pub struct RequestPartition<'p, T> {
+    request: MPI_Request,
+    index: usize,
+    data: &'p mut [T],
+    send: bool,
+    arrived: bool,
+    ready: &'p AtomicBool,
 }
 
-unsafe impl<'a, D: ?Sized, S: Scope<'a>> AsRaw for WaitGuard<'a, D, S> {
-    type Raw = MPI_Request;
-    fn as_raw(&self) -> Self::Raw {
-        self.0.as_ref().expect("invalid WaitGuard").as_raw()
-    }
-}
+// Each partition only accesses its own part of the buffer and MPI is thread-safe for a request
+// that is used by multiple threads with `Threading::Multiple`.
+#[cfg(mpi4)]
+unsafe impl<'p, T: Send> Send for RequestPartition<'p, T> {}
 
-impl<'a, D: ?Sized, S: Scope<'a>> From<WaitGuard<'a, D, S>> for Request<'a, D, S> {
-    fn from(mut guard: WaitGuard<'a, D, S>) -> Self {
-        guard.0.take().expect("invalid WaitGuard")
+#[cfg(mpi4)]
+impl<'p, T> RequestPartition<'p, T> {
+    /// The index of the partition within the buffer
+    pub fn index(&self) -> usize {
+        self.index
     }
-}
 
-impl<'a, D: ?Sized, S: Scope<'a>> From<Request<'a, D, S>> for WaitGuard<'a, D, S> {
-    fn from(req: Request<'a, D, S>) -> Self {
-        WaitGuard(Some(req))
+    /// Mark the partition of a send request as ready to be transferred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the partition belongs to a receive request.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Pready` function
+    pub fn ready(self) {
+        assert!(
+            self.send,
+            "only partitions of a send request can be marked ready"
+        );
+        let partition = self
+            .index
+            .try_into()
+            .expect("Partition index cannot be expressed as an int.");
+        mark_ready(self.ready, self.index);
+        unsafe { pready(partition, self.request) }
     }
-}
 
-impl<'a, D: ?Sized, S: Scope<'a>> WaitGuard<'a, D, S> {
-    fn cancel(&self) {
-        if let Some(ref req) = self.0 {
-            req.cancel();
+    /// Whether the partition of a receive request has arrived.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the partition belongs to a send request.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2, see the `MPI_Parrived` function
+    pub fn arrived(&mut self) -> bool {
+        assert!(
+            !self.send,
+            "only partitions of a receive request can arrive"
+        );
+        if !self.arrived {
+            let partition = self
+                .index
+                .try_into()
+                .expect("Partition index cannot be expressed as an int.");
+            self.arrived = unsafe { parrived(self.request, partition) };
         }
+        self.arrived
     }
 }
 
-/// Guard object that tries to cancel and waits for the completion of an operation when it is
-/// dropped
-///
-/// The guard can be constructed or deconstructed using the `From` and `Into` traits.
-///
-/// # Examples
-///
-/// See `examples/immediate.rs`
-#[derive(Debug)]
-pub struct CancelGuard<'a, D: ?Sized, S: Scope<'a> = StaticScope>(WaitGuard<'a, D, S>);
+#[cfg(mpi4)]
+impl<'p, T> Deref for RequestPartition<'p, T> {
+    type Target = [T];
 
-impl<'a, D: ?Sized, S: Scope<'a>> Drop for CancelGuard<'a, D, S> {
-    fn drop(&mut self) {
-        self.0.cancel();
+    /// # Panics
+    ///
+    /// Panics if the partition of a receive request has not arrived yet.
+    fn deref(&self) -> &[T] {
+        assert!(self.arrived, "partition has not arrived yet");
+        self.data
     }
 }
 
-impl<'a, D: ?Sized, S: Scope<'a>> From<CancelGuard<'a, D, S>> for WaitGuard<'a, D, S> {
-    fn from(guard: CancelGuard<'a, D, S>) -> Self {
-        unsafe {
-            let inner = ptr::read(&guard.0);
-            mem::forget(guard);
-            inner
-        }
+#[cfg(mpi4)]
+impl<'p, T> DerefMut for RequestPartition<'p, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        assert!(self.arrived, "partition has not arrived yet");
+        self.data
     }
 }
 
-impl<'a, D: ?Sized, S: Scope<'a>> From<WaitGuard<'a, D, S>> for CancelGuard<'a, D, S> {
-    fn from(guard: WaitGuard<'a, D, S>) -> Self {
-        CancelGuard(guard)
-    }
+/// Record that `partition` is marked ready, which must happen at most once per start.
+#[cfg(mpi4)]
+fn mark_ready(ready: &AtomicBool, partition: usize) {
+    assert!(
+        !ready.swap(true, Ordering::SeqCst),
+        "partition {} has already been marked ready",
+        partition
+    );
 }
 
-impl<'a, D: ?Sized, S: Scope<'a>> From<Request<'a, D, S>> for CancelGuard<'a, D, S> {
-    fn from(req: Request<'a, D, S>) -> Self {
-        CancelGuard(WaitGuard::from(req))
-    }
+/// Mark `partition` of the active send request `request` as ready.
+#[cfg(mpi4)]
+unsafe fn pready(partition: c_int, request: MPI_Request) {
+    ffi::MPI_Pready(partition, request);
+}
+
+/// Whether `partition` of the active receive request `request` has arrived.
+#[cfg(mpi4)]
+unsafe fn parrived(request: MPI_Request, partition: c_int) -> bool {
+    with_uninitialized(|flag| ffi::MPI_Parrived(request, partition, flag)).1 != 0
 }
 
 /// A common interface for [`LocalScope`](struct.LocalScope.html) and
@@ -765,3 +1843,224 @@ impl<'a, D: ?Sized> Drop for RequestCollection<'a, D> {
         }
     }
 }
+
+/// Collection of persistent requests that are started and completed together
+///
+/// Requests keep their registration with their `Scope` while they are part of the collection.
+/// When the collection is dropped, all active communications are waited for and all requests are
+/// freed.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub struct PersistentRequestCollection<'a, D: ?Sized, S: Scope<'a> = StaticScope> {
+    /// Array of requests
+    requests: Vec<MPI_Request>,
+    /// List of data buffers attached to each request
+    data: Vec<&'a mut D>,
+    /// Scope each request is registered with
+    scopes: Vec<S>,
+    /// Request statuses
+    statuses: Vec<MaybeUninit<MPI_Status>>,
+    /// Whether the requests have been started and not yet completed
+    active: bool,
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> Default for PersistentRequestCollection<'a, D, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> PersistentRequestCollection<'a, D, S> {
+    /// Create an empty collection.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty collection with room for `capacity` requests.
+    pub fn with_capacity(capacity: usize) -> Self {
+        PersistentRequestCollection {
+            requests: Vec::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+            scopes: Vec::with_capacity(capacity),
+            statuses: Vec::with_capacity(capacity),
+            active: false,
+        }
+    }
+
+    /// The number of requests in the collection
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Whether the collection contains no requests
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Whether the requests have been started but not yet completed.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Add an inactive request to the collection and return its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collection or the request is active.
+    pub fn add(&mut self, req: PersistentRequest<'a, D, S>) -> usize {
+        assert!(!self.active, "persistent requests are still active");
+        let i = self.requests.len();
+        // Keep the request registered with its scope until the collection frees it.
+        let (request, data, scope) = unsafe { req.into_raw() };
+        scope.register();
+        self.requests.push(request);
+        self.data.push(data);
+        self.scopes.push(scope);
+        self.statuses.push(MaybeUninit::uninit());
+        i
+    }
+
+    /// Start all requests in the collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requests have already been started.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start_all(&mut self) {
+        assert!(
+            !self.active,
+            "persistent requests have already been started"
+        );
+        unsafe {
+            ffi::MPI_Startall(
+                self.requests
+                    .len()
+                    .try_into()
+                    .expect("could not cast usize to c_int"),
+                self.requests.as_mut_ptr(),
+            );
+        }
+        self.active = true;
+    }
+
+    /// Wait for all started requests to complete, putting (request_index, status) into result for
+    /// every request.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5, 3.9
+    pub fn wait_all(&mut self, result: &mut Vec<(usize, Status)>) {
+        unsafe {
+            ffi::MPI_Waitall(
+                self.requests
+                    .len()
+                    .try_into()
+                    .expect("could not cast usize to c_int"),
+                self.requests.as_mut_ptr(),
+                self.statuses.as_mut_ptr() as *mut MPI_Status,
+            );
+        }
+        self.active = false;
+
+        result.clear();
+        result.reserve(self.requests.len());
+        for (i, status) in self.statuses.iter().enumerate() {
+            result.push((i, Status::from_raw(unsafe { status.assume_init() })));
+        }
+    }
+
+    /// Test for the completion of all started requests. If all have completed, (request_index,
+    /// status) is put into result for every request and `true` is returned.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5, 3.9
+    pub fn test_all(&mut self, result: &mut Vec<(usize, Status)>) -> bool {
+        let mut flag = 0;
+        unsafe {
+            ffi::MPI_Testall(
+                self.requests
+                    .len()
+                    .try_into()
+                    .expect("could not cast usize to c_int"),
+                self.requests.as_mut_ptr(),
+                &mut flag,
+                self.statuses.as_mut_ptr() as *mut MPI_Status,
+            );
+        }
+
+        result.clear();
+        if flag != 0 {
+            self.active = false;
+            result.reserve(self.requests.len());
+            for (i, status) in self.statuses.iter().enumerate() {
+                result.push((i, Status::from_raw(unsafe { status.assume_init() })));
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The buffer associated with request `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requests are active.
+    pub fn data(&self, i: usize) -> &D {
+        assert!(!self.active, "persistent requests are still active");
+        &*self.data[i]
+    }
+
+    /// The buffer associated with request `i`, e.g. to update the data sent by the next
+    /// `start_all()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requests are active.
+    pub fn data_mut(&mut self, i: usize) -> &mut D
+    where
+        D: FixedBuffer,
+    {
+        assert!(!self.active, "persistent requests are still active");
+        &mut *self.data[i]
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> Drop for PersistentRequestCollection<'a, D, S> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.active {
+                ffi::MPI_Waitall(
+                    self.requests
+                        .len()
+                        .try_into()
+                        .expect("could not cast usize to c_int"),
+                    self.requests.as_mut_ptr(),
+                    ffi::RSMPI_STATUSES_IGNORE,
+                );
+            }
+            for (request, scope) in self.requests.iter_mut().zip(&self.scopes) {
+                ffi::MPI_Request_free(request);
+                scope.unregister();
+            }
+        }
+    }
+}
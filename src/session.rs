@@ -0,0 +1,284 @@
+//! The sessions model (MPI-4)
+//!
+//! A [`Session`](struct.Session.html) is an isolated handle to the MPI library. Unlike
+//! [`initialize()`](../environment/fn.initialize.html), creating a session does not initialize
+//! the world model, so a library can use MPI without taking ownership of `MPI_COMM_WORLD` from the
+//! application that hosts it. Any number of sessions can exist at the same time, alongside or
+//! without the world model.
+//!
+//! Processes are discovered through named process sets, e.g. `"mpi://WORLD"` and `"mpi://SELF"`.
+//! A group created from a process set can be turned into a communicator with
+//! [`SimpleCommunicator::from_group()`](../topology/struct.SimpleCommunicator.html#method.from_group).
+//!
+//! Datatypes of `#[derive(Equivalence)]` can be used while a session is alive. Since they are
+//! cached for the rest of the program, the first one that is built without the world model starts
+//! an internal session that is never finalized, so MPI remains initialized from then on.
+//!
+//! # Unfinished features
+//!
+//! - **9.3**: Error handlers for sessions, `MPI_Session_create_errhandler()`,
+//! `MPI_Session_set_errhandler()`, `MPI_Session_call_errhandler()`
+
+use std::{
+    ffi::CString,
+    os::raw::{c_char, c_int},
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+#[cfg(feature = "derive")]
+use std::{mem, sync::Once};
+
+use conv::ConvUtil;
+
+use crate::{
+    environment::Threading, ffi, ffi::MPI_Session, info::Info, raw::traits::*, topology::UserGroup,
+    with_uninitialized, MpiError,
+};
+
+/// The number of sessions that have been initialized and not yet finalized
+static LIVE_SESSIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// An MPI session
+///
+/// The session is finalized when it is dropped. Groups and communicators derived from the session
+/// must be dropped before the session.
+///
+/// # Examples
+///
+/// See `examples/session.rs`
+///
+/// # Standard section(s)
+///
+/// 11.3
+pub struct Session(MPI_Session);
+
+impl Session {
+    /// Initialize a new session.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/session.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_init` function
+    pub fn init() -> Self {
+        Self::init_with_info(&Info::new())
+    }
+
+    /// Initialize a new session with hints in `info`, e.g. `thread_level`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_init` function
+    pub fn init_with_info(info: &Info) -> Self {
+        let session = Session(unsafe {
+            with_uninitialized(|session| {
+                ffi::MPI_Session_init(info.as_raw(), ffi::RSMPI_ERRORS_ARE_FATAL, session)
+            })
+            .1
+        });
+        LIVE_SESSIONS.fetch_add(1, Ordering::SeqCst);
+        session
+    }
+
+    /// Initialize a new session with the desired level of multithreading support.
+    ///
+    /// Returns the session and the level of multithreading actually provided for it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_init` function
+    pub fn init_with_threading(threading: Threading) -> (Self, Threading) {
+        let info = Info::builder()
+            .set("thread_level", threading_name(threading))
+            .build()
+            .expect("rsmpi internal error: invalid thread_level hint");
+        let session = Self::init_with_info(&info);
+        let provided = session
+            .get_info()
+            .get("thread_level")
+            .and_then(|level| threading_from_name(&level))
+            .unwrap_or(Threading::Single);
+        (session, provided)
+    }
+
+    /// The hints that are actually used by MPI for this session, including `thread_level`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_get_info` function
+    pub fn get_info(&self) -> Info {
+        unsafe {
+            Info::from_raw(
+                with_uninitialized(|info_used| ffi::MPI_Session_get_info(self.0, info_used)).1,
+            )
+        }
+    }
+
+    /// The number of process sets available in this session
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2, see the `MPI_Session_get_num_psets` function
+    pub fn num_psets(&self) -> usize {
+        let (_, num_psets) = unsafe {
+            with_uninitialized(|num_psets| {
+                ffi::MPI_Session_get_num_psets(self.0, ffi::RSMPI_INFO_NULL, num_psets)
+            })
+        };
+        num_psets
+            .value_as()
+            .expect("Received unexpected value from MPI_Session_get_num_psets")
+    }
+
+    /// The name of the process set with index `n`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2, see the `MPI_Session_get_nth_pset` function
+    pub fn pset_name(&self, n: usize) -> String {
+        let n: c_int = n
+            .value_as()
+            .expect("Process set index exceeds the range of int.");
+
+        // Query the length of the name, including the terminating null character, first.
+        let mut len: c_int = 0;
+        unsafe {
+            ffi::MPI_Session_get_nth_pset(
+                self.0,
+                ffi::RSMPI_INFO_NULL,
+                n,
+                &mut len,
+                ptr::null_mut(),
+            );
+        }
+
+        let mut buf = vec![
+            0u8;
+            len.value_as::<usize>()
+                .expect("Received unexpected value from MPI_Session_get_nth_pset")
+        ];
+        unsafe {
+            ffi::MPI_Session_get_nth_pset(
+                self.0,
+                ffi::RSMPI_INFO_NULL,
+                n,
+                &mut len,
+                buf.as_mut_ptr() as *mut c_char,
+            );
+        }
+        let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        buf.truncate(end);
+        String::from_utf8_lossy(&buf).into_owned()
+    }
+
+    /// The names of all process sets available in this session, e.g. `"mpi://WORLD"`
+    ///
+    /// # Examples
+    ///
+    /// See `examples/session.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2
+    pub fn psets(&self) -> Vec<String> {
+        (0..self.num_psets()).map(|n| self.pset_name(n)).collect()
+    }
+
+    /// Information about the process set `name`, e.g. its `mpi_size`.
+    ///
+    /// Returns an `Err` if `name` contains an interior 0 byte.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2, see the `MPI_Session_get_pset_info` function
+    pub fn pset_info(&self, name: &str) -> Result<Info, MpiError> {
+        let name = CString::new(name)?;
+        Ok(unsafe {
+            Info::from_raw(
+                with_uninitialized(|info| {
+                    ffi::MPI_Session_get_pset_info(self.0, name.as_ptr(), info)
+                })
+                .1,
+            )
+        })
+    }
+
+    /// Creates a group of the processes in the process set `name`.
+    ///
+    /// Returns an `Err` if `name` contains an interior 0 byte.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/session.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.3.2, see the `MPI_Group_from_session_pset` function
+    pub fn group_from_pset(&self, name: &str) -> Result<UserGroup, MpiError> {
+        let name = CString::new(name)?;
+        Ok(unsafe {
+            UserGroup::from_raw(
+                with_uninitialized(|group| {
+                    ffi::MPI_Group_from_session_pset(self.0, name.as_ptr(), group)
+                })
+                .1,
+            )
+        })
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Session_finalize(&mut self.0);
+        }
+        assert_eq!(self.0, unsafe { ffi::RSMPI_SESSION_NULL });
+        LIVE_SESSIONS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+unsafe impl AsRaw for Session {
+    type Raw = MPI_Session;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+/// Keeps MPI initialized for the datatypes of `#[derive(Equivalence)]` if a session is alive.
+///
+/// The datatypes are cached for the rest of the program and must not be invalidated when the
+/// application finalizes its last session, so an internal session is initialized and leaked.
+/// Returns `false` if no session is alive.
+#[cfg(feature = "derive")]
+pub(crate) fn retain_for_derived_datatypes() -> bool {
+    static RETAINED: Once = Once::new();
+
+    if LIVE_SESSIONS.load(Ordering::SeqCst) == 0 {
+        return false;
+    }
+    RETAINED.call_once(|| mem::forget(Session::init()));
+    true
+}
+
+/// The value of the `thread_level` hint for `threading`
+fn threading_name(threading: Threading) -> &'static str {
+    match threading {
+        Threading::Single => "MPI_THREAD_SINGLE",
+        Threading::Funneled => "MPI_THREAD_FUNNELED",
+        Threading::Serialized => "MPI_THREAD_SERIALIZED",
+        Threading::Multiple => "MPI_THREAD_MULTIPLE",
+    }
+}
+
+fn threading_from_name(name: &str) -> Option<Threading> {
+    [
+        Threading::Single,
+        Threading::Funneled,
+        Threading::Serialized,
+        Threading::Multiple,
+    ]
+    .into_iter()
+    .find(|&threading| threading_name(threading) == name)
+}
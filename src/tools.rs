@@ -0,0 +1,922 @@
+//! The MPI tool information interface
+//!
+//! Implementations of MPI expose internal settings, e.g. eager limits or the algorithms used by
+//! collective operations, as *control variables* and internal measurements, e.g. the number of
+//! unexpected messages, as *performance variables*. Which variables exist depends on the MPI
+//! library, its version and how it was configured, so they are discovered at run time through
+//! [`Tools`](struct.Tools.html).
+//!
+//! The tool information interface is initialized independently of MPI and can be used before
+//! [`initialize()`](../environment/fn.initialize.html) and after the `Universe` is dropped.
+//!
+//! MS-MPI does not implement the tool information interface, so this module is not available there.
+//!
+//! # Unfinished features
+//!
+//! - **15.3.6**: `MPI_T_cvar_get_index()` is used, but not `MPI_T_pvar_get_index()`
+//! - **15.3.7**: `MPI_T_pvar_write()`, `MPI_T_pvar_readreset()`, `MPI_T_PVAR_ALL_HANDLES`
+//! - **15.3.8**: Variable categorization, `MPI_T_category_get_info()`, ...
+//! - **15.3.2**: Variables bound to objects other than communicators
+
+use std::{
+    ffi::CString,
+    marker::PhantomData,
+    os::raw::{c_char, c_int, c_uint, c_ulong, c_ulonglong, c_void},
+    ptr,
+};
+
+use conv::ConvUtil;
+
+use crate::{
+    check_error,
+    environment::Threading,
+    ffi,
+    ffi::{MPI_Datatype, MPI_T_cvar_handle, MPI_T_enum, MPI_T_pvar_handle, MPI_T_pvar_session},
+    topology::Communicator,
+    traits::AsRaw,
+    with_uninitialized, with_uninitialized2, Error, MpiError,
+};
+
+/// An initialized tool information interface
+///
+/// The interface is finalized when this value is dropped. It may be initialized several times,
+/// in which case it is only finalized when the last `Tools` value is dropped.
+///
+/// # Examples
+///
+/// See `examples/tools.rs`
+///
+/// # Standard section(s)
+///
+/// 15.3.4
+pub struct Tools(());
+
+impl Tools {
+    /// Initialize the tool information interface.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.4, see the `MPI_T_init_thread` function
+    pub fn init() -> Result<Self, MpiError> {
+        Self::init_with_threading(Threading::Single).map(|(tools, _)| tools)
+    }
+
+    /// Initialize the tool information interface with the desired level of multithreading support.
+    ///
+    /// Returns the interface and the level of multithreading actually provided for it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.4, see the `MPI_T_init_thread` function
+    pub fn init_with_threading(threading: Threading) -> Result<(Self, Threading), MpiError> {
+        let (code, provided) = unsafe {
+            with_uninitialized(|provided| ffi::MPI_T_init_thread(threading.as_raw(), provided))
+        };
+        check_error(code)?;
+        Ok((Tools(()), provided.into()))
+    }
+
+    /// The number of control variables
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_get_num` function
+    pub fn num_cvars(&self) -> usize {
+        let (_, num_cvar) =
+            unsafe { with_uninitialized(|num_cvar| ffi::MPI_T_cvar_get_num(num_cvar)) };
+        num_cvar
+            .value_as()
+            .expect("Received unexpected value from MPI_T_cvar_get_num")
+    }
+
+    /// Describes the control variable with index `index`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_get_info` function
+    pub fn cvar(&self, index: usize) -> Result<ControlVariable, MpiError> {
+        let raw_index = raw_index(index);
+        let mut verbosity: c_int = 0;
+        let mut datatype: MPI_Datatype = unsafe { ffi::RSMPI_DATATYPE_NULL };
+        let mut enumtype: MPI_T_enum = unsafe { ffi::RSMPI_T_ENUM_NULL };
+        let mut bind: c_int = 0;
+        let mut scope: c_int = 0;
+        let (name, description) = with_strings(|name, name_len, desc, desc_len| unsafe {
+            ffi::MPI_T_cvar_get_info(
+                raw_index,
+                name,
+                name_len,
+                &mut verbosity,
+                &mut datatype,
+                &mut enumtype,
+                desc,
+                desc_len,
+                &mut bind,
+                &mut scope,
+            )
+        })?;
+
+        Ok(ControlVariable {
+            index,
+            name,
+            description,
+            verbosity,
+            value_type: ValueType::from_raw(datatype),
+            enumeration: enumeration(enumtype)?,
+            binding: Binding::from_raw(bind),
+            scope,
+        })
+    }
+
+    /// Describes all control variables.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/tools.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6
+    pub fn cvars(&self) -> Result<Vec<ControlVariable>, MpiError> {
+        (0..self.num_cvars())
+            .map(|index| self.cvar(index))
+            .collect()
+    }
+
+    /// Describes the control variable called `name`, or returns `None` if there is no such
+    /// variable.
+    ///
+    /// Returns an `Err` if `name` contains an interior 0 byte.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_get_index` function
+    pub fn find_cvar(&self, name: &str) -> Result<Option<ControlVariable>, MpiError> {
+        let name = CString::new(name)?;
+        let (code, index) =
+            unsafe { with_uninitialized(|index| ffi::MPI_T_cvar_get_index(name.as_ptr(), index)) };
+        if code == ffi::MPI_T_ERR_INVALID_NAME as Error {
+            return Ok(None);
+        }
+        check_error(code)?;
+        let index = index
+            .value_as()
+            .expect("Received unexpected value from MPI_T_cvar_get_index");
+        self.cvar(index).map(Some)
+    }
+
+    /// Creates a handle for reading and writing `cvar`, which must not be bound to an MPI object.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/tools.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_handle_alloc` function
+    pub fn cvar_handle(
+        &self,
+        cvar: &ControlVariable,
+    ) -> Result<ControlVariableHandle<'_>, MpiError> {
+        if cvar.binding != Binding::NoObject {
+            return Err(invalid());
+        }
+        self.cvar_handle_with(cvar, ptr::null_mut())
+    }
+
+    /// Creates a handle for reading and writing `cvar` of the communicator `comm`.
+    ///
+    /// `cvar` must be bound to communicators. The handle borrows `comm`, since it must not
+    /// outlive the communicator.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_handle_alloc` function
+    pub fn cvar_handle_for_communicator<'a, C: Communicator + ?Sized>(
+        &'a self,
+        cvar: &ControlVariable,
+        comm: &'a C,
+    ) -> Result<ControlVariableHandle<'a>, MpiError> {
+        if cvar.binding != Binding::Communicator {
+            return Err(invalid());
+        }
+        let mut raw_comm = comm.as_raw();
+        self.cvar_handle_with(cvar, ptr::addr_of_mut!(raw_comm).cast())
+    }
+
+    fn cvar_handle_with(
+        &self,
+        cvar: &ControlVariable,
+        obj_handle: *mut c_void,
+    ) -> Result<ControlVariableHandle<'_>, MpiError> {
+        let (code, raw, count) = unsafe {
+            with_uninitialized2(|handle, count| {
+                ffi::MPI_T_cvar_handle_alloc(raw_index(cvar.index), obj_handle, handle, count)
+            })
+        };
+        check_error(code)?;
+        Ok(ControlVariableHandle {
+            raw,
+            value_type: cvar.value_type,
+            count: raw_count(count),
+            tools: PhantomData,
+        })
+    }
+
+    /// The number of performance variables
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_get_num` function
+    pub fn num_pvars(&self) -> usize {
+        let (_, num_pvar) =
+            unsafe { with_uninitialized(|num_pvar| ffi::MPI_T_pvar_get_num(num_pvar)) };
+        num_pvar
+            .value_as()
+            .expect("Received unexpected value from MPI_T_pvar_get_num")
+    }
+
+    /// Describes the performance variable with index `index`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_get_info` function
+    pub fn pvar(&self, index: usize) -> Result<PerformanceVariable, MpiError> {
+        let raw_index = raw_index(index);
+        let mut verbosity: c_int = 0;
+        let mut var_class: c_int = 0;
+        let mut datatype: MPI_Datatype = unsafe { ffi::RSMPI_DATATYPE_NULL };
+        let mut enumtype: MPI_T_enum = unsafe { ffi::RSMPI_T_ENUM_NULL };
+        let mut bind: c_int = 0;
+        let mut readonly: c_int = 0;
+        let mut continuous: c_int = 0;
+        let mut atomic: c_int = 0;
+        let (name, description) = with_strings(|name, name_len, desc, desc_len| unsafe {
+            ffi::MPI_T_pvar_get_info(
+                raw_index,
+                name,
+                name_len,
+                &mut verbosity,
+                &mut var_class,
+                &mut datatype,
+                &mut enumtype,
+                desc,
+                desc_len,
+                &mut bind,
+                &mut readonly,
+                &mut continuous,
+                &mut atomic,
+            )
+        })?;
+
+        Ok(PerformanceVariable {
+            index,
+            name,
+            description,
+            verbosity,
+            class: var_class,
+            value_type: ValueType::from_raw(datatype),
+            enumeration: enumeration(enumtype)?,
+            binding: Binding::from_raw(bind),
+            read_only: readonly != 0,
+            continuous: continuous != 0,
+            atomic: atomic != 0,
+        })
+    }
+
+    /// Describes all performance variables.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/tools.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7
+    pub fn pvars(&self) -> Result<Vec<PerformanceVariable>, MpiError> {
+        (0..self.num_pvars())
+            .map(|index| self.pvar(index))
+            .collect()
+    }
+
+    /// Creates a session in which performance variables can be started, stopped and read
+    /// independently of other sessions.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/tools.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_session_create` function
+    pub fn pvar_session(&self) -> Result<PerformanceSession<'_>, MpiError> {
+        let (code, raw) =
+            unsafe { with_uninitialized(|session| ffi::MPI_T_pvar_session_create(session)) };
+        check_error(code)?;
+        Ok(PerformanceSession {
+            raw,
+            tools: PhantomData,
+        })
+    }
+}
+
+impl Drop for Tools {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_T_finalize();
+        }
+    }
+}
+
+/// The type of the values of a control or performance variable
+///
+/// # Standard section(s)
+///
+/// 15.3.5
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValueType {
+    /// `MPI_INT`, also used for enumerations
+    Int,
+    /// `MPI_UNSIGNED`
+    Unsigned,
+    /// `MPI_UNSIGNED_LONG`
+    UnsignedLong,
+    /// `MPI_UNSIGNED_LONG_LONG`
+    UnsignedLongLong,
+    /// `MPI_COUNT`
+    Count,
+    /// `MPI_CHAR`, a string
+    Char,
+    /// `MPI_DOUBLE`
+    Double,
+    /// Another datatype, e.g. `MPI_LONG` or `MPI_INT64_T`. Variables of such types cannot be read
+    /// or written.
+    Other(MPI_Datatype),
+}
+
+impl ValueType {
+    fn from_raw(datatype: MPI_Datatype) -> Self {
+        unsafe {
+            if datatype == ffi::RSMPI_INT {
+                ValueType::Int
+            } else if datatype == ffi::RSMPI_UNSIGNED {
+                ValueType::Unsigned
+            } else if datatype == ffi::RSMPI_UNSIGNED_LONG {
+                ValueType::UnsignedLong
+            } else if datatype == ffi::RSMPI_UNSIGNED_LONG_LONG {
+                ValueType::UnsignedLongLong
+            } else if datatype == ffi::RSMPI_COUNT {
+                ValueType::Count
+            } else if datatype == ffi::RSMPI_CHAR {
+                ValueType::Char
+            } else if datatype == ffi::RSMPI_DOUBLE {
+                ValueType::Double
+            } else {
+                ValueType::Other(datatype)
+            }
+        }
+    }
+}
+
+/// A value read from or written to a control or performance variable
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A value of type [`ValueType::Int`](enum.ValueType.html#variant.Int)
+    Int(c_int),
+    /// A value of type [`ValueType::Unsigned`](enum.ValueType.html#variant.Unsigned)
+    Unsigned(c_uint),
+    /// A value of type [`ValueType::UnsignedLong`](enum.ValueType.html#variant.UnsignedLong)
+    UnsignedLong(c_ulong),
+    /// A value of type
+    /// [`ValueType::UnsignedLongLong`](enum.ValueType.html#variant.UnsignedLongLong)
+    UnsignedLongLong(c_ulonglong),
+    /// A value of type [`ValueType::Count`](enum.ValueType.html#variant.Count)
+    Count(ffi::MPI_Count),
+    /// The value of a variable of type [`ValueType::Char`](enum.ValueType.html#variant.Char)
+    String(String),
+    /// A value of type [`ValueType::Double`](enum.ValueType.html#variant.Double)
+    Double(f64),
+}
+
+/// The kind of MPI object a variable is bound to
+///
+/// # Standard section(s)
+///
+/// 15.3.2
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Binding {
+    /// The variable applies to the whole MPI library.
+    NoObject,
+    /// The variable has a value per communicator.
+    Communicator,
+    /// The variable is bound to another kind of object, identified by its `MPI_T_BIND_` constant.
+    Other(c_int),
+}
+
+impl Binding {
+    fn from_raw(bind: c_int) -> Self {
+        if bind == unsafe { ffi::RSMPI_T_BIND_NO_OBJECT } {
+            Binding::NoObject
+        } else if bind == unsafe { ffi::RSMPI_T_BIND_MPI_COMM } {
+            Binding::Communicator
+        } else {
+            Binding::Other(bind)
+        }
+    }
+}
+
+/// Describes a control variable
+///
+/// # Standard section(s)
+///
+/// 15.3.6
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ControlVariable {
+    /// The index of the variable, between 0 and `Tools::num_cvars()`
+    pub index: usize,
+    /// The name of the variable, unique among control variables
+    pub name: String,
+    /// A description of the variable
+    pub description: String,
+    /// The verbosity level of the variable, one of the `MPI_T_VERBOSITY_` constants
+    pub verbosity: c_int,
+    /// The type of the values of the variable
+    pub value_type: ValueType,
+    /// The `(value, name)` pairs of an enumeration variable, empty for other variables
+    pub enumeration: Vec<(c_int, String)>,
+    /// The kind of MPI object the variable is bound to
+    pub binding: Binding,
+    /// Where and when the variable may be changed, one of the `MPI_T_SCOPE_` constants
+    pub scope: c_int,
+}
+
+impl ControlVariable {
+    /// Returns `true` if the variable cannot be written.
+    pub fn is_read_only(&self) -> bool {
+        self.scope == unsafe { ffi::RSMPI_T_SCOPE_CONSTANT }
+            || self.scope == unsafe { ffi::RSMPI_T_SCOPE_READONLY }
+    }
+}
+
+/// A handle for reading and writing a control variable
+///
+/// # Examples
+///
+/// See `examples/tools.rs`
+///
+/// # Standard section(s)
+///
+/// 15.3.6
+pub struct ControlVariableHandle<'t> {
+    raw: MPI_T_cvar_handle,
+    value_type: ValueType,
+    count: usize,
+    tools: PhantomData<&'t Tools>,
+}
+
+impl<'t> ControlVariableHandle<'t> {
+    /// The number of values of the variable, or the maximum length of its value including the
+    /// terminating null character if it is of type `ValueType::Char`
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Reads the values of the variable.
+    ///
+    /// Variables of type `ValueType::Char` are read as a single `Value::String`. Returns an `Err`
+    /// for variables of type `ValueType::Other`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_read` function
+    pub fn read(&self) -> Result<Vec<Value>, MpiError> {
+        read_values(self.value_type, self.count, |buf| unsafe {
+            ffi::MPI_T_cvar_read(self.raw, buf)
+        })
+    }
+
+    /// Writes the values of the variable.
+    ///
+    /// Returns an `Err` if `values` do not match the type and count of the variable, if the
+    /// variable is of type `ValueType::Other`, or if MPI does not allow the variable to be
+    /// changed, e.g. because it is read-only or because it can only be changed before MPI is
+    /// initialized.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.6, see the `MPI_T_cvar_write` function
+    pub fn write(&self, values: &[Value]) -> Result<(), MpiError> {
+        write_values(self.value_type, self.count, values, |buf| unsafe {
+            ffi::MPI_T_cvar_write(self.raw, buf)
+        })
+    }
+}
+
+impl<'t> Drop for ControlVariableHandle<'t> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_T_cvar_handle_free(&mut self.raw);
+        }
+        assert_eq!(self.raw, unsafe { ffi::RSMPI_T_CVAR_HANDLE_NULL });
+    }
+}
+
+unsafe impl<'t> AsRaw for ControlVariableHandle<'t> {
+    type Raw = MPI_T_cvar_handle;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+/// Describes a performance variable
+///
+/// # Standard section(s)
+///
+/// 15.3.7
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PerformanceVariable {
+    /// The index of the variable, between 0 and `Tools::num_pvars()`
+    pub index: usize,
+    /// The name of the variable, unique among performance variables of the same class
+    pub name: String,
+    /// A description of the variable
+    pub description: String,
+    /// The verbosity level of the variable, one of the `MPI_T_VERBOSITY_` constants
+    pub verbosity: c_int,
+    /// What the variable measures, one of the `MPI_T_PVAR_CLASS_` constants
+    pub class: c_int,
+    /// The type of the values of the variable
+    pub value_type: ValueType,
+    /// The `(value, name)` pairs of an enumeration variable, empty for other variables
+    pub enumeration: Vec<(c_int, String)>,
+    /// The kind of MPI object the variable is bound to
+    pub binding: Binding,
+    /// Whether the variable cannot be written or reset
+    pub read_only: bool,
+    /// Whether the variable is always active and cannot be started or stopped
+    pub continuous: bool,
+    /// Whether the variable can be read and reset atomically
+    pub atomic: bool,
+}
+
+/// A session for using performance variables
+///
+/// Performance variables started in one session do not affect their values in other sessions.
+///
+/// # Examples
+///
+/// See `examples/tools.rs`
+///
+/// # Standard section(s)
+///
+/// 15.3.7
+pub struct PerformanceSession<'t> {
+    raw: MPI_T_pvar_session,
+    tools: PhantomData<&'t Tools>,
+}
+
+impl<'t> PerformanceSession<'t> {
+    /// Creates a handle for using `pvar` in this session, which must not be bound to an MPI
+    /// object.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/tools.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_handle_alloc` function
+    pub fn handle(
+        &self,
+        pvar: &PerformanceVariable,
+    ) -> Result<PerformanceVariableHandle<'_>, MpiError> {
+        if pvar.binding != Binding::NoObject {
+            return Err(invalid());
+        }
+        self.handle_with(pvar, ptr::null_mut())
+    }
+
+    /// Creates a handle for using `pvar` of the communicator `comm` in this session.
+    ///
+    /// `pvar` must be bound to communicators. The handle borrows `comm`, since it must not
+    /// outlive the communicator.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_handle_alloc` function
+    pub fn handle_for_communicator<'a, C: Communicator + ?Sized>(
+        &'a self,
+        pvar: &PerformanceVariable,
+        comm: &'a C,
+    ) -> Result<PerformanceVariableHandle<'a>, MpiError> {
+        if pvar.binding != Binding::Communicator {
+            return Err(invalid());
+        }
+        let mut raw_comm = comm.as_raw();
+        self.handle_with(pvar, ptr::addr_of_mut!(raw_comm).cast())
+    }
+
+    fn handle_with(
+        &self,
+        pvar: &PerformanceVariable,
+        obj_handle: *mut c_void,
+    ) -> Result<PerformanceVariableHandle<'_>, MpiError> {
+        let (code, raw, count) = unsafe {
+            with_uninitialized2(|handle, count| {
+                ffi::MPI_T_pvar_handle_alloc(
+                    self.raw,
+                    raw_index(pvar.index),
+                    obj_handle,
+                    handle,
+                    count,
+                )
+            })
+        };
+        check_error(code)?;
+        Ok(PerformanceVariableHandle {
+            raw,
+            session: self,
+            value_type: pvar.value_type,
+            count: raw_count(count),
+        })
+    }
+}
+
+impl<'t> Drop for PerformanceSession<'t> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_T_pvar_session_free(&mut self.raw);
+        }
+        assert_eq!(self.raw, unsafe { ffi::RSMPI_T_PVAR_SESSION_NULL });
+    }
+}
+
+unsafe impl<'t> AsRaw for PerformanceSession<'t> {
+    type Raw = MPI_T_pvar_session;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+/// A handle for using a performance variable in a session
+///
+/// # Examples
+///
+/// See `examples/tools.rs`
+///
+/// # Standard section(s)
+///
+/// 15.3.7
+pub struct PerformanceVariableHandle<'s> {
+    raw: MPI_T_pvar_handle,
+    session: &'s PerformanceSession<'s>,
+    value_type: ValueType,
+    count: usize,
+}
+
+impl<'s> PerformanceVariableHandle<'s> {
+    /// The number of values of the variable, or the maximum length of its value including the
+    /// terminating null character if it is of type `ValueType::Char`
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Starts measuring. Continuous variables cannot be started.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_start` function
+    pub fn start(&self) -> Result<(), MpiError> {
+        check_error(unsafe { ffi::MPI_T_pvar_start(self.session.raw, self.raw) })
+    }
+
+    /// Stops measuring. Continuous variables cannot be stopped.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_stop` function
+    pub fn stop(&self) -> Result<(), MpiError> {
+        check_error(unsafe { ffi::MPI_T_pvar_stop(self.session.raw, self.raw) })
+    }
+
+    /// Resets the variable to its starting value. Read-only variables cannot be reset.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_reset` function
+    pub fn reset(&self) -> Result<(), MpiError> {
+        check_error(unsafe { ffi::MPI_T_pvar_reset(self.session.raw, self.raw) })
+    }
+
+    /// Reads the values of the variable in this session.
+    ///
+    /// Variables of type `ValueType::Char` are read as a single `Value::String`. Returns an `Err`
+    /// for variables of type `ValueType::Other`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 15.3.7, see the `MPI_T_pvar_read` function
+    pub fn read(&self) -> Result<Vec<Value>, MpiError> {
+        read_values(self.value_type, self.count, |buf| unsafe {
+            ffi::MPI_T_pvar_read(self.session.raw, self.raw, buf)
+        })
+    }
+}
+
+impl<'s> Drop for PerformanceVariableHandle<'s> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_T_pvar_handle_free(self.session.raw, &mut self.raw);
+        }
+        assert_eq!(self.raw, unsafe { ffi::RSMPI_T_PVAR_HANDLE_NULL });
+    }
+}
+
+unsafe impl<'s> AsRaw for PerformanceVariableHandle<'s> {
+    type Raw = MPI_T_pvar_handle;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+/// The error returned for arguments that do not match a variable
+fn invalid() -> MpiError {
+    MpiError::from_code(ffi::MPI_T_ERR_INVALID as Error)
+}
+
+fn raw_index(index: usize) -> c_int {
+    index
+        .value_as()
+        .expect("Variable index exceeds the range of int.")
+}
+
+fn raw_count(count: c_int) -> usize {
+    count
+        .value_as()
+        .expect("Received unexpected value from the MPI tool information interface")
+}
+
+fn string_from_buf(mut buf: Vec<u8>) -> String {
+    let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    buf.truncate(end);
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
+/// Calls `query` once to find the lengths of a name and a description, including the
+/// terminating null characters, and again to fetch them.
+fn with_strings<F>(mut query: F) -> Result<(String, String), MpiError>
+where
+    F: FnMut(*mut c_char, *mut c_int, *mut c_char, *mut c_int) -> Error,
+{
+    let mut name_len: c_int = 0;
+    let mut desc_len: c_int = 0;
+    check_error(query(
+        ptr::null_mut(),
+        &mut name_len,
+        ptr::null_mut(),
+        &mut desc_len,
+    ))?;
+
+    let mut name = vec![0u8; raw_count(name_len)];
+    let mut desc = vec![0u8; raw_count(desc_len)];
+    check_error(query(
+        name.as_mut_ptr() as *mut c_char,
+        &mut name_len,
+        desc.as_mut_ptr() as *mut c_char,
+        &mut desc_len,
+    ))?;
+    Ok((string_from_buf(name), string_from_buf(desc)))
+}
+
+/// Like `with_strings`, for queries that return only a name
+fn with_string<F>(mut query: F) -> Result<String, MpiError>
+where
+    F: FnMut(*mut c_char, *mut c_int) -> Error,
+{
+    let mut len: c_int = 0;
+    check_error(query(ptr::null_mut(), &mut len))?;
+
+    let mut buf = vec![0u8; raw_count(len)];
+    check_error(query(buf.as_mut_ptr() as *mut c_char, &mut len))?;
+    Ok(string_from_buf(buf))
+}
+
+/// The `(value, name)` pairs of `enumtype`, or an empty list for `MPI_T_ENUM_NULL`
+fn enumeration(enumtype: MPI_T_enum) -> Result<Vec<(c_int, String)>, MpiError> {
+    if enumtype == unsafe { ffi::RSMPI_T_ENUM_NULL } {
+        return Ok(Vec::new());
+    }
+
+    let mut num: c_int = 0;
+    with_string(|name, name_len| unsafe {
+        ffi::MPI_T_enum_get_info(enumtype, &mut num, name, name_len)
+    })?;
+
+    (0..num)
+        .map(|index| {
+            let mut value: c_int = 0;
+            let name = with_string(|name, name_len| unsafe {
+                ffi::MPI_T_enum_get_item(enumtype, index, &mut value, name, name_len)
+            })?;
+            Ok((value, name))
+        })
+        .collect()
+}
+
+fn read_values<F>(value_type: ValueType, count: usize, read: F) -> Result<Vec<Value>, MpiError>
+where
+    F: FnOnce(*mut c_void) -> Error,
+{
+    match value_type {
+        ValueType::Int => read_as(count, read, Value::Int),
+        ValueType::Unsigned => read_as(count, read, Value::Unsigned),
+        ValueType::UnsignedLong => read_as(count, read, Value::UnsignedLong),
+        ValueType::UnsignedLongLong => read_as(count, read, Value::UnsignedLongLong),
+        ValueType::Count => read_as(count, read, Value::Count),
+        ValueType::Double => read_as(count, read, Value::Double),
+        ValueType::Char => {
+            let mut buf = vec![0u8; count];
+            check_error(read(buf.as_mut_ptr().cast()))?;
+            Ok(vec![Value::String(string_from_buf(buf))])
+        }
+        ValueType::Other(_) => Err(invalid()),
+    }
+}
+
+fn read_as<T, F>(count: usize, read: F, wrap: fn(T) -> Value) -> Result<Vec<Value>, MpiError>
+where
+    T: Copy + Default,
+    F: FnOnce(*mut c_void) -> Error,
+{
+    let mut buf = vec![T::default(); count];
+    check_error(read(buf.as_mut_ptr().cast()))?;
+    Ok(buf.into_iter().map(wrap).collect())
+}
+
+fn write_values<F>(
+    value_type: ValueType,
+    count: usize,
+    values: &[Value],
+    write: F,
+) -> Result<(), MpiError>
+where
+    F: FnOnce(*const c_void) -> Error,
+{
+    if value_type == ValueType::Char {
+        return match values {
+            [Value::String(value)] if value.len() < count => {
+                let value = CString::new(value.as_str())?;
+                check_error(write(value.as_ptr().cast()))
+            }
+            _ => Err(invalid()),
+        };
+    }
+
+    if values.len() != count {
+        return Err(invalid());
+    }
+    match value_type {
+        ValueType::Int => write_as(values, write, |value| match *value {
+            Value::Int(value) => Some(value),
+            _ => None,
+        }),
+        ValueType::Unsigned => write_as(values, write, |value| match *value {
+            Value::Unsigned(value) => Some(value),
+            _ => None,
+        }),
+        ValueType::UnsignedLong => write_as(values, write, |value| match *value {
+            Value::UnsignedLong(value) => Some(value),
+            _ => None,
+        }),
+        ValueType::UnsignedLongLong => write_as(values, write, |value| match *value {
+            Value::UnsignedLongLong(value) => Some(value),
+            _ => None,
+        }),
+        ValueType::Count => write_as(values, write, |value| match *value {
+            Value::Count(value) => Some(value),
+            _ => None,
+        }),
+        ValueType::Double => write_as(values, write, |value| match *value {
+            Value::Double(value) => Some(value),
+            _ => None,
+        }),
+        ValueType::Other(_) => Err(invalid()),
+        ValueType::Char => unreachable!(),
+    }
+}
+
+fn write_as<T, F>(
+    values: &[Value],
+    write: F,
+    unwrap: fn(&Value) -> Option<T>,
+) -> Result<(), MpiError>
+where
+    F: FnOnce(*const c_void) -> Error,
+{
+    let buf = values
+        .iter()
+        .map(unwrap)
+        .collect::<Option<Vec<T>>>()
+        .ok_or_else(invalid)?;
+    check_error(write(buf.as_ptr().cast()))
+}
@@ -0,0 +1,370 @@
+use std::mem;
+
+use conv::ConvUtil;
+
+use super::{sealed, AsCommunicator, Communicator, IntoTopology, Rank};
+use crate::{
+    ffi, ffi::MPI_Comm, raw::traits::*, topology::SimpleCommunicator, with_uninitialized,
+    with_uninitialized2, Count,
+};
+
+/// Contains arrays describing the layout of the
+/// [`GraphCommunicator`](struct.GraphCommunicator.html).
+///
+/// The neighbors of node `i` are `edges[index[i - 1]..index[i]]`, where `index[-1]` is taken to
+/// be `0`.
+pub struct GraphLayout {
+    /// `index[i]` is the total number of neighbors of the nodes `0` to `i`
+    pub index: Vec<Count>,
+    /// The concatenated lists of neighbors of all nodes
+    pub edges: Vec<Rank>,
+}
+
+/// A `GraphCommunicator` is an MPI communicator object where ranks are the nodes of a general
+/// graph that is known in full on every process.
+///
+/// # Standard Section(s)
+///
+/// 7
+pub struct GraphCommunicator(pub(crate) SimpleCommunicator);
+
+impl GraphCommunicator {
+    /// Given a valid `MPI_Comm` handle in `raw`, returns a `GraphCommunicator` value if, and only
+    /// if:
+    /// - The handle is not `MPI_COMM_NULL`
+    /// - The topology of the communicator is `MPI_GRAPH`
+    ///
+    /// Otherwise returns None.
+    ///
+    /// # Parameters
+    /// * `raw` - Handle to a valid `MPI_Comm` object
+    ///
+    /// # Safety
+    /// - `raw` must be a live MPI_Comm handle.
+    /// - `raw` must not be a system communicator handle.
+    /// - `raw` must not be a inter-communicator handle.
+    /// - `raw` must not be used after calling this function.
+    pub unsafe fn try_from_raw(raw: MPI_Comm) -> Option<GraphCommunicator> {
+        SimpleCommunicator::try_from_raw(raw).and_then(|comm| match comm.into_topology() {
+            IntoTopology::Graph(c) => Some(c),
+            incorrect => {
+                // Forget the comm object so it's not dropped
+                mem::forget(incorrect);
+
+                None
+            }
+        })
+    }
+
+    /// Returns the number of nodes and the number of edges of the graph.
+    ///
+    /// # Standard section(s)
+    /// 7.5.5 (MPI_Graphdims_get)
+    pub fn num_nodes_and_edges(&self) -> (Count, Count) {
+        unsafe {
+            let (_, nnodes, nedges) = with_uninitialized2(|nnodes, nedges| {
+                ffi::MPI_Graphdims_get(self.as_raw(), nnodes, nedges)
+            });
+            (nnodes, nedges)
+        }
+    }
+
+    /// Returns the graph structure of the communicator.
+    ///
+    /// # Standard section(s)
+    /// 7.5.5 (MPI_Graph_get)
+    pub fn get_layout(&self) -> GraphLayout {
+        let (nnodes, nedges) = self.num_nodes_and_edges();
+
+        let mut layout = GraphLayout {
+            index: vec![
+                0;
+                nnodes
+                    .value_as()
+                    .expect("Received unexpected value from MPI_Graphdims_get")
+            ],
+            edges: vec![
+                0;
+                nedges
+                    .value_as()
+                    .expect("Received unexpected value from MPI_Graphdims_get")
+            ],
+        };
+
+        unsafe {
+            ffi::MPI_Graph_get(
+                self.as_raw(),
+                nnodes,
+                nedges,
+                layout.index.as_mut_ptr(),
+                layout.edges.as_mut_ptr(),
+            );
+        }
+
+        layout
+    }
+
+    /// Returns the number of neighbors of `rank`.
+    ///
+    /// Panics if `rank` is not a non-negative value less than
+    /// [`size`](trait.Communicator.html#method.size).
+    ///
+    /// # Standard section(s)
+    /// 7.5.5 (MPI_Graph_neighbors_count)
+    pub fn num_neighbors(&self, rank: Rank) -> Count {
+        assert!(
+            rank >= 0 && rank < self.size(),
+            "rank ({}) must be in the range [0,{})",
+            rank,
+            self.size()
+        );
+
+        unsafe {
+            with_uninitialized(|nneighbors| {
+                ffi::MPI_Graph_neighbors_count(self.as_raw(), rank, nneighbors)
+            })
+            .1
+        }
+    }
+
+    /// Returns the neighbors of `rank` in the order in which they were given when the graph was
+    /// created.
+    ///
+    /// Panics if `rank` is not a non-negative value less than
+    /// [`size`](trait.Communicator.html#method.size).
+    ///
+    /// # Standard section(s)
+    /// 7.5.5 (MPI_Graph_neighbors)
+    pub fn neighbors(&self, rank: Rank) -> Vec<Rank> {
+        let nneighbors = self.num_neighbors(rank);
+
+        let mut neighbors = vec![
+            0;
+            nneighbors.value_as().expect(
+                "Received unexpected value from MPI_Graph_neighbors_count"
+            )
+        ];
+
+        unsafe {
+            ffi::MPI_Graph_neighbors(self.as_raw(), rank, nneighbors, neighbors.as_mut_ptr());
+        }
+
+        neighbors
+    }
+}
+
+impl Communicator for GraphCommunicator {
+    fn target_size(&self) -> Rank {
+        self.size()
+    }
+}
+
+impl sealed::AsHandle for GraphCommunicator {
+    fn as_handle(&self) -> &sealed::CommunicatorHandle {
+        self.0.as_handle()
+    }
+}
+
+impl AsCommunicator for GraphCommunicator {
+    type Out = GraphCommunicator;
+    fn as_communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+unsafe impl AsRaw for GraphCommunicator {
+    type Raw = MPI_Comm;
+    fn as_raw(&self) -> Self::Raw {
+        self.0.as_raw()
+    }
+}
+
+impl FromRaw for GraphCommunicator {
+    /// Creates a `GraphCommunicator` from `raw`.
+    ///
+    /// # Parameters
+    /// * `raw` - Handle to a valid `MPI_GRAPH` `MPI_Comm` object
+    ///
+    /// # Safety
+    /// - `raw` must be a live MPI_Comm handle
+    /// - `raw` must not be an inter-comm handle, the parent handle, or a system handle
+    /// - `raw` must not be used after calling this function.
+    unsafe fn from_raw(raw: <Self as AsRaw>::Raw) -> Self {
+        debug_assert_ne!(raw, ffi::RSMPI_COMM_NULL);
+        GraphCommunicator(SimpleCommunicator::from_raw(raw))
+    }
+}
+
+/// The neighbors of the calling process in a
+/// [`DistributedGraphCommunicator`](struct.DistributedGraphCommunicator.html).
+///
+/// The weights are `None` if the graph was created without weights.
+pub struct DistributedGraphNeighbors {
+    /// The processes for which the calling process is a destination
+    pub sources: Vec<Rank>,
+    /// `source_weights[i]` is the weight of the edge from `sources[i]`
+    pub source_weights: Option<Vec<Count>>,
+    /// The processes for which the calling process is a source
+    pub destinations: Vec<Rank>,
+    /// `destination_weights[i]` is the weight of the edge to `destinations[i]`
+    pub destination_weights: Option<Vec<Count>>,
+}
+
+/// A `DistributedGraphCommunicator` is an MPI communicator object where ranks are the nodes of a
+/// directed graph, and each process only knows about its own incoming and outgoing edges.
+///
+/// # Standard Section(s)
+///
+/// 7
+pub struct DistributedGraphCommunicator(pub(crate) SimpleCommunicator);
+
+impl DistributedGraphCommunicator {
+    /// Given a valid `MPI_Comm` handle in `raw`, returns a `DistributedGraphCommunicator` value
+    /// if, and only if:
+    /// - The handle is not `MPI_COMM_NULL`
+    /// - The topology of the communicator is `MPI_DIST_GRAPH`
+    ///
+    /// Otherwise returns None.
+    ///
+    /// # Parameters
+    /// * `raw` - Handle to a valid `MPI_Comm` object
+    ///
+    /// # Safety
+    /// - `raw` must be a live MPI_Comm handle.
+    /// - `raw` must not be a system communicator handle.
+    /// - `raw` must not be a inter-communicator handle.
+    /// - `raw` must not be used after calling this function.
+    pub unsafe fn try_from_raw(raw: MPI_Comm) -> Option<DistributedGraphCommunicator> {
+        SimpleCommunicator::try_from_raw(raw).and_then(|comm| match comm.into_topology() {
+            IntoTopology::DistributedGraph(c) => Some(c),
+            incorrect => {
+                // Forget the comm object so it's not dropped
+                mem::forget(incorrect);
+
+                None
+            }
+        })
+    }
+
+    /// Returns the number of incoming and outgoing edges of the calling process and whether the
+    /// graph was created with weights, as `(indegree, outdegree, weighted)`.
+    ///
+    /// # Standard section(s)
+    /// 7.5.5 (MPI_Dist_graph_neighbors_count)
+    pub fn num_neighbors(&self) -> (Count, Count, bool) {
+        let mut indegree = 0;
+        let mut outdegree = 0;
+        let mut weighted = 0;
+        unsafe {
+            ffi::MPI_Dist_graph_neighbors_count(
+                self.as_raw(),
+                &mut indegree,
+                &mut outdegree,
+                &mut weighted,
+            );
+        }
+        (indegree, outdegree, weighted != 0)
+    }
+
+    /// Returns the sources and destinations of the edges adjacent to the calling process,
+    /// together with their weights if the graph is weighted.
+    ///
+    /// # Standard section(s)
+    /// 7.5.5 (MPI_Dist_graph_neighbors)
+    pub fn neighbors(&self) -> DistributedGraphNeighbors {
+        let (indegree, outdegree, weighted) = self.num_neighbors();
+        let indegree_len: usize = indegree
+            .value_as()
+            .expect("Received unexpected value from MPI_Dist_graph_neighbors_count");
+        let outdegree_len: usize = outdegree
+            .value_as()
+            .expect("Received unexpected value from MPI_Dist_graph_neighbors_count");
+
+        let mut sources = vec![0; indegree_len];
+        let mut destinations = vec![0; outdegree_len];
+        let mut source_weights = vec![0; if weighted { indegree_len } else { 0 }];
+        let mut destination_weights = vec![0; if weighted { outdegree_len } else { 0 }];
+
+        unsafe {
+            ffi::MPI_Dist_graph_neighbors(
+                self.as_raw(),
+                indegree,
+                sources.as_mut_ptr(),
+                if weighted {
+                    source_weights.as_mut_ptr()
+                } else {
+                    ffi::RSMPI_UNWEIGHTED()
+                },
+                outdegree,
+                destinations.as_mut_ptr(),
+                if weighted {
+                    destination_weights.as_mut_ptr()
+                } else {
+                    ffi::RSMPI_UNWEIGHTED()
+                },
+            );
+        }
+
+        DistributedGraphNeighbors {
+            sources,
+            source_weights: if weighted { Some(source_weights) } else { None },
+            destinations,
+            destination_weights: if weighted {
+                Some(destination_weights)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl Communicator for DistributedGraphCommunicator {
+    fn target_size(&self) -> Rank {
+        self.size()
+    }
+}
+
+impl sealed::AsHandle for DistributedGraphCommunicator {
+    fn as_handle(&self) -> &sealed::CommunicatorHandle {
+        self.0.as_handle()
+    }
+}
+
+impl AsCommunicator for DistributedGraphCommunicator {
+    type Out = DistributedGraphCommunicator;
+    fn as_communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+unsafe impl AsRaw for DistributedGraphCommunicator {
+    type Raw = MPI_Comm;
+    fn as_raw(&self) -> Self::Raw {
+        self.0.as_raw()
+    }
+}
+
+impl FromRaw for DistributedGraphCommunicator {
+    /// Creates a `DistributedGraphCommunicator` from `raw`.
+    ///
+    /// # Parameters
+    /// * `raw` - Handle to a valid `MPI_DIST_GRAPH` `MPI_Comm` object
+    ///
+    /// # Safety
+    /// - `raw` must be a live MPI_Comm handle
+    /// - `raw` must not be an inter-comm handle, the parent handle, or a system handle
+    /// - `raw` must not be used after calling this function.
+    unsafe fn from_raw(raw: <Self as AsRaw>::Raw) -> Self {
+        debug_assert_ne!(raw, ffi::RSMPI_COMM_NULL);
+        DistributedGraphCommunicator(SimpleCommunicator::from_raw(raw))
+    }
+}
+
+/// Returns the raw weights pointer for an optional array of edge weights.
+pub(crate) fn weights_ptr(weights: Option<&[Count]>) -> *const Count {
+    match weights {
+        None => unsafe { ffi::RSMPI_UNWEIGHTED() },
+        Some([]) => unsafe { ffi::RSMPI_WEIGHTS_EMPTY() },
+        Some(weights) => weights.as_ptr(),
+    }
+}
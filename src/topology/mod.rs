@@ -8,18 +8,18 @@
 //!
 //! # Unfinished features
 //!
+//! - **4.2**: Large-count packing, `MPI_Pack_c()`, `MPI_Unpack_c()`, `MPI_Pack_size_c()`
 //! - **6.3**: Group management
 //!   - **6.3.2**: Constructors, `MPI_Group_range_incl()`, `MPI_Group_range_excl()`
 //! - **6.4**: Communicator management
-//!   - **6.4.2**: Constructors, `MPI_Comm_dup_with_info()`, `MPI_Comm_idup()`,
-//!     `MPI_Comm_split_type()`
-//!   - **6.4.4**: Info, `MPI_Comm_set_info()`, `MPI_Comm_get_info()`
+//!   - **6.4.2**: Constructors, `MPI_Comm_idup()`
 //! - **6.6**: Inter-communication
 //! - **6.7**: Caching
 //! - **6.8**: Naming objects
-//! - **7**: Process topologies
+//! - **7**: Process topologies, `MPI_Dims_create()`
 //! - **Parts of sections**: 8, 10, 12
 use std::{
+    borrow::Cow,
     ffi::{CStr, CString},
     mem::MaybeUninit,
     os::raw::{c_char, c_int, c_void},
@@ -33,21 +33,25 @@ use crate::Tag;
 use crate::{
     attribute::CommAttribute,
     datatype::traits::*,
+    environment::ErrorHandler,
     ffi,
     ffi::{MPI_Comm, MPI_Group},
+    info::Info,
     raw::traits::*,
-    with_uninitialized, Count, IntArray,
+    with_uninitialized, Count, Error, IntArray, MpiError,
 };
 
 mod cartesian;
+mod graph;
 
 /// Topology traits
 pub mod traits {
     pub use super::{AnyCommunicator, AsCommunicator, Communicator, Group};
 }
 
-// Re-export cartesian functions and types from topology modules.
+// Re-export cartesian and graph functions and types from topology modules.
 pub use self::cartesian::*;
+pub use self::graph::*;
 
 /// Something that has a communicator associated with it
 pub trait AsCommunicator {
@@ -85,6 +89,40 @@ impl SimpleCommunicator {
         SimpleCommunicator(sealed::CommunicatorHandle::SelfComm)
     }
 
+    /// Creates a communicator of the processes in `group` without a parent communicator, e.g.
+    /// from a group of a [`Session`](../session/struct.Session.html) process set.
+    ///
+    /// `tag` distinguishes concurrent calls on overlapping groups and must be the same on all
+    /// processes of `group`. This is a collective operation on the processes of `group`, all of
+    /// which have to call it.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/session.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.4.2, see the `MPI_Comm_create_from_group` function
+    #[cfg(mpi4)]
+    pub fn from_group<G: Group + ?Sized>(group: &G, tag: &str) -> SimpleCommunicator {
+        let tag = CString::new(tag).expect("Failed to convert the Rust string to a C string");
+        unsafe {
+            SimpleCommunicator::try_from_raw(
+                with_uninitialized(|newcomm| {
+                    ffi::MPI_Comm_create_from_group(
+                        group.as_raw(),
+                        tag.as_ptr(),
+                        ffi::RSMPI_INFO_NULL,
+                        ffi::RSMPI_ERRORS_ARE_FATAL,
+                        newcomm,
+                    )
+                })
+                .1,
+            )
+            .expect("rsmpi internal error: MPI implementation incorrectly returned MPI_COMM_NULL from MPI_Comm_create_from_group")
+        }
+    }
+
     /// If the raw value is the null handle returns `None`, otherwise it tries to create a
     /// `SimpleCommunicator` from the raw value. Returns `None` if the raw value is an
     /// inter-communicator.
@@ -132,9 +170,11 @@ impl SimpleCommunicator {
     /// 7.5.5
     pub fn into_topology(self) -> IntoTopology {
         match self.topology() {
-            Topology::Graph => unimplemented!(),
+            Topology::Graph => IntoTopology::Graph(GraphCommunicator(self)),
             Topology::Cartesian => IntoTopology::Cartesian(CartesianCommunicator(self)),
-            Topology::DistributedGraph => unimplemented!(),
+            Topology::DistributedGraph => {
+                IntoTopology::DistributedGraph(DistributedGraphCommunicator(self))
+            }
             Topology::Undefined => IntoTopology::Undefined(self),
         }
     }
@@ -306,14 +346,6 @@ impl Communicator for InterCommunicator {
     }
 }
 
-/// Unimplemented
-#[allow(missing_copy_implementations)]
-pub struct GraphCommunicator;
-
-/// Unimplemented
-#[allow(missing_copy_implementations)]
-pub struct DistributedGraphCommunicator;
-
 /// A color used in a communicator split
 #[derive(Copy, Clone, Debug)]
 pub struct Color(c_int);
@@ -343,6 +375,56 @@ impl Color {
 /// A key used when determining the rank order of processes after a communicator split.
 pub type Key = c_int;
 
+/// The kind of resource shared by the processes of a communicator created by
+/// [`split_by_type`](trait.Communicator.html#method.split_by_type)
+///
+/// # Standard section(s)
+///
+/// 6.4.2
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SplitType {
+    /// Processes that can create a shared memory region (`MPI_COMM_TYPE_SHARED`)
+    Shared,
+    /// Processes that share the hardware resource named by the `mpi_hw_resource_type` info hint,
+    /// e.g. `"mpi_shared_memory"` (`MPI_COMM_TYPE_HW_GUIDED`). Which other resource types, such as
+    /// sockets or caches, are available depends on the MPI library.
+    #[cfg(mpi4)]
+    HardwareGuided(String),
+    /// Processes that share the next smaller level of the hardware hierarchy below the
+    /// communicator that is split (`MPI_COMM_TYPE_HW_UNGUIDED`). The name of the level is reported
+    /// in the `mpi_hw_resource_type` info hint of the new communicator.
+    #[cfg(mpi4)]
+    HardwareUnguided,
+    /// A split type defined by the MPI library, e.g. `OMPI_COMM_TYPE_SOCKET` in Open MPI
+    Implementation(c_int),
+}
+
+impl SplitType {
+    /// The raw value understood by the MPI C API
+    fn as_raw(&self) -> c_int {
+        match *self {
+            SplitType::Shared => unsafe { ffi::RSMPI_COMM_TYPE_SHARED },
+            #[cfg(mpi4)]
+            SplitType::HardwareGuided(_) => unsafe { ffi::RSMPI_COMM_TYPE_HW_GUIDED },
+            #[cfg(mpi4)]
+            SplitType::HardwareUnguided => unsafe { ffi::RSMPI_COMM_TYPE_HW_UNGUIDED },
+            SplitType::Implementation(split_type) => split_type,
+        }
+    }
+
+    /// Adds the hints the split type is driven by to `info`.
+    fn hints<'a>(&self, info: &'a Info) -> Cow<'a, Info> {
+        #[cfg(mpi4)]
+        if let SplitType::HardwareGuided(resource_type) = self {
+            let mut info = info.clone();
+            info.set("mpi_hw_resource_type", resource_type)
+                .expect("Hardware resource type cannot be used as an info value.");
+            return Cow::Owned(info);
+        }
+        Cow::Borrowed(info)
+    }
+}
+
 /// Communicators are contexts for communication
 pub trait Communicator: sealed::AsHandle {
     /// Returns the number of processes available to communicate with in this `Communicator`. For
@@ -383,6 +465,19 @@ pub trait Communicator: sealed::AsHandle {
         Process::by_rank_unchecked(self, r)
     }
 
+    /// Like [`process_at_rank`](#method.process_at_rank), but returns an `MPI_ERR_RANK` error
+    /// instead of panicking if `r` is not a valid rank.
+    ///
+    /// # Examples
+    /// See `examples/errors_return.rs`
+    fn try_process_at_rank(&self, r: Rank) -> Result<Process, MpiError> {
+        if 0 <= r && r < self.target_size() {
+            Ok(Process::by_rank_unchecked(self, r))
+        } else {
+            Err(MpiError::from_code(ffi::MPI_ERR_RANK as Error))
+        }
+    }
+
     /// Returns an `AnyProcess` identifier that can be used, e.g. as a `Source` in point to point
     /// communication.
     fn any_process(&self) -> AnyProcess {
@@ -427,6 +522,61 @@ pub trait Communicator: sealed::AsHandle {
         }
     }
 
+    /// Duplicate a communicator, using the hints in `info` instead of the hints of this
+    /// communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/info.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2, see the `MPI_Comm_dup_with_info` function
+    fn duplicate_with_info(&self, info: &Info) -> SimpleCommunicator {
+        unsafe {
+            SimpleCommunicator::from_raw(
+                with_uninitialized(|newcomm| {
+                    ffi::MPI_Comm_dup_with_info(self.as_raw(), info.as_raw(), newcomm)
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Set the hints of the communicator.
+    ///
+    /// MPI may ignore some or all of the hints.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/info.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.4, see the `MPI_Comm_set_info` function
+    fn set_info(&self, info: &Info) {
+        unsafe {
+            ffi::MPI_Comm_set_info(self.as_raw(), info.as_raw());
+        }
+    }
+
+    /// Get the hints of the communicator that are actually used by MPI.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/info.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.4, see the `MPI_Comm_get_info` function
+    fn get_info(&self) -> Info {
+        unsafe {
+            Info::from_raw(
+                with_uninitialized(|info_used| ffi::MPI_Comm_get_info(self.as_raw(), info_used)).1,
+            )
+        }
+    }
+
     /// Split a communicator by color.
     ///
     /// Creates as many new communicators as distinct values of `color` are given. All processes
@@ -473,19 +623,54 @@ pub trait Communicator: sealed::AsHandle {
     ///
     /// 6.4.2 (See: `MPI_Comm_split_type`)
     fn split_shared(&self, key: c_int) -> SimpleCommunicator {
+        self.split_by_type(SplitType::Shared, key)
+            .expect("rsmpi internal error: MPI implementation incorrectly returned MPI_COMM_NULL from MPI_Comm_split_type(..., MPI_COMM_TYPE_SHARED, ...)")
+    }
+
+    /// Split the communicator into subcommunicators of processes that share a resource of kind
+    /// `split_type`, e.g. a shared memory node or a socket.
+    ///
+    /// Within each subgroup, the processes are ranked in the order defined by the value of the
+    /// argument key, with ties broken according to their rank in the old group. Returns `None` if
+    /// the calling process is not placed in a subcommunicator, e.g. because MPI does not know the
+    /// requested hardware resource.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/split_type.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2, see the `MPI_Comm_split_type` function
+    fn split_by_type(&self, split_type: SplitType, key: Key) -> Option<SimpleCommunicator> {
+        self.split_by_type_with_info(split_type, key, &Info::new())
+    }
+
+    /// Like [`split_by_type`](#method.split_by_type), but passes additional hints in `info`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2, see the `MPI_Comm_split_type` function
+    fn split_by_type_with_info(
+        &self,
+        split_type: SplitType,
+        key: Key,
+        info: &Info,
+    ) -> Option<SimpleCommunicator> {
+        let info = split_type.hints(info);
         unsafe {
             SimpleCommunicator::try_from_raw(
                 with_uninitialized(|newcomm| {
                     ffi::MPI_Comm_split_type(
                         self.as_raw(),
-                        ffi::RSMPI_COMM_TYPE_SHARED,
+                        split_type.as_raw(),
                         key,
-                        ffi::RSMPI_INFO_NULL,
+                        info.as_raw(),
                         newcomm,
                     )
                 })
-                    .1,
-            ).expect("rsmpi internal error: MPI implementation incorrectly returned MPI_COMM_NULL from MPI_Comm_split_type(..., MPI_COMM_TYPE_SHARED, ...)")
+                .1,
+            )
         }
     }
 
@@ -581,6 +766,103 @@ pub trait Communicator: sealed::AsHandle {
         process::abort();
     }
 
+    /// Makes operations on this communicator return error codes instead of aborting the program.
+    ///
+    /// This installs the `MPI_ERRORS_RETURN` error handler. Errors can then be handled through
+    /// the `try_` variants of communication operations, e.g.
+    /// [`try_send`](../point_to_point/trait.Destination.html#method.try_send) or
+    /// [`try_all_reduce_into`](../collective/trait.CommunicatorCollectives.html#method.try_all_reduce_into).
+    ///
+    /// Communicators created from this communicator inherit its error handler.
+    ///
+    /// # Safety
+    /// All operations other than the `try_` variants ignore the error codes and use their outputs,
+    /// e.g. a `Status` or a received value, as if MPI had written them. While `MPI_ERRORS_RETURN`
+    /// is installed, the caller must ensure that only `try_` variants fail on this communicator
+    /// and on the communicators that inherit its error handler.
+    ///
+    /// # Examples
+    /// See `examples/errors_return.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3, see the `MPI_Comm_set_errhandler` function
+    unsafe fn set_errors_return(&self) {
+        ffi::MPI_Comm_set_errhandler(self.as_raw(), ffi::RSMPI_ERRORS_RETURN);
+    }
+
+    /// Makes errors in operations on this communicator abort the program.
+    ///
+    /// This installs the `MPI_ERRORS_ARE_FATAL` error handler, which is the default.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3, see the `MPI_Comm_set_errhandler` function
+    fn set_errors_are_fatal(&self) {
+        unsafe {
+            ffi::MPI_Comm_set_errhandler(self.as_raw(), ffi::RSMPI_ERRORS_ARE_FATAL);
+        }
+    }
+
+    /// Attaches the error handler `handler` to this communicator, replacing its current error
+    /// handler.
+    ///
+    /// Communicators created from this communicator inherit its error handler.
+    ///
+    /// # Safety
+    /// If `handler` returns instead of aborting the program, as
+    /// [`ErrorHandler::errors_return()`](../environment/struct.ErrorHandler.html#method.errors_return)
+    /// and closures passed to
+    /// [`ErrorHandler::new()`](../environment/struct.ErrorHandler.html#method.new) do, the
+    /// failing operation returns as well. The same contract as for
+    /// [`set_errors_return()`](#method.set_errors_return) applies then: only `try_` variants of
+    /// operations may fail on this communicator and on the communicators that inherit its error
+    /// handler.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1, see the `MPI_Comm_set_errhandler` function
+    unsafe fn set_error_handler(&self, handler: &ErrorHandler) {
+        ffi::MPI_Comm_set_errhandler(self.as_raw(), handler.as_raw());
+    }
+
+    /// Returns the error handler currently attached to this communicator.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1, see the `MPI_Comm_get_errhandler` function
+    fn get_error_handler(&self) -> ErrorHandler {
+        unsafe {
+            ErrorHandler::from_raw(
+                with_uninitialized(|handler| ffi::MPI_Comm_get_errhandler(self.as_raw(), handler))
+                    .1,
+            )
+        }
+    }
+
+    /// Invokes the error handler of this communicator with the error code `code`.
+    ///
+    /// Libraries can use this to report errors of their own error classes, see
+    /// [`add_error_class`](../environment/fn.add_error_class.html).
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.5, see the `MPI_Comm_call_errhandler` function
+    fn call_error_handler(&self, code: Error) {
+        unsafe {
+            ffi::MPI_Comm_call_errhandler(self.as_raw(), code);
+        }
+    }
+
     /// Tests if the communicator is an inter-communicator.
     ///
     /// # Standard sections(s)
@@ -701,6 +983,208 @@ pub trait Communicator: sealed::AsHandle {
         }
     }
 
+    /// Creates a communicator with ranks as the nodes of a graph that is specified in full on
+    /// every process.
+    ///
+    /// Returns `None` if the local process does not partake in the new `GraphCommunicator`, i.e.
+    /// if its rank is not smaller than `index.len()`.
+    ///
+    /// * `index` - `index[i]` is the total number of neighbors of the nodes `0` to `i`
+    /// * `edges` - the concatenated lists of neighbors of all nodes, the neighbors of node `i` are
+    ///     `edges[index[i - 1]..index[i]]`
+    /// * `reorder` - If true, MPI may re-order ranks in the new communicator.
+    ///
+    /// # Examples
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    /// 7.5.3 (MPI_Graph_create)
+    fn create_graph_communicator(
+        &self,
+        index: &[Count],
+        edges: &[Rank],
+        reorder: bool,
+    ) -> Option<GraphCommunicator> {
+        assert_eq!(
+            index.last().copied().unwrap_or(0),
+            edges.count(),
+            "the last entry of index must be the number of edges"
+        );
+
+        unsafe {
+            let mut comm_graph = ffi::RSMPI_COMM_NULL;
+            ffi::MPI_Graph_create(
+                self.as_raw(),
+                index.count(),
+                index.as_ptr(),
+                edges.as_ptr(),
+                reorder as Count,
+                &mut comm_graph,
+            );
+            GraphCommunicator::try_from_raw(comm_graph)
+        }
+    }
+
+    /// Gets the target rank of this rank as-if
+    /// [`create_graph_communicator`](#method.create_graph_communicator) had been called with
+    /// `index`, `edges`, and `reorder = true`.
+    ///
+    /// Returns `None` if the local process would not particate in the new GraphCommunicator.
+    ///
+    /// * `index` - `index[i]` is the total number of neighbors of the nodes `0` to `i`
+    /// * `edges` - the concatenated lists of neighbors of all nodes
+    ///
+    /// # Standard section
+    /// 7.5.8 (MPI_Graph_map)
+    fn graph_map(&self, index: &[Count], edges: &[Rank]) -> Option<Rank> {
+        assert_eq!(
+            index.last().copied().unwrap_or(0),
+            edges.count(),
+            "the last entry of index must be the number of edges"
+        );
+
+        unsafe {
+            let mut new_rank = ffi::MPI_UNDEFINED;
+            ffi::MPI_Graph_map(
+                self.as_raw(),
+                index.count(),
+                index.as_ptr(),
+                edges.as_ptr(),
+                &mut new_rank,
+            );
+            if new_rank == ffi::MPI_UNDEFINED {
+                None
+            } else {
+                Some(new_rank)
+            }
+        }
+    }
+
+    /// Creates a communicator with ranks as the nodes of a directed graph, where every process
+    /// may specify any subset of the edges.
+    ///
+    /// For `i` in 0 to `sources.len()`, the calling process adds edges from `sources[i]` to the
+    /// next `degrees[i]` entries of `destinations`.
+    ///
+    /// * `sources` - source nodes for which this process specifies edges
+    /// * `degrees` - must match length of `sources`, the number of edges for each source
+    /// * `destinations` - the concatenated destination nodes of all specified edges
+    /// * `weights` - `None` for an unweighted graph, otherwise a weight for each entry in
+    ///     `destinations`. Either all or none of the processes must provide weights.
+    /// * `reorder` - If true, MPI may re-order ranks in the new communicator.
+    ///
+    /// # Examples
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    /// 7.5.4 (MPI_Dist_graph_create)
+    fn create_dist_graph_communicator(
+        &self,
+        sources: &[Rank],
+        degrees: &[Count],
+        destinations: &[Rank],
+        weights: Option<&[Count]>,
+        reorder: bool,
+    ) -> DistributedGraphCommunicator {
+        assert_eq!(
+            sources.len(),
+            degrees.len(),
+            "sources and degrees must be parallel, equal-sized arrays"
+        );
+        assert_eq!(
+            degrees.iter().sum::<Count>(),
+            destinations.count(),
+            "degrees must sum up to the number of destinations"
+        );
+        if let Some(weights) = weights {
+            assert_eq!(
+                destinations.len(),
+                weights.len(),
+                "destinations and weights must be parallel, equal-sized arrays"
+            );
+        }
+
+        unsafe {
+            let mut comm_dist_graph = ffi::RSMPI_COMM_NULL;
+            ffi::MPI_Dist_graph_create(
+                self.as_raw(),
+                sources.count(),
+                sources.as_ptr(),
+                degrees.as_ptr(),
+                destinations.as_ptr(),
+                graph::weights_ptr(weights),
+                ffi::RSMPI_INFO_NULL,
+                reorder as Count,
+                &mut comm_dist_graph,
+            );
+            DistributedGraphCommunicator::from_raw(comm_dist_graph)
+        }
+    }
+
+    /// Creates a communicator with ranks as the nodes of a directed graph, where every process
+    /// specifies exactly its own incoming and outgoing edges.
+    ///
+    /// * `sources` - the processes with an edge to this process
+    /// * `source_weights` - `None` for an unweighted graph, otherwise the weight of the edge from
+    ///     each entry in `sources`
+    /// * `destinations` - the processes with an edge from this process
+    /// * `destination_weights` - `None` for an unweighted graph, otherwise the weight of the edge
+    ///     to each entry in `destinations`
+    /// * `reorder` - If true, MPI may re-order ranks in the new communicator.
+    ///
+    /// Either all or none of the processes must provide weights.
+    ///
+    /// # Examples
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    /// 7.5.4 (MPI_Dist_graph_create_adjacent)
+    fn create_dist_graph_communicator_adjacent(
+        &self,
+        sources: &[Rank],
+        source_weights: Option<&[Count]>,
+        destinations: &[Rank],
+        destination_weights: Option<&[Count]>,
+        reorder: bool,
+    ) -> DistributedGraphCommunicator {
+        assert_eq!(
+            source_weights.is_some(),
+            destination_weights.is_some(),
+            "either both or neither of source_weights and destination_weights must be given"
+        );
+        if let Some(source_weights) = source_weights {
+            assert_eq!(
+                sources.len(),
+                source_weights.len(),
+                "sources and source_weights must be parallel, equal-sized arrays"
+            );
+        }
+        if let Some(destination_weights) = destination_weights {
+            assert_eq!(
+                destinations.len(),
+                destination_weights.len(),
+                "destinations and destination_weights must be parallel, equal-sized arrays"
+            );
+        }
+
+        unsafe {
+            let mut comm_dist_graph = ffi::RSMPI_COMM_NULL;
+            ffi::MPI_Dist_graph_create_adjacent(
+                self.as_raw(),
+                sources.count(),
+                sources.as_ptr(),
+                graph::weights_ptr(source_weights),
+                destinations.count(),
+                destinations.as_ptr(),
+                graph::weights_ptr(destination_weights),
+                ffi::RSMPI_INFO_NULL,
+                reorder as Count,
+                &mut comm_dist_graph,
+            );
+            DistributedGraphCommunicator::from_raw(comm_dist_graph)
+        }
+    }
+
     /// Gets the implementation-defined buffer size required to pack 'incount' elements of type
     /// 'datatype'.
     ///
@@ -723,6 +1207,11 @@ pub trait Communicator: sealed::AsHandle {
     /// Packs inbuf into a byte array with an implementation-defined format. Often paired with
     /// `unpack` to convert back into a specific datatype.
     ///
+    /// # Panics
+    ///
+    /// Panics if `inbuf` or the packed bytes exceed the range of `Count`, since large-count
+    /// packing is not supported yet.
+    ///
     /// # Standard Sections
     ///
     /// 4.2, see MPI_Pack
@@ -755,6 +1244,11 @@ pub trait Communicator: sealed::AsHandle {
     /// Packs inbuf into a byte array with an implementation-defined format. Often paired with
     /// `unpack` to convert back into a specific datatype.
     ///
+    /// # Panics
+    ///
+    /// Panics if `inbuf` or `outbuf` exceed the range of `Count`, since large-count packing is not
+    /// supported yet.
+    ///
     /// # Standard Sections
     ///
     /// 4.2, see MPI_Pack
@@ -783,6 +1277,11 @@ pub trait Communicator: sealed::AsHandle {
     /// Unpacks an implementation-specific byte array from `pack` or `pack_into` into a buffer of a
     /// specific datatype.
     ///
+    /// # Panics
+    ///
+    /// Panics if `inbuf` or `outbuf` exceed the range of `Count`, since large-count packing is not
+    /// supported yet.
+    ///
     /// # Standard Sections
     ///
     /// 4.2, see MPI_Unpack
@@ -1089,6 +1588,12 @@ unsafe impl AsRaw for UserGroup {
     }
 }
 
+impl FromRaw for UserGroup {
+    unsafe fn from_raw(handle: MPI_Group) -> Self {
+        UserGroup(handle)
+    }
+}
+
 impl Group for UserGroup {}
 
 /// Groups are collections of parallel processes
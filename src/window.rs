@@ -0,0 +1,1003 @@
+//! One-sided communication (RMA)
+//!
+//! A `Window` exposes a region of memory of every process in a communicator to remote memory
+//! access (RMA) by the other processes of that communicator. RMA operations (`put()`, `get()` and
+//! `accumulate()`) can only be issued while an access epoch on the window is open. Epochs are
+//! modelled as guard objects that open the epoch when they are created and close it when they are
+//! dropped:
+//!
+//! - [`FenceEpoch`](struct.FenceEpoch.html) for active target synchronization with
+//! `MPI_Win_fence()`,
+//! - [`AccessEpoch`](struct.AccessEpoch.html) and [`ExposureEpoch`](struct.ExposureEpoch.html) for
+//! generalized active target synchronization with `MPI_Win_start()`/`MPI_Win_complete()` and
+//! `MPI_Win_post()`/`MPI_Win_wait()`,
+//! - [`LockEpoch`](struct.LockEpoch.html) for passive target synchronization with
+//! `MPI_Win_lock()`/`MPI_Win_unlock()` and `MPI_Win_lock_all()`/`MPI_Win_unlock_all()`.
+//!
+//! The origin buffers of RMA operations must not be touched until the epoch they were issued in has
+//! been closed. Like requests, epochs are therefore registered with a
+//! [`Scope`](../request/trait.Scope.html) that the origin buffers have to outlive.
+//!
+//! A [`SharedWindow`](struct.SharedWindow.html) is allocated in memory that the processes of a
+//! node can access directly with loads and stores instead of RMA operations.
+//!
+//! # Unfinished features
+//!
+//! - **12.2.4**: `MPI_Win_create_dynamic()`, `MPI_Win_attach()`, `MPI_Win_detach()`
+//! - **12.3.4**: `MPI_Get_accumulate()`, `MPI_Fetch_and_op()`, `MPI_Compare_and_swap()`
+//! - **12.3.5**: Request-based RMA operations, `MPI_Rput()`, `MPI_Rget()`, ...
+//! - **12.5**: Assertions, `MPI_Win_flush()`, `MPI_Win_test()`
+
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    mem,
+    os::raw::{c_int, c_void},
+    ptr, slice,
+};
+
+use conv::ConvUtil;
+
+use crate::{
+    collective::Operation,
+    datatype::traits::*,
+    environment::ErrorHandler,
+    ffi,
+    ffi::MPI_Win,
+    info::Info,
+    raw::traits::*,
+    request::Scope,
+    topology::{traits::*, Rank, SimpleCommunicator, UserGroup},
+    with_uninitialized, with_uninitialized2, Address, Error,
+};
+
+/// One-sided communication traits
+pub mod traits {
+    pub use super::Epoch;
+}
+
+/// A window of memory exposed to remote memory access by the processes of a communicator
+///
+/// The memory of the window consists of a slice of `T` on every process. Remote processes address
+/// it in units of `T`, i.e. a target displacement of `i` refers to the `i`th element of the
+/// target's slice.
+///
+/// The window is freed when it is dropped, which is a collective operation on the communicator it
+/// was created on.
+///
+/// # Examples
+///
+/// See `examples/window.rs`
+///
+/// # Standard section(s)
+///
+/// 12.2
+pub struct Window<'a, T: Equivalence> {
+    raw: MPI_Win,
+    base: *mut T,
+    len: usize,
+    phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: Equivalence> Window<'a, T> {
+    /// Expose the local buffer `buf` for remote memory access by the processes of `comm`.
+    ///
+    /// `buf` remains borrowed for as long as the window exists. This is a collective operation on
+    /// `comm`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.1
+    pub fn create<C: Communicator + ?Sized>(comm: &C, buf: &'a mut [T]) -> Self {
+        let size: Address = mem::size_of_val(buf)
+            .value_as()
+            .expect("Window size exceeds the range of MPI_Aint.");
+        let raw = unsafe {
+            with_uninitialized(|win| {
+                ffi::MPI_Win_create(
+                    buf.as_mut_ptr().cast::<c_void>(),
+                    size,
+                    Self::disp_unit(),
+                    ffi::RSMPI_INFO_NULL,
+                    comm.as_raw(),
+                    win,
+                )
+            })
+            .1
+        };
+        Window {
+            raw,
+            base: buf.as_mut_ptr(),
+            len: buf.len(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Let MPI allocate `len` elements of memory and expose it for remote memory access by the
+    /// processes of `comm`.
+    ///
+    /// The memory is initialized with `T::default()` and is released when the window is freed
+    /// without dropping its elements, hence `T` has to be `Copy`. This is a collective operation on
+    /// `comm`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.2
+    pub fn allocate<C: Communicator + ?Sized>(comm: &C, len: usize) -> Self
+    where
+        T: Copy + Default,
+    {
+        let size: Address = len
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|size| size.value_as().ok())
+            .expect("Window size exceeds the range of MPI_Aint.");
+        let mut base: *mut T = ptr::null_mut();
+        let raw = unsafe {
+            with_uninitialized(|win| {
+                ffi::MPI_Win_allocate(
+                    size,
+                    Self::disp_unit(),
+                    ffi::RSMPI_INFO_NULL,
+                    comm.as_raw(),
+                    ptr::addr_of_mut!(base).cast::<c_void>(),
+                    win,
+                )
+            })
+            .1
+        };
+        for i in 0..len {
+            unsafe { ptr::write(base.add(i), T::default()) };
+        }
+        Window {
+            raw,
+            base,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    fn disp_unit() -> c_int {
+        mem::size_of::<T>()
+            .value_as()
+            .expect("Size of window element type exceeds the range of int.")
+    }
+
+    /// The number of elements of `T` exposed by the local process
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the local process exposes no memory through this window
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The memory exposed by the local process.
+    ///
+    /// Local access to the window memory must not conflict with remote memory access from other
+    /// processes. Outside of an epoch this means that other processes must not currently hold a
+    /// lock on the window at this process.
+    pub fn local(&self) -> &[T] {
+        unsafe { slice_or_empty(self.base, self.len) }
+    }
+
+    /// The memory exposed by the local process, mutably.
+    ///
+    /// See [`local()`](#method.local) for the restrictions that apply.
+    pub fn local_mut(&mut self) -> &mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.base, self.len) }
+    }
+
+    /// The group of processes that share access to this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.6
+    pub fn group(&self) -> UserGroup {
+        unsafe {
+            UserGroup::from_raw(
+                with_uninitialized(|group| ffi::MPI_Win_get_group(self.raw, group)).1,
+            )
+        }
+    }
+
+    /// Set the hints of the window.
+    ///
+    /// MPI may ignore some or all of the hints.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.7, see the `MPI_Win_set_info` function
+    pub fn set_info(&self, info: &Info) {
+        unsafe {
+            ffi::MPI_Win_set_info(self.raw, info.as_raw());
+        }
+    }
+
+    /// The hints of the window that are actually used by MPI
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.7, see the `MPI_Win_get_info` function
+    pub fn get_info(&self) -> Info {
+        unsafe {
+            Info::from_raw(
+                with_uninitialized(|info_used| ffi::MPI_Win_get_info(self.raw, info_used)).1,
+            )
+        }
+    }
+
+    /// Attaches the error handler `handler` to this window, replacing its current error handler.
+    ///
+    /// # Safety
+    /// - `handler` must be a predefined error handler or have been created with
+    /// [`ErrorHandler::new_for_windows()`](../environment/struct.ErrorHandler.html#method.new_for_windows).
+    /// - If `handler` returns instead of aborting the program, the failing operation returns as
+    /// well, but the operations on windows ignore the error codes and use their outputs as if MPI
+    /// had written them. The caller must ensure that no operation on this window fails then.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_set_errhandler` function
+    pub unsafe fn set_error_handler(&self, handler: &ErrorHandler) {
+        ffi::MPI_Win_set_errhandler(self.raw, handler.as_raw());
+    }
+
+    /// Returns the error handler currently attached to this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_get_errhandler` function
+    pub fn get_error_handler(&self) -> ErrorHandler {
+        unsafe {
+            ErrorHandler::from_raw(
+                with_uninitialized(|handler| ffi::MPI_Win_get_errhandler(self.raw, handler)).1,
+            )
+        }
+    }
+
+    /// Invokes the error handler of this window with the error code `code`.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.5, see the `MPI_Win_call_errhandler` function
+    pub fn call_error_handler(&self, code: Error) {
+        unsafe {
+            ffi::MPI_Win_call_errhandler(self.raw, code);
+        }
+    }
+
+    /// Open an access and exposure epoch on all processes of the window with `MPI_Win_fence()`.
+    ///
+    /// The epoch is closed by another fence when the returned guard is dropped. Both fences are
+    /// collective operations on the group of the window.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.1
+    pub fn fence<'w, 's, Sc: Scope<'s>>(&'w mut self, scope: Sc) -> FenceEpoch<'w, 's, Sc> {
+        unsafe {
+            ffi::MPI_Win_fence(0, self.raw);
+        }
+        scope.register();
+        FenceEpoch {
+            raw: self.raw,
+            scope,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Open an access epoch for RMA operations targeting the processes in `group` with
+    /// `MPI_Win_start()`.
+    ///
+    /// The epoch is completed with `MPI_Win_complete()` when the returned guard is dropped. The
+    /// target processes have to open a matching exposure epoch with [`post()`](#method.post).
+    /// Processes that are origin and target at the same time use
+    /// [`post_start()`](#method.post_start) instead.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.2
+    pub fn start<'w, 's, Sc: Scope<'s>, G: Group + ?Sized>(
+        &'w mut self,
+        group: &G,
+        scope: Sc,
+    ) -> AccessEpoch<'w, 's, Sc> {
+        unsafe {
+            ffi::MPI_Win_start(group.as_raw(), 0, self.raw);
+        }
+        scope.register();
+        AccessEpoch {
+            raw: self.raw,
+            scope,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Open an exposure epoch for RMA operations originating from the processes in `group` with
+    /// `MPI_Win_post()`.
+    ///
+    /// The epoch is closed with `MPI_Win_wait()` when the returned guard is dropped, i.e. dropping
+    /// the guard blocks until all origin processes have completed their access epochs.
+    /// Processes that are origin and target at the same time use
+    /// [`post_start()`](#method.post_start) instead.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.2
+    pub fn post<G: Group + ?Sized>(&mut self, group: &G) -> ExposureEpoch<'_> {
+        unsafe {
+            ffi::MPI_Win_post(group.as_raw(), 0, self.raw);
+        }
+        ExposureEpoch {
+            raw: self.raw,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Open an exposure epoch for the processes in `exposure_group` with `MPI_Win_post()` and an
+    /// access epoch targeting the processes in `access_group` with `MPI_Win_start()`.
+    ///
+    /// This is the symmetric form of general active target synchronization, e.g. for exchanging
+    /// data with neighbors. When the returned guard is dropped, the access epoch is completed with
+    /// `MPI_Win_complete()` and then the exposure epoch is closed with `MPI_Win_wait()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.2
+    pub fn post_start<'w, 's, Sc, G, H>(
+        &'w mut self,
+        exposure_group: &G,
+        access_group: &H,
+        scope: Sc,
+    ) -> PostStartEpoch<'w, 's, Sc>
+    where
+        Sc: Scope<'s>,
+        G: Group + ?Sized,
+        H: Group + ?Sized,
+    {
+        unsafe {
+            ffi::MPI_Win_post(exposure_group.as_raw(), 0, self.raw);
+            ffi::MPI_Win_start(access_group.as_raw(), 0, self.raw);
+        }
+        scope.register();
+        PostStartEpoch {
+            raw: self.raw,
+            scope,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Open a passive target access epoch on the window memory of process `rank` with
+    /// `MPI_Win_lock()`.
+    ///
+    /// The lock is released with `MPI_Win_unlock()` when the returned guard is dropped, which also
+    /// completes all RMA operations issued in the epoch.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.3
+    pub fn lock<'w, 's, Sc: Scope<'s>>(
+        &'w mut self,
+        lock_type: LockType,
+        rank: Rank,
+        scope: Sc,
+    ) -> LockEpoch<'w, 's, Sc> {
+        unsafe {
+            ffi::MPI_Win_lock(lock_type.as_raw(), rank, 0, self.raw);
+        }
+        scope.register();
+        LockEpoch {
+            raw: self.raw,
+            target: Some(rank),
+            scope,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Open a passive target access epoch on the window memory of all processes with
+    /// `MPI_Win_lock_all()`.
+    ///
+    /// All processes are locked with a shared lock. The locks are released with
+    /// `MPI_Win_unlock_all()` when the returned guard is dropped.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.3
+    pub fn lock_all<'w, 's, Sc: Scope<'s>>(&'w mut self, scope: Sc) -> LockEpoch<'w, 's, Sc> {
+        unsafe {
+            ffi::MPI_Win_lock_all(0, self.raw);
+        }
+        scope.register();
+        LockEpoch {
+            raw: self.raw,
+            target: None,
+            scope,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Equivalence> Drop for Window<'a, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_free(&mut self.raw);
+        }
+        assert_eq!(self.raw, unsafe { ffi::RSMPI_WIN_NULL });
+    }
+}
+
+unsafe impl<'a, T: Equivalence> AsRaw for Window<'a, T> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+/// A window of memory that is shared by processes on the same node
+///
+/// Every process contributes a segment of `len` elements of `T`. All processes can access the
+/// segments of all other processes directly through [`SharedEpoch`](struct.SharedEpoch.html)s,
+/// e.g. to keep a single copy of a large read-only table per node.
+///
+/// The window keeps a passive target epoch open on all processes for its whole lifetime and
+/// synchronizes accesses with `MPI_Win_sync()` and barriers. The window is freed when it is
+/// dropped, which is a collective operation on the communicator it was allocated on.
+///
+/// # Examples
+///
+/// See `examples/shared_window.rs`
+///
+/// # Standard section(s)
+///
+/// 12.2.3
+pub struct SharedWindow<T: Equivalence> {
+    raw: MPI_Win,
+    comm: SimpleCommunicator,
+    base: *mut T,
+    len: usize,
+}
+
+impl<T: Equivalence> SharedWindow<T> {
+    /// Let MPI allocate `len` elements of memory that can be accessed by all processes of `comm`.
+    ///
+    /// All processes of `comm` must be able to share memory, e.g. because `comm` has been created
+    /// with [`split_shared()`](../topology/trait.Communicator.html#method.split_shared). The
+    /// memory is initialized with `T::default()` and is released when the window is freed without
+    /// dropping its elements, hence `T` has to be `Copy`. This is a collective operation on `comm`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/shared_window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.3
+    pub fn allocate<C: Communicator + ?Sized>(comm: &C, len: usize) -> Self
+    where
+        T: Copy + Default,
+    {
+        let size: Address = len
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|size| size.value_as().ok())
+            .expect("Window size exceeds the range of MPI_Aint.");
+        let disp_unit: c_int = mem::size_of::<T>()
+            .value_as()
+            .expect("Size of window element type exceeds the range of int.");
+        let mut base: *mut T = ptr::null_mut();
+        let raw = unsafe {
+            with_uninitialized(|win| {
+                ffi::MPI_Win_allocate_shared(
+                    size,
+                    disp_unit,
+                    ffi::RSMPI_INFO_NULL,
+                    comm.as_raw(),
+                    ptr::addr_of_mut!(base).cast::<c_void>(),
+                    win,
+                )
+            })
+            .1
+        };
+        for i in 0..len {
+            unsafe { ptr::write(base.add(i), T::default()) };
+        }
+        unsafe {
+            ffi::MPI_Win_lock_all(ffi::RSMPI_MODE_NOCHECK, raw);
+        }
+        SharedWindow {
+            raw,
+            comm: comm.duplicate(),
+            base,
+            len,
+        }
+    }
+
+    /// The number of elements of `T` in the segment of the local process
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the segment of the local process is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The segment of the local process.
+    ///
+    /// Other processes can only access the segment during a
+    /// [`SharedEpoch`](struct.SharedEpoch.html), so outside of one the local process has exclusive
+    /// access to it.
+    pub fn local(&self) -> &[T] {
+        unsafe { slice_or_empty(self.base, self.len) }
+    }
+
+    /// The segment of the local process, mutably.
+    ///
+    /// See [`local()`](#method.local) for the restrictions that apply.
+    pub fn local_mut(&mut self) -> &mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.base, self.len) }
+    }
+
+    /// Make all previous writes to the window visible to all processes and open an epoch in which
+    /// the segments of all processes can be read.
+    ///
+    /// Both opening and closing the epoch synchronize all processes of the window with
+    /// `MPI_Win_sync()` and a barrier, so no process writes to its segment while another process
+    /// still reads it. This is a collective operation on the communicator the window was allocated
+    /// on.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/shared_window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.5.4
+    pub fn synchronize(&mut self) -> SharedEpoch<'_, T> {
+        self.sync_barrier();
+        SharedEpoch { window: self }
+    }
+
+    fn sync_barrier(&self) {
+        unsafe {
+            ffi::MPI_Win_sync(self.raw);
+            ffi::MPI_Barrier(self.comm.as_raw());
+            ffi::MPI_Win_sync(self.raw);
+        }
+    }
+
+    /// Attaches the error handler `handler` to this window, replacing its current error handler.
+    ///
+    /// # Safety
+    /// - `handler` must be a predefined error handler or have been created with
+    /// [`ErrorHandler::new_for_windows()`](../environment/struct.ErrorHandler.html#method.new_for_windows).
+    /// - If `handler` returns instead of aborting the program, the failing operation returns as
+    /// well, but the operations on windows ignore the error codes and use their outputs as if MPI
+    /// had written them. The caller must ensure that no operation on this window fails then.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_set_errhandler` function
+    pub unsafe fn set_error_handler(&self, handler: &ErrorHandler) {
+        ffi::MPI_Win_set_errhandler(self.raw, handler.as_raw());
+    }
+
+    /// Returns the error handler currently attached to this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_get_errhandler` function
+    pub fn get_error_handler(&self) -> ErrorHandler {
+        unsafe {
+            ErrorHandler::from_raw(
+                with_uninitialized(|handler| ffi::MPI_Win_get_errhandler(self.raw, handler)).1,
+            )
+        }
+    }
+
+    /// Invokes the error handler of this window with the error code `code`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.5, see the `MPI_Win_call_errhandler` function
+    pub fn call_error_handler(&self, code: Error) {
+        unsafe {
+            ffi::MPI_Win_call_errhandler(self.raw, code);
+        }
+    }
+}
+
+impl<T: Equivalence> Drop for SharedWindow<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_unlock_all(self.raw);
+            ffi::MPI_Win_free(&mut self.raw);
+        }
+        assert_eq!(self.raw, unsafe { ffi::RSMPI_WIN_NULL });
+    }
+}
+
+unsafe impl<T: Equivalence> AsRaw for SharedWindow<T> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+/// An epoch in which the segments of all processes of a [`SharedWindow`](struct.SharedWindow.html)
+/// can be accessed
+///
+/// The epoch is closed by synchronizing all processes again when the guard is dropped.
+///
+/// # Standard section(s)
+///
+/// 12.2.3, 12.5.4
+#[must_use]
+pub struct SharedEpoch<'w, T: Equivalence> {
+    window: &'w mut SharedWindow<T>,
+}
+
+impl<'w, T: Equivalence> SharedEpoch<'w, T> {
+    /// The segment of process `rank`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.3, see the `MPI_Win_shared_query` function
+    pub fn segment(&self, rank: Rank) -> &[T] {
+        let (base, len) = self.query(rank);
+        unsafe { slice_or_empty(base, len) }
+    }
+
+    /// The segment of process `rank`, mutably.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2.3, see the `MPI_Win_shared_query` function
+    ///
+    /// # Safety
+    ///
+    /// No other process may access the segment of process `rank` during this epoch.
+    pub unsafe fn segment_mut(&mut self, rank: Rank) -> &mut [T] {
+        let (base, len) = self.query(rank);
+        if len == 0 {
+            return &mut [];
+        }
+        slice::from_raw_parts_mut(base, len)
+    }
+
+    fn query(&self, rank: Rank) -> (*mut T, usize) {
+        let mut base: *mut T = ptr::null_mut();
+        let (_, size, _disp_unit) = unsafe {
+            with_uninitialized2(|size, disp_unit| {
+                ffi::MPI_Win_shared_query(
+                    self.window.raw,
+                    rank,
+                    size,
+                    disp_unit,
+                    ptr::addr_of_mut!(base).cast::<c_void>(),
+                )
+            })
+        };
+        let size: usize = size
+            .value_as()
+            .expect("Received unexpected value from MPI_Win_shared_query");
+        (base, size.checked_div(mem::size_of::<T>()).unwrap_or(0))
+    }
+}
+
+impl<'w, T: Equivalence> Drop for SharedEpoch<'w, T> {
+    fn drop(&mut self) {
+        self.window.sync_barrier();
+    }
+}
+
+/// Creates a slice from memory allocated by MPI, which may be a null pointer if `len` is 0.
+unsafe fn slice_or_empty<'a, T>(base: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(base, len)
+    }
+}
+
+/// The kind of lock acquired in a passive target epoch
+///
+/// # Standard section(s)
+///
+/// 12.5.3
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LockType {
+    /// No other process may hold a lock on the target at the same time.
+    Exclusive,
+    /// Other processes may hold shared locks on the target at the same time.
+    Shared,
+}
+
+impl LockType {
+    fn as_raw(self) -> c_int {
+        match self {
+            LockType::Exclusive => unsafe { ffi::RSMPI_LOCK_EXCLUSIVE },
+            LockType::Shared => unsafe { ffi::RSMPI_LOCK_SHARED },
+        }
+    }
+}
+
+/// An open access epoch on a window in which RMA operations can be issued
+///
+/// Origin buffers passed to RMA operations stay borrowed for the lifetime `'s` of the `Scope` the
+/// epoch is registered with, since MPI may access them until the epoch is closed.
+///
+/// # Safety
+///
+/// `as_raw()` must return a window that has an access epoch open for as long as `self` lives and
+/// the epoch must only be closed after all accesses to buffers that live for `'s` have completed.
+pub unsafe trait Epoch<'s>: AsRaw<Raw = MPI_Win> {
+    /// Write the contents of `origin` into the window memory of process `target` starting at
+    /// element `target_disp`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.3.1
+    fn put<Buf>(&self, origin: &'s Buf, target: Rank, target_disp: Address)
+    where
+        Buf: 's + Buffer + ?Sized,
+    {
+        unsafe {
+            ffi::MPI_Put(
+                origin.pointer(),
+                origin.count(),
+                origin.as_datatype().as_raw(),
+                target,
+                target_disp,
+                origin.count(),
+                origin.as_datatype().as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Read from the window memory of process `target` starting at element `target_disp` into
+    /// `origin`.
+    ///
+    /// The contents of `origin` are only defined once the epoch has been closed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.3.2
+    fn get<Buf>(&self, origin: &'s mut Buf, target: Rank, target_disp: Address)
+    where
+        Buf: 's + BufferMut + ?Sized,
+    {
+        unsafe {
+            ffi::MPI_Get(
+                origin.pointer_mut(),
+                origin.count(),
+                origin.as_datatype().as_raw(),
+                target,
+                target_disp,
+                origin.count(),
+                origin.as_datatype().as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Combine the contents of `origin` element-wise with the window memory of process `target`
+    /// starting at element `target_disp` using the operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/window.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.3.4
+    fn accumulate<Buf, O>(&self, origin: &'s Buf, target: Rank, target_disp: Address, op: O)
+    where
+        Buf: 's + Buffer + ?Sized,
+        O: Operation,
+    {
+        unsafe {
+            ffi::MPI_Accumulate(
+                origin.pointer(),
+                origin.count(),
+                origin.as_datatype().as_raw(),
+                target,
+                target_disp,
+                origin.count(),
+                origin.as_datatype().as_raw(),
+                op.as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+}
+
+/// An access and exposure epoch opened with `MPI_Win_fence()`
+///
+/// The epoch is closed with another fence when the guard is dropped.
+///
+/// # Standard section(s)
+///
+/// 12.5.1
+#[must_use]
+pub struct FenceEpoch<'w, 's, Sc: Scope<'s>> {
+    raw: MPI_Win,
+    scope: Sc,
+    phantom: PhantomData<(&'w mut (), Cell<&'s ()>)>,
+}
+
+impl<'w, 's, Sc: Scope<'s>> Drop for FenceEpoch<'w, 's, Sc> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_fence(0, self.raw);
+            self.scope.unregister();
+        }
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> AsRaw for FenceEpoch<'w, 's, Sc> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> Epoch<'s> for FenceEpoch<'w, 's, Sc> {}
+
+/// An access epoch opened with `MPI_Win_start()`
+///
+/// The epoch is completed with `MPI_Win_complete()` when the guard is dropped.
+///
+/// # Standard section(s)
+///
+/// 12.5.2
+#[must_use]
+pub struct AccessEpoch<'w, 's, Sc: Scope<'s>> {
+    raw: MPI_Win,
+    scope: Sc,
+    phantom: PhantomData<(&'w mut (), Cell<&'s ()>)>,
+}
+
+impl<'w, 's, Sc: Scope<'s>> Drop for AccessEpoch<'w, 's, Sc> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_complete(self.raw);
+            self.scope.unregister();
+        }
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> AsRaw for AccessEpoch<'w, 's, Sc> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> Epoch<'s> for AccessEpoch<'w, 's, Sc> {}
+
+/// An exposure epoch opened with `MPI_Win_post()`
+///
+/// No RMA operations can be issued in an exposure epoch. Dropping the guard waits for the origin
+/// processes to complete their access epochs with `MPI_Win_wait()`.
+///
+/// # Standard section(s)
+///
+/// 12.5.2
+#[must_use]
+pub struct ExposureEpoch<'w> {
+    raw: MPI_Win,
+    phantom: PhantomData<&'w mut ()>,
+}
+
+impl<'w> Drop for ExposureEpoch<'w> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_wait(self.raw);
+        }
+    }
+}
+
+/// Matching exposure and access epochs opened with [`Window::post_start()`]
+///
+/// RMA operations issued through the guard target the processes of the access group. Dropping the
+/// guard completes the access epoch with `MPI_Win_complete()` and then waits for the origin
+/// processes of the exposure group with `MPI_Win_wait()`.
+///
+/// # Standard section(s)
+///
+/// 12.5.2
+#[must_use]
+pub struct PostStartEpoch<'w, 's, Sc: Scope<'s>> {
+    raw: MPI_Win,
+    scope: Sc,
+    phantom: PhantomData<(&'w mut (), Cell<&'s ()>)>,
+}
+
+impl<'w, 's, Sc: Scope<'s>> Drop for PostStartEpoch<'w, 's, Sc> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_complete(self.raw);
+            ffi::MPI_Win_wait(self.raw);
+            self.scope.unregister();
+        }
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> AsRaw for PostStartEpoch<'w, 's, Sc> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> Epoch<'s> for PostStartEpoch<'w, 's, Sc> {}
+
+/// A passive target access epoch opened with `MPI_Win_lock()` or `MPI_Win_lock_all()`
+///
+/// The lock is released when the guard is dropped.
+///
+/// # Standard section(s)
+///
+/// 12.5.3
+#[must_use]
+pub struct LockEpoch<'w, 's, Sc: Scope<'s>> {
+    raw: MPI_Win,
+    target: Option<Rank>,
+    scope: Sc,
+    phantom: PhantomData<(&'w mut (), Cell<&'s ()>)>,
+}
+
+impl<'w, 's, Sc: Scope<'s>> Drop for LockEpoch<'w, 's, Sc> {
+    fn drop(&mut self) {
+        unsafe {
+            match self.target {
+                Some(rank) => ffi::MPI_Win_unlock(rank, self.raw),
+                None => ffi::MPI_Win_unlock_all(self.raw),
+            };
+            self.scope.unregister();
+        }
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> AsRaw for LockEpoch<'w, 's, Sc> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.raw
+    }
+}
+
+unsafe impl<'w, 's, Sc: Scope<'s>> Epoch<'s> for LockEpoch<'w, 's, Sc> {}
@@ -0,0 +1,30 @@
+#![cfg(feature = "derive")]
+
+use mpi::traits::Equivalence;
+
+/// We test that the field attributes of #[derive(Equivalence)] accept fields whose types do not
+/// implement `Equivalence`.
+#[test]
+fn derive_field_attributes() {
+    struct Cache([f64; 2]);
+    #[derive(Clone, Copy)]
+    struct Rgb([u8; 3]);
+
+    #[derive(Equivalence)]
+    struct Particle {
+        position: [f64; 3],
+        #[mpi(unsafe_skip)]
+        _cache: Cache,
+        #[mpi(unsafe_bytes)]
+        _color: Rgb,
+        #[mpi(unsafe_datatype = <u16 as Equivalence>::equivalent_datatype())]
+        _kind: [u8; 2],
+    }
+
+    #[derive(Equivalence)]
+    struct Wrapper<T>(#[mpi(unsafe_bytes)] T, #[mpi(unsafe_skip)] Cache);
+
+    fn assert_equivalence<T: Equivalence>() {}
+    assert_equivalence::<Particle>();
+    assert_equivalence::<Wrapper<f32>>();
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "derive")]
+
+use mpi::{datatype::Checked, traits::*};
+
+/// We test that #[derive(Equivalence)] maps the discriminants of a fieldless enum onto its `repr`
+/// and rejects all other values, without initializing MPI.
+#[test]
+fn derive_enum_equivalence() {
+    #[derive(Equivalence, Debug, PartialEq)]
+    #[repr(i16)]
+    enum Direction {
+        Down = -1,
+        Stay,
+        Up = 7,
+    }
+
+    assert_eq!(-1, Direction::Down.to_repr());
+    assert_eq!(0, Direction::Stay.to_repr());
+    assert_eq!(7, Direction::Up.to_repr());
+
+    assert_eq!(Some(Direction::Down), Direction::from_repr(-1));
+    assert_eq!(Some(Direction::Up), Direction::from_repr(7));
+    assert_eq!(None, Direction::from_repr(1));
+
+    assert_eq!(Some(Direction::Stay), Checked::new(Direction::Stay).get());
+    assert_eq!(None, Checked::<Direction>::from_repr(i16::MAX).get());
+}
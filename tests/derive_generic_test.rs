@@ -0,0 +1,23 @@
+#![cfg(feature = "derive")]
+
+use mpi::traits::Equivalence;
+
+/// We test that #[derive(Equivalence)] accepts generic structs with type and const parameters and
+/// bounds their type parameters by `Equivalence`.
+#[test]
+fn derive_generic_equivalence() {
+    #[derive(Equivalence)]
+    struct Pair<T> {
+        a: T,
+        b: T,
+    }
+    #[derive(Equivalence)]
+    struct Samples<T: Copy, const N: usize>([T; N], Pair<T>)
+    where
+        T: Default;
+
+    fn assert_equivalence<T: Equivalence>() {}
+    assert_equivalence::<Pair<f64>>();
+    assert_equivalence::<Pair<Pair<u8>>>();
+    assert_equivalence::<Samples<i32, 4>>();
+}
@@ -0,0 +1,77 @@
+use std::mem::size_of;
+
+use mpi::{
+    datatype::UserDatatype,
+    io::{File, Offset, OpenOptions, Whence},
+    topology::SimpleCommunicator,
+    traits::*,
+};
+
+/// Writes to and reads back from a temporary file through explicit offsets, the individual and
+/// the shared file pointer, and a file view.
+#[test]
+fn io_test() {
+    let _universe = mpi::initialize().unwrap();
+    let comm = SimpleCommunicator::self_comm();
+    let path = std::env::temp_dir().join(format!("rsmpi_io_test_{}.dat", std::process::id()));
+    let bytes = size_of::<i32>() as Offset;
+
+    assert!(File::open(&comm, &path).is_err());
+
+    {
+        let file = File::create(&comm, &path).unwrap();
+        let data: Vec<i32> = (0..8).collect();
+
+        let status = file.write_at(0, &data[..4]);
+        assert_eq!(status.count(i32::equivalent_datatype()), 4);
+        file.write_at_all(4 * bytes, &data[4..]);
+        assert_eq!(file.size(), 8 * bytes);
+
+        let mut read_back = vec![0i32; 8];
+        file.read_at(0, &mut read_back[..4]);
+        file.read_at_all(4 * bytes, &mut read_back[4..]);
+        assert_eq!(read_back, data);
+
+        file.seek(2 * bytes, Whence::Start);
+        let mut x = 0i32;
+        file.read(&mut x);
+        assert_eq!(x, 2);
+        assert_eq!(file.position(), 3 * bytes);
+        file.write(&42i32);
+        file.seek(-bytes, Whence::Current);
+        file.read_all(&mut x);
+        assert_eq!(x, 42);
+
+        file.seek_shared(0, Whence::End);
+        file.write_shared(&[8i32, 9][..]);
+        assert_eq!(file.position_shared(), 10 * bytes);
+        file.seek_shared(8 * bytes, Whence::Start);
+        let mut tail = [0i32; 2];
+        file.read_shared(&mut tail[..]);
+        assert_eq!(tail, [8, 9]);
+    }
+
+    {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .delete_on_close(true)
+            .open(&comm, &path)
+            .unwrap();
+
+        // View the file as a sequence of i32 starting after the first four elements.
+        let filetype = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+        file.set_view(4 * bytes, &i32::equivalent_datatype(), &filetype);
+        assert_eq!(file.position(), 0);
+
+        let mut view = [0i32; 6];
+        file.read_all(&mut view[..]);
+        assert_eq!(view, [4, 5, 6, 7, 8, 9]);
+        file.write_at(0, &[-4i32, -5][..]);
+        file.read_at(0, &mut view[..2]);
+        assert_eq!(view[..2], [-4, -5]);
+    }
+
+    assert!(File::open(&comm, &path).is_err());
+    assert!(File::delete(&path).is_err());
+}